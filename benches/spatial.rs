@@ -0,0 +1,61 @@
+use comgeo::{KdTree, Point};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const POINTS: usize = 1_000_000;
+const QUERIES: usize = 100_000;
+
+struct Rng(u64);
+
+impl Rng {
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn point(&mut self) -> Point<f64> {
+        Point::with_coords(self.next_f64() * 1000.0, self.next_f64() * 1000.0)
+    }
+}
+
+fn report(name: &str, operations: usize, elapsed: Duration) {
+    println!(
+        "{:<24} {:>10.2?} total {:>10.2?} per operation",
+        name,
+        elapsed,
+        elapsed / operations as u32
+    );
+}
+
+fn main() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let points: Vec<_> = (0..POINTS).map(|_| rng.point()).collect();
+    let queries: Vec<_> = (0..QUERIES).map(|_| rng.point()).collect();
+
+    let start = Instant::now();
+    let tree = KdTree::with_points(&points);
+    report("build (1M points)", 1, start.elapsed());
+
+    let start = Instant::now();
+    for &q in &queries {
+        black_box(tree.nearest(black_box(q)));
+    }
+    report("nearest", QUERIES, start.elapsed());
+
+    let start = Instant::now();
+    for &q in &queries {
+        black_box(tree.k_nearest(black_box(q), 16));
+    }
+    report("k_nearest (k = 16)", QUERIES, start.elapsed());
+
+    let start = Instant::now();
+    let mut found = 0;
+    for &q in &queries {
+        let max = Point::with_coords(q.x + 10.0, q.y + 10.0);
+        found += black_box(tree.range(black_box(q), max)).len();
+    }
+    report("range (10 x 10 box)", QUERIES, start.elapsed());
+    black_box(found);
+}
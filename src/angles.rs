@@ -0,0 +1,57 @@
+use core::f64::consts::{PI, TAU};
+
+pub fn angle_difference(a: f64, b: f64) -> f64 {
+    let difference = (b - a) % TAU;
+    if difference > PI {
+        difference - TAU
+    } else if difference <= -PI {
+        difference + TAU
+    } else {
+        difference
+    }
+}
+
+pub fn lerp_angle(a: f64, b: f64, t: f64) -> f64 {
+    a + angle_difference(a, b) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_difference_between_two_angles() {
+        assert_eq!(angle_difference(0.5, 2.0), 1.5);
+        assert_eq!(angle_difference(2.0, 0.5), -1.5);
+    }
+
+    #[test]
+    fn computes_difference_across_wrap_boundary() {
+        assert!((angle_difference(3.0, -3.0) - (TAU - 6.0)).abs() < 1e-12);
+        assert!((angle_difference(-3.0, 3.0) + (TAU - 6.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn computes_difference_of_angles_several_turns_apart() {
+        assert!((angle_difference(0.0, 4.0 * TAU + 1.0) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn computes_difference_of_opposite_angles_as_half_turn() {
+        assert_eq!(angle_difference(0.0, PI), PI);
+        assert_eq!(angle_difference(PI, 0.0), PI);
+    }
+
+    #[test]
+    fn interpolates_between_two_angles() {
+        assert_eq!(lerp_angle(1.0, 2.0, 0.25), 1.25);
+    }
+
+    #[test]
+    fn interpolates_along_shortest_arc_across_wrap_boundary() {
+        let halfway = lerp_angle(3.0, -3.0, 0.5);
+        assert!((halfway - PI).abs() < 1e-12);
+        let quarter = lerp_angle(3.0, -3.0, 0.25);
+        assert!(quarter > 3.0);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::meta::Coord;
+use crate::polyline::Polyline;
+use crate::primitives::{to_f64, to_f64_point, Point};
+use crate::vectors::Vector;
+use num_traits::{Float, FloatConst, ToPrimitive};
+
+/// A circular arc running counterclockwise from `start_angle` to `end_angle`, both in radians
+/// from the positive x axis. An end angle below the start wraps around through a full turn.
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arc<T>
+where
+    T: Coord,
+{
+    pub center: Point<T>,
+    pub radius: T,
+    pub start_angle: f64,
+    pub end_angle: f64,
+}
+
+impl<T> Arc<T>
+where
+    T: Coord + ToPrimitive,
+{
+    pub fn with_center_radius_and_angles(
+        center: Point<T>,
+        radius: T,
+        start_angle: f64,
+        end_angle: f64,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        }
+    }
+
+    /// Returns the angle covered, which lies in `[0, 2π)` when the end angle is below the start.
+    pub fn sweep_angle(&self) -> f64 {
+        let sweep = self.end_angle - self.start_angle;
+        if sweep >= 0.0 {
+            sweep
+        } else {
+            // A multiple of 2π, or a tiny negative sweep after rounding, would otherwise give 2π.
+            let wrapped = sweep % f64::TAU() + f64::TAU();
+            if wrapped == f64::TAU() {
+                0.0
+            } else {
+                wrapped
+            }
+        }
+    }
+
+    pub fn length(&self) -> f64 {
+        to_f64(&self.radius) * self.sweep_angle()
+    }
+
+    /// Returns the point of the underlying circle at `angle`, which need not lie within the arc.
+    pub fn point_at_angle(&self, angle: f64) -> Point<f64> {
+        let (sin, cos) = Float::sin_cos(angle);
+        to_f64_point(&self.center) + Vector::with_coords(cos, sin) * to_f64(&self.radius)
+    }
+
+    /// Approximates the arc with `segments` chords of equal angle, at least one, running from the
+    /// start to the end.
+    pub fn to_polyline(&self, segments: usize) -> Polyline<f64> {
+        let segments = segments.max(1);
+        let step = self.sweep_angle() / segments as f64;
+        Polyline::with_vertices(
+            (0..=segments)
+                .map(|i| self.point_at_angle(self.start_angle + step * i as f64))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn measures_quarter_circle_arc() {
+        let arc = Arc::with_center_radius_and_angles(Point::with_coords(1, 2), 3, 0.0, FRAC_PI_2);
+        assert_eq!(arc.sweep_angle(), FRAC_PI_2);
+        assert!((arc.length() - PI * 3.0 / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn wraps_arc_ending_before_its_start() {
+        let arc = Arc::with_center_radius_and_angles(Point::origin(), 2.0, 3.0 * FRAC_PI_2, 0.0);
+        assert!((arc.sweep_angle() - FRAC_PI_2).abs() < 1e-12);
+        assert!((arc.length() - PI).abs() < 1e-12);
+
+        let full = Arc::with_center_radius_and_angles(Point::origin(), 1.0, 0.0, 2.0 * PI);
+        assert_eq!(full.sweep_angle(), 2.0 * PI);
+    }
+
+    #[test]
+    fn gives_no_sweep_when_end_is_full_turn_before_start() {
+        let arc = Arc::with_center_radius_and_angles(Point::origin(), 1.0, 2.0 * PI, 0.0);
+        assert_eq!(arc.sweep_angle(), 0.0);
+        let arc = Arc::with_center_radius_and_angles(Point::origin(), 1.0, 1.0, 1.0 - 4.0 * PI);
+        assert!(arc.sweep_angle() < 2.0 * PI);
+        let arc = Arc::with_center_radius_and_angles(Point::origin(), 1.0, 1e-20, 0.0);
+        assert_eq!(arc.sweep_angle(), 0.0);
+    }
+
+    #[test]
+    fn finds_point_at_angle() {
+        let arc = Arc::with_center_radius_and_angles(Point::with_coords(1, 2), 3, 0.0, PI);
+        let p = arc.point_at_angle(FRAC_PI_2);
+        assert!((p - Point::with_coords(1.0, 5.0)).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn tessellates_arc_into_polyline() {
+        let arc = Arc::with_center_radius_and_angles(Point::origin(), 2.0, -FRAC_PI_2, PI);
+        let polyline = arc.to_polyline(3);
+        let expected = [(0.0, -2.0), (2.0, 0.0), (0.0, 2.0), (-2.0, 0.0)];
+        assert_eq!(polyline.vertices.len(), expected.len());
+        for (&p, &(x, y)) in polyline.vertices.iter().zip(&expected) {
+            assert!((p - Point::with_coords(x, y)).magnitude() < 1e-12);
+        }
+        assert_eq!(arc.to_polyline(0).vertices.len(), 2);
+    }
+}
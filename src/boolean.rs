@@ -0,0 +1,934 @@
+use crate::bounding::Aabb;
+use crate::meta::{Coord, FloatCoord};
+use crate::polygon::{ConvexPolygon, Polygon, PolygonWithHoles};
+use crate::primitives::{orientation, to_f64_point, Line, Point, Segment, SegmentIntersection};
+use crate::sweep::{intersections, Intersection};
+use crate::vectors::Orientation;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter;
+use num_traits::ToPrimitive;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum BooleanOp {
+    Intersection,
+    Union,
+    Difference,
+    Xor,
+}
+
+/// The shapes the boolean operations combine. Polygons with holes give pieces with holes in their
+/// own coordinate type. Plain polygons of any coordinate type give plain polygons in `f64`: each
+/// piece's counterclockwise exterior is followed by its holes as clockwise rings.
+pub trait BooleanOperand {
+    type Piece;
+
+    fn combine(a: &Self, b: &Self, op: BooleanOp) -> Vec<Self::Piece>;
+}
+
+impl<T> BooleanOperand for PolygonWithHoles<T>
+where
+    T: FloatCoord,
+{
+    type Piece = PolygonWithHoles<T>;
+
+    fn combine(a: &Self, b: &Self, op: BooleanOp) -> Vec<Self::Piece> {
+        overlay(a, b, op)
+    }
+}
+
+impl<T> BooleanOperand for Polygon<T>
+where
+    T: Coord + ToPrimitive,
+{
+    type Piece = Polygon<f64>;
+
+    fn combine(a: &Self, b: &Self, op: BooleanOp) -> Vec<Self::Piece> {
+        let shape = |polygon: &Self| {
+            PolygonWithHoles::from(Polygon::with_vertices(
+                polygon.vertices.iter().map(to_f64_point).collect(),
+            ))
+        };
+        overlay(&shape(a), &shape(b), op)
+            .into_iter()
+            .flat_map(|piece| iter::once(piece.exterior).chain(piece.holes))
+            .collect()
+    }
+}
+
+/// Clips `subject` against the convex polygon `clip` with the Sutherland–Hodgman algorithm. The
+/// subject may be concave, in which case parts of the result can be joined by zero-width bridges
+/// along the clip boundary; use `overlay` to get them as separate polygons.
+pub fn clip_convex<T>(subject: &Polygon<T>, clip: &Polygon<T>) -> Polygon<T>
+where
+    T: FloatCoord,
+{
+    let counterclockwise = match clip.winding() {
+        Orientation::Counterclockwise => true,
+        Orientation::Clockwise => false,
+        Orientation::Collinear => return Polygon::with_vertices(Vec::new()),
+    };
+
+    let mut output = subject.vertices.clone();
+    for edge in clip.edges() {
+        if output.is_empty() {
+            break;
+        }
+        let (a, b) = if counterclockwise {
+            (edge.start, edge.end)
+        } else {
+            (edge.end, edge.start)
+        };
+        output = clip_to_half_plane(&output, a, b);
+    }
+    Polygon::with_vertices(output)
+}
+
+impl<T> ConvexPolygon<T>
+where
+    T: Coord + ToPrimitive,
+{
+    /// Cuts the polygon along `line`, returning the parts to its left and to its right, either of
+    /// which is `None` if the polygon has no area on that side. Coordinates are processed as
+    /// `f64`; the parts are taken to be convex even where rounding the crossings bends them
+    /// slightly.
+    pub fn split_by_line(
+        &self,
+        line: &Line<T>,
+    ) -> (Option<ConvexPolygon<f64>>, Option<ConvexPolygon<f64>>) {
+        let vertices: Vec<Point<f64>> = self.vertices().iter().map(to_f64_point).collect();
+        let a = to_f64_point(&line.point);
+        let b = to_f64_point(&(&line.point + &line.direction));
+        let piece = |a, b| {
+            let polygon = Polygon::with_vertices(clip_to_half_plane(&vertices, a, b));
+            if polygon.winding() == Orientation::Counterclockwise {
+                Some(ConvexPolygon { polygon })
+            } else {
+                None
+            }
+        };
+        (piece(a, b), piece(b, a))
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Coord + ToPrimitive,
+{
+    /// Clips the polygon to `bounds` like `clip_convex`, keeping its winding, or returns an empty
+    /// polygon if nothing with area is left. Coordinates are processed as `f64`.
+    pub fn clip_to_aabb(&self, bounds: &Aabb<T>) -> Polygon<f64> {
+        let (min, max) = (to_f64_point(&bounds.min), to_f64_point(&bounds.max));
+        let corners = [
+            min,
+            Point::with_coords(max.x, min.y),
+            max,
+            Point::with_coords(min.x, max.y),
+        ];
+        let mut output: Vec<Point<f64>> = self.vertices.iter().map(to_f64_point).collect();
+        for i in 0..4 {
+            output = clip_to_half_plane(&output, corners[i], corners[(i + 1) % 4]);
+        }
+        let clipped = Polygon::with_vertices(output);
+        if clipped.winding() == Orientation::Collinear {
+            Polygon::with_vertices(Vec::new())
+        } else {
+            clipped
+        }
+    }
+}
+
+// Keeps the part of the polygon through `vertices` to the left of the line from `a` to `b`,
+// including the line itself, with one Sutherland–Hodgman step.
+fn clip_to_half_plane<T>(vertices: &[Point<T>], a: Point<T>, b: Point<T>) -> Vec<Point<T>>
+where
+    T: FloatCoord,
+{
+    let mut output = Vec::with_capacity(vertices.len() + 1);
+    let mut previous = match vertices.last() {
+        Some(&last) => last,
+        None => return output,
+    };
+    let inside = |p| orientation(a, b, p) != Orientation::Clockwise;
+    for &current in vertices {
+        match (inside(previous), inside(current)) {
+            (true, true) => output.push(current),
+            (true, false) => output.push(line_crossing(previous, current, a, b)),
+            (false, true) => {
+                output.push(line_crossing(previous, current, a, b));
+                output.push(current);
+            }
+            (false, false) => {}
+        }
+        previous = current;
+    }
+    output.dedup();
+    if output.len() > 1 && output[0] == output[output.len() - 1] {
+        output.pop();
+    }
+    output
+}
+
+/// Computes the intersection of two polygons, with or without holes; see `BooleanOperand` and
+/// `overlay`.
+pub fn intersection<S>(a: &S, b: &S) -> Vec<S::Piece>
+where
+    S: BooleanOperand,
+{
+    S::combine(a, b, BooleanOp::Intersection)
+}
+
+/// Computes the union of two polygons, with or without holes; see `BooleanOperand` and `overlay`.
+pub fn union<S>(a: &S, b: &S) -> Vec<S::Piece>
+where
+    S: BooleanOperand,
+{
+    S::combine(a, b, BooleanOp::Union)
+}
+
+/// Computes `a` minus `b` for two polygons, with or without holes; see `BooleanOperand` and
+/// `overlay`.
+pub fn difference<S>(a: &S, b: &S) -> Vec<S::Piece>
+where
+    S: BooleanOperand,
+{
+    S::combine(a, b, BooleanOp::Difference)
+}
+
+/// Computes the symmetric difference of two polygons, with or without holes: the regions covered
+/// by exactly one of them; see `BooleanOperand` and `overlay`.
+pub fn xor<S>(a: &S, b: &S) -> Vec<S::Piece>
+where
+    S: BooleanOperand,
+{
+    S::combine(a, b, BooleanOp::Xor)
+}
+
+/// Combines two simple polygons with holes, returning the resulting pieces with counterclockwise
+/// exteriors and clockwise holes, without collinear or repeated vertices. Pieces touching at a
+/// single vertex are returned separately.
+///
+/// Both boundaries are split at their mutual intersections, found with the Bentley–Ottmann sweep
+/// of `intersections`, and every piece of edge is kept or dropped depending on which side of the
+/// other polygon it lies. Coordinates are processed as `f64`.
+pub fn overlay<T>(
+    a: &PolygonWithHoles<T>,
+    b: &PolygonWithHoles<T>,
+    op: BooleanOp,
+) -> Vec<PolygonWithHoles<T>>
+where
+    T: FloatCoord,
+{
+    let shapes = [oriented_rings(a), oriented_rings(b)];
+    let edges = split_edges(&shapes);
+    let selected = select_edges(&shapes, edges, op);
+    let rings = link_rings(selected);
+    assemble(rings)
+        .into_iter()
+        .map(|shape| {
+            let cast = |ring: Polygon<f64>| {
+                Polygon::with_vertices(
+                    ring.vertices
+                        .into_iter()
+                        .map(|p| p.cast().expect("coordinates representable as f64"))
+                        .collect(),
+                )
+            };
+            PolygonWithHoles::with_exterior_and_holes(
+                cast(shape.exterior),
+                shape.holes.into_iter().map(cast).collect(),
+            )
+        })
+        .collect()
+}
+
+// Intersects the line through `p` and `q` with the line through `a` and `b`.
+fn line_crossing<T>(p: Point<T>, q: Point<T>, a: Point<T>, b: Point<T>) -> Point<T>
+where
+    T: FloatCoord,
+{
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let (ex, ey) = (q.x - p.x, q.y - p.y);
+    let t = (dx * (a.y - p.y) - dy * (a.x - p.x)) / (dx * ey - dy * ex);
+    Point::with_coords(p.x + ex * t, p.y + ey * t)
+}
+
+// Returns the rings converted to `f64` with the exterior counterclockwise and holes clockwise, so
+// that the interior always lies to the left of every edge.
+fn oriented_rings<T>(shape: &PolygonWithHoles<T>) -> Vec<Polygon<f64>>
+where
+    T: FloatCoord,
+{
+    let rings = iter::once((&shape.exterior, Orientation::Counterclockwise)).chain(
+        shape
+            .holes
+            .iter()
+            .map(|hole| (hole, Orientation::Clockwise)),
+    );
+    rings
+        .filter_map(|(ring, winding)| {
+            let mut vertices: Vec<Point<f64>> = ring
+                .vertices
+                .iter()
+                .map(|p| p.cast().expect("coordinates representable as f64"))
+                .collect();
+            vertices.dedup();
+            if vertices.len() > 1 && vertices[0] == vertices[vertices.len() - 1] {
+                vertices.pop();
+            }
+            let mut ring = Polygon::with_vertices(vertices);
+            match ring.winding() {
+                Orientation::Collinear => return None,
+                w if w != winding => ring.vertices.reverse(),
+                _ => {}
+            }
+            Some(ring)
+        })
+        .collect()
+}
+
+struct Edge {
+    start: Point<f64>,
+    end: Point<f64>,
+    shape: usize,
+}
+
+// Splits every edge at the points where it meets edges of either shape.
+fn split_edges(shapes: &[Vec<Polygon<f64>>; 2]) -> Vec<Edge> {
+    let mut segments = Vec::new();
+    let mut owners = Vec::new();
+    for (shape, rings) in shapes.iter().enumerate() {
+        for ring in rings {
+            segments.extend(ring.edges());
+            owners.resize(segments.len(), shape);
+        }
+    }
+
+    let mut splits = vec![Vec::new(); segments.len()];
+    for Intersection {
+        first,
+        second,
+        kind,
+    } in intersections(&segments)
+    {
+        let points = match kind {
+            SegmentIntersection::Point(p) => {
+                vec![snap_to_endpoint(p, &segments[first], &segments[second])]
+            }
+            SegmentIntersection::Overlap(overlap) => vec![overlap.start, overlap.end],
+            SegmentIntersection::None => continue,
+        };
+        splits[first].extend_from_slice(&points);
+        splits[second].extend(points);
+    }
+
+    let mut edges = Vec::new();
+    for ((segment, mut points), shape) in segments.into_iter().zip(splits).zip(owners) {
+        let Segment { start, end } = segment;
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        let along = |p: &Point<f64>| (p.x - start.x) * dx + (p.y - start.y) * dy;
+        points.retain(|&p| p != start && p != end);
+        points.sort_by(|p, q| {
+            along(p)
+                .partial_cmp(&along(q))
+                .expect("coordinates are comparable")
+        });
+        points.dedup();
+
+        let mut previous = start;
+        for p in points.into_iter().chain(iter::once(end)) {
+            edges.push(Edge {
+                start: previous,
+                end: p,
+                shape,
+            });
+            previous = p;
+        }
+    }
+    edges
+}
+
+// Replaces a computed intersection point by an endpoint lying exactly on the other segment, so that
+// edges meeting at a vertex are split at that very vertex rather than at a rounded copy of it.
+fn snap_to_endpoint(p: Point<f64>, s: &Segment<f64>, t: &Segment<f64>) -> Point<f64> {
+    [(s.start, t), (s.end, t), (t.start, s), (t.end, s)]
+        .iter()
+        .find(|(endpoint, other)| other.contains_point(*endpoint))
+        .map_or(p, |&(endpoint, _)| endpoint)
+}
+
+fn select_edges(
+    shapes: &[Vec<Polygon<f64>>; 2],
+    mut edges: Vec<Edge>,
+    op: BooleanOp,
+) -> Vec<(Point<f64>, Point<f64>)> {
+    let key = |edge: &Edge| {
+        if edge.start < edge.end {
+            (edge.start, edge.end)
+        } else {
+            (edge.end, edge.start)
+        }
+    };
+    edges.sort_by(|e, f| {
+        key(e)
+            .partial_cmp(&key(f))
+            .expect("coordinates are comparable")
+    });
+
+    let inside = |shape: usize, edge: &Edge| {
+        let midpoint = Point::with_coords(
+            (edge.start.x + edge.end.x) / 2.0,
+            (edge.start.y + edge.end.y) / 2.0,
+        );
+        let winding: i32 = shapes[shape]
+            .iter()
+            .map(|ring| ring.winding_number(midpoint))
+            .sum();
+        winding != 0
+    };
+
+    let mut selected = Vec::new();
+    let mut group_start = 0;
+    while group_start < edges.len() {
+        let group_key = key(&edges[group_start]);
+        let group_end = group_start
+            + edges[group_start..]
+                .iter()
+                .take_while(|edge| key(edge) == group_key)
+                .count();
+        let group = &edges[group_start..group_end];
+        group_start = group_end;
+
+        let from_a = group.iter().find(|edge| edge.shape == 0);
+        let from_b = group.iter().find(|edge| edge.shape == 1);
+        let (edge, reversed) = match (from_a, from_b) {
+            (Some(a), Some(b)) => {
+                let same_direction = a.start == b.start;
+                let keep = match op {
+                    BooleanOp::Intersection | BooleanOp::Union => same_direction,
+                    BooleanOp::Difference => !same_direction,
+                    BooleanOp::Xor => false,
+                };
+                if !keep {
+                    continue;
+                }
+                (a, false)
+            }
+            (Some(a), None) => {
+                let keep = match op {
+                    BooleanOp::Intersection => inside(1, a),
+                    BooleanOp::Union | BooleanOp::Difference => !inside(1, a),
+                    BooleanOp::Xor => true,
+                };
+                if !keep {
+                    continue;
+                }
+                (a, op == BooleanOp::Xor && inside(1, a))
+            }
+            (None, Some(b)) => {
+                let keep = match op {
+                    BooleanOp::Intersection | BooleanOp::Difference => inside(0, b),
+                    BooleanOp::Union => !inside(0, b),
+                    BooleanOp::Xor => true,
+                };
+                if !keep {
+                    continue;
+                }
+                let reversed = match op {
+                    BooleanOp::Difference => true,
+                    BooleanOp::Xor => inside(0, b),
+                    _ => false,
+                };
+                (b, reversed)
+            }
+            (None, None) => unreachable!("groups are never empty"),
+        };
+        selected.push(if reversed {
+            (edge.end, edge.start)
+        } else {
+            (edge.start, edge.end)
+        });
+    }
+    selected
+}
+
+// Follows the selected edges into closed rings, always taking the leftmost turn so that every ring
+// bounds a single face and rings touching at a vertex stay apart.
+fn link_rings(mut edges: Vec<(Point<f64>, Point<f64>)>) -> Vec<Polygon<f64>> {
+    edges.sort_by(|e, f| e.partial_cmp(f).expect("coordinates are comparable"));
+    let outgoing = |v: Point<f64>| {
+        let from = edges.partition_point(|&(start, _)| start < v);
+        let to = edges.partition_point(|&(start, _)| start <= v);
+        from..to
+    };
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        let mut vertices = Vec::new();
+        let mut current = first;
+        let closed = loop {
+            used[current] = true;
+            let (start, end) = edges[current];
+            vertices.push(start);
+            let next = outgoing(end)
+                .filter(|&e| !used[e] || e == first)
+                .max_by(|&e, &f| turn_order(end, start, edges[e].1, edges[f].1));
+            match next {
+                Some(e) if e == first => break true,
+                Some(e) => current = e,
+                None => break false,
+            }
+        };
+        if closed {
+            let mut ring = Polygon::with_vertices(vertices);
+            ring.simplify_collinear();
+            if ring.vertices.len() >= 3 {
+                rings.push(ring);
+            }
+        }
+    }
+    rings
+}
+
+// Orders `p` and `q` by the counterclockwise angle from the direction of `back` as seen from `v`,
+// in `[0, 2π)`. The largest angle is the leftmost turn for a walk arriving at `v` from `back`.
+fn turn_order(v: Point<f64>, back: Point<f64>, p: Point<f64>, q: Point<f64>) -> Ordering {
+    let half = |w: Point<f64>| match orientation(v, back, w) {
+        Orientation::Counterclockwise => 0,
+        Orientation::Clockwise => 1,
+        Orientation::Collinear => {
+            let dot = (back.x - v.x) * (w.x - v.x) + (back.y - v.y) * (w.y - v.y);
+            if dot > 0.0 {
+                0
+            } else {
+                1
+            }
+        }
+    };
+    half(p)
+        .cmp(&half(q))
+        .then_with(|| match orientation(v, p, q) {
+            Orientation::Counterclockwise => Ordering::Less,
+            Orientation::Clockwise => Ordering::Greater,
+            Orientation::Collinear => Ordering::Equal,
+        })
+}
+
+// Sorts rings into exteriors and holes by winding, and puts every hole into the smallest exterior
+// enclosing it.
+fn assemble(rings: Vec<Polygon<f64>>) -> Vec<PolygonWithHoles<f64>> {
+    let (exteriors, holes): (Vec<_>, Vec<_>) = rings
+        .into_iter()
+        .partition(|ring| ring.twice_signed_area() > 0.0);
+    let mut shapes: Vec<_> = exteriors.into_iter().map(PolygonWithHoles::from).collect();
+
+    for hole in holes {
+        let area = -hole.twice_signed_area();
+        let enclosing = shapes
+            .iter_mut()
+            .filter(|shape| {
+                shape.exterior.twice_signed_area() > area
+                    && hole
+                        .vertices
+                        .iter()
+                        .all(|&p| shape.exterior.contains_point(p))
+            })
+            .min_by(|s, t| {
+                s.exterior
+                    .twice_signed_area()
+                    .partial_cmp(&t.exterior.twice_signed_area())
+                    .expect("coordinates are comparable")
+            });
+        if let Some(shape) = enclosing {
+            shape.holes.push(hole);
+        }
+    }
+    shapes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::Rng;
+    use crate::vectors::Vector;
+
+    fn polygon(coords: &[(f64, f64)]) -> Polygon<f64> {
+        Polygon::with_vertices(
+            coords
+                .iter()
+                .map(|&(x, y)| Point::with_coords(x, y))
+                .collect(),
+        )
+    }
+
+    fn rectangle(x1: f64, y1: f64, x2: f64, y2: f64) -> PolygonWithHoles<f64> {
+        polygon(&[(x1, y1), (x2, y1), (x2, y2), (x1, y2)]).into()
+    }
+
+    fn total_area(shapes: &[PolygonWithHoles<f64>]) -> f64 {
+        shapes.iter().map(PolygonWithHoles::area).sum()
+    }
+
+    fn sorted_vertices(polygon: &Polygon<f64>) -> Vec<Point<f64>> {
+        let mut vertices = polygon.vertices.clone();
+        vertices.sort_by(|p, q| p.partial_cmp(q).unwrap());
+        vertices
+    }
+
+    fn assert_well_formed(shapes: &[PolygonWithHoles<f64>]) {
+        for shape in shapes {
+            assert_eq!(shape.exterior.winding(), Orientation::Counterclockwise);
+            for hole in &shape.holes {
+                assert_eq!(hole.winding(), Orientation::Clockwise);
+            }
+        }
+    }
+
+    // Builds a star-shaped polygon around a random center, which is simple by construction.
+    fn random_star(rng: &mut Rng, n: usize) -> Polygon<f64> {
+        let (cx, cy) = (rng.unit() * 4.0, rng.unit() * 4.0);
+        polygon(
+            &(0..n)
+                .map(|k| {
+                    let angle = core::f64::consts::TAU * (k as f64 + rng.unit() * 0.8) / n as f64;
+                    let radius = 1.0 + rng.unit() * 2.0;
+                    (cx + radius * angle.cos(), cy + radius * angle.sin())
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn clips_polygon_against_convex_window() {
+        let triangle = polygon(&[(0.0, 0.0), (4.0, 0.0), (0.0, 4.0)]);
+        let window = polygon(&[(1.0, -1.0), (3.0, -1.0), (3.0, 5.0), (1.0, 5.0)]);
+        let clipped = clip_convex(&triangle, &window);
+        assert_eq!(clipped.area(), 4.0);
+        assert_eq!(
+            sorted_vertices(&clipped),
+            sorted_vertices(&polygon(&[(1.0, 0.0), (3.0, 0.0), (3.0, 1.0), (1.0, 3.0)]))
+        );
+
+        let mut clockwise = window.clone();
+        clockwise.vertices.reverse();
+        assert_eq!(clip_convex(&triangle, &clockwise).area(), 4.0);
+    }
+
+    #[test]
+    fn splits_square_through_center() {
+        let square = ConvexPolygon::from_vertices(vec![
+            Point::with_coords(0, 0),
+            Point::with_coords(4, 0),
+            Point::with_coords(4, 4),
+            Point::with_coords(0, 4),
+        ])
+        .unwrap();
+        let vertical = Line::through_points(Point::with_coords(2, -1), Point::with_coords(2, 5));
+        let (left, right) = square.split_by_line(&vertical);
+        let (left, right) = (left.unwrap(), right.unwrap());
+        assert_eq!(left.as_polygon().area(), 8.0);
+        assert_eq!(right.as_polygon().area(), 8.0);
+        assert_eq!(
+            sorted_vertices(left.as_polygon()),
+            sorted_vertices(&polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 4.0), (0.0, 4.0)]))
+        );
+        assert_eq!(
+            sorted_vertices(right.as_polygon()),
+            sorted_vertices(&polygon(&[(2.0, 0.0), (4.0, 0.0), (4.0, 4.0), (2.0, 4.0)]))
+        );
+
+        let diagonal = Line::through_points(Point::with_coords(0, 0), Point::with_coords(1, 1));
+        let (above, below) = square.split_by_line(&diagonal);
+        assert_eq!(above.unwrap().as_polygon().area(), 8.0);
+        assert_eq!(below.unwrap().as_polygon().area(), 8.0);
+    }
+
+    #[test]
+    fn splits_polygon_on_one_side_of_line() {
+        let triangle = ConvexPolygon::from_vertices(vec![
+            Point::with_coords(0, 0),
+            Point::with_coords(4, 0),
+            Point::with_coords(0, 4),
+        ])
+        .unwrap();
+        let along_edge = Line::through_points(Point::with_coords(0, 0), Point::with_coords(1, 0));
+        let (left, right) = triangle.split_by_line(&along_edge);
+        assert_eq!(left.unwrap().as_polygon().area(), 8.0);
+        assert_eq!(right, None);
+
+        let far =
+            Line::with_point_and_direction(Point::with_coords(9, 9), Vector::with_coords(1, 0));
+        let (left, right) = triangle.split_by_line(&far);
+        assert_eq!(left, None);
+        assert_eq!(right.unwrap().vertices().len(), 3);
+    }
+
+    #[test]
+    fn clips_polygon_inside_or_outside_window() {
+        let square = polygon(&[(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0)]);
+        let window = polygon(&[(0.0, 0.0), (5.0, 0.0), (0.0, 5.0)]);
+        assert_eq!(clip_convex(&square, &window), square);
+
+        let far = polygon(&[(10.0, 10.0), (11.0, 10.0), (11.0, 11.0)]);
+        assert!(clip_convex(&far, &window).vertices.is_empty());
+        let degenerate = polygon(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]);
+        assert!(clip_convex(&square, &degenerate).vertices.is_empty());
+    }
+
+    #[test]
+    fn clips_polygon_to_box() {
+        let diamond = Polygon::with_vertices(vec![
+            Point::with_coords(0, -10),
+            Point::with_coords(10, 0),
+            Point::with_coords(0, 10),
+            Point::with_coords(-10, 0),
+        ]);
+        let viewport = Aabb::with_corners(Point::with_coords(-2, -2), Point::with_coords(3, 1));
+        let clipped = diamond.clip_to_aabb(&viewport);
+        assert_eq!(clipped.area(), 15.0);
+        assert_eq!(
+            sorted_vertices(&clipped),
+            sorted_vertices(&polygon(&[
+                (-2.0, -2.0),
+                (3.0, -2.0),
+                (3.0, 1.0),
+                (-2.0, 1.0)
+            ]))
+        );
+
+        let corner = Aabb::with_corners(Point::with_coords(8, 8), Point::with_coords(12, 12));
+        assert!(diamond.clip_to_aabb(&corner).vertices.is_empty());
+        let touching = Aabb::with_corners(Point::with_coords(10, -1), Point::with_coords(12, 1));
+        assert!(diamond.clip_to_aabb(&touching).vertices.is_empty());
+    }
+
+    #[test]
+    fn combines_overlapping_squares() {
+        let a = rectangle(0.0, 0.0, 2.0, 2.0);
+        let b = rectangle(1.0, 1.0, 3.0, 3.0);
+
+        let common = intersection(&a, &b);
+        assert_eq!(common.len(), 1);
+        assert_eq!(
+            sorted_vertices(&common[0].exterior),
+            sorted_vertices(&rectangle(1.0, 1.0, 2.0, 2.0).exterior)
+        );
+
+        let merged = union(&a, &b);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].exterior.vertices.len(), 8);
+        assert_eq!(total_area(&merged), 7.0);
+
+        let remainder = difference(&a, &b);
+        assert_eq!(remainder.len(), 1);
+        assert_eq!(remainder[0].exterior.vertices.len(), 6);
+        assert_eq!(total_area(&remainder), 3.0);
+
+        for shapes in [common, merged, remainder].iter() {
+            assert_well_formed(shapes);
+        }
+    }
+
+    #[test]
+    fn combines_integer_polygons_into_float_pieces() {
+        let square = |x1, y1, x2, y2| {
+            Polygon::with_vertices(vec![
+                Point::with_coords(x1, y1),
+                Point::with_coords(x2, y1),
+                Point::with_coords(x2, y2),
+                Point::with_coords(x1, y2),
+            ])
+        };
+        let (a, b) = (square(0, 0, 4, 4), square(2, 2, 6, 6));
+
+        let common = intersection(&a, &b);
+        assert_eq!(common.len(), 1);
+        assert_eq!(
+            sorted_vertices(&common[0]),
+            sorted_vertices(&rectangle(2.0, 2.0, 4.0, 4.0).exterior)
+        );
+        let merged = union(&a, &b);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].area(), 28.0);
+        assert_eq!(difference(&a, &b)[0].area(), 12.0);
+        let leftovers = xor(&a, &b);
+        assert_eq!(leftovers.len(), 2);
+        assert!(leftovers.iter().all(|piece| piece.area() == 12.0));
+
+        let punched = difference(&a, &square(1, 1, 2, 2));
+        assert_eq!(punched.len(), 2);
+        assert!(punched[0].is_counterclockwise());
+        assert!(!punched[1].is_counterclockwise());
+        let net: f64 = punched.iter().map(Polygon::signed_area).sum();
+        assert_eq!(net, 15.0);
+    }
+
+    #[test]
+    fn combines_identical_squares() {
+        let a = rectangle(0.0, 0.0, 2.0, 2.0);
+        for op in [BooleanOp::Intersection, BooleanOp::Union].iter() {
+            let shapes = overlay(&a, &a, *op);
+            assert_eq!(shapes.len(), 1);
+            assert_eq!(
+                sorted_vertices(&shapes[0].exterior),
+                sorted_vertices(&a.exterior)
+            );
+        }
+        assert!(difference(&a, &a).is_empty());
+    }
+
+    #[test]
+    fn splits_overlapping_squares_by_symmetric_difference() {
+        let a = rectangle(0.0, 0.0, 4.0, 4.0);
+        let b = rectangle(2.0, 2.0, 6.0, 6.0);
+        let leftovers = xor(&a, &b);
+        assert_eq!(leftovers.len(), 2);
+        assert!(leftovers
+            .iter()
+            .all(|shape| shape.exterior.vertices.len() == 6 && shape.area() == 12.0));
+        assert_well_formed(&leftovers);
+
+        let inner = rectangle(1.0, 1.0, 2.0, 2.0);
+        let punched = xor(&a, &inner);
+        assert_eq!(punched, difference(&a, &inner));
+        assert!(xor(&a, &a).is_empty());
+        assert_eq!(
+            xor(&a, &rectangle(4.0, 0.0, 6.0, 4.0)),
+            vec![rectangle(0.0, 0.0, 6.0, 4.0)]
+        );
+    }
+
+    #[test]
+    fn merges_squares_sharing_an_edge() {
+        let a = rectangle(0.0, 0.0, 1.0, 1.0);
+        let b = rectangle(1.0, 0.0, 2.0, 1.0);
+        let merged = union(&a, &b);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(
+            sorted_vertices(&merged[0].exterior),
+            sorted_vertices(&rectangle(0.0, 0.0, 2.0, 1.0).exterior)
+        );
+        assert!(intersection(&a, &b).is_empty());
+        assert_eq!(total_area(&difference(&a, &b)), 1.0);
+    }
+
+    #[test]
+    fn merges_squares_along_partially_shared_edge() {
+        let a = rectangle(0.0, 0.0, 2.0, 2.0);
+        let b = rectangle(2.0, 1.0, 3.0, 4.0);
+        let merged = union(&a, &b);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].exterior.vertices.len(), 8);
+        assert_eq!(total_area(&merged), 7.0);
+    }
+
+    #[test]
+    fn keeps_squares_touching_at_a_corner_apart() {
+        let a = rectangle(0.0, 0.0, 1.0, 1.0);
+        let b = rectangle(1.0, 1.0, 2.0, 2.0);
+        let merged = union(&a, &b);
+        assert_eq!(merged.len(), 2);
+        assert!(merged
+            .iter()
+            .all(|shape| shape.exterior.vertices.len() == 4));
+        assert!(intersection(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn handles_disjoint_and_nested_polygons() {
+        let outer = rectangle(0.0, 0.0, 4.0, 4.0);
+        let inner = rectangle(1.0, 1.0, 2.0, 2.0);
+        let far = rectangle(10.0, 10.0, 11.0, 11.0);
+
+        assert_eq!(union(&outer, &far).len(), 2);
+        assert!(intersection(&outer, &far).is_empty());
+        assert_eq!(difference(&outer, &far), vec![outer.clone()]);
+
+        assert_eq!(union(&outer, &inner), vec![outer.clone()]);
+        assert_eq!(intersection(&outer, &inner), vec![inner.clone()]);
+        let punched = difference(&outer, &inner);
+        assert_eq!(punched.len(), 1);
+        assert_eq!(punched[0].holes.len(), 1);
+        assert_eq!(total_area(&punched), 15.0);
+        assert_well_formed(&punched);
+        assert!(difference(&inner, &outer).is_empty());
+    }
+
+    #[test]
+    fn fills_hole_of_frame() {
+        let frame = PolygonWithHoles::with_exterior_and_holes(
+            rectangle(0.0, 0.0, 6.0, 6.0).exterior,
+            vec![rectangle(2.0, 2.0, 4.0, 4.0).exterior],
+        );
+        let patch = rectangle(1.0, 1.0, 5.0, 5.0);
+
+        let filled = union(&frame, &patch);
+        assert_eq!(filled, vec![rectangle(0.0, 0.0, 6.0, 6.0)]);
+        let ring = intersection(&frame, &patch);
+        assert_eq!(ring.len(), 1);
+        assert_eq!(ring[0].holes.len(), 1);
+        assert_eq!(total_area(&ring), 12.0);
+        assert_eq!(total_area(&difference(&frame, &patch)), 20.0);
+        assert_eq!(total_area(&difference(&patch, &frame)), 4.0);
+    }
+
+    #[test]
+    fn produces_area_consistent_results_on_random_polygons() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for round in 0..200 {
+            let a: PolygonWithHoles<f64> = random_star(&mut rng, 3 + round % 17).into();
+            let b: PolygonWithHoles<f64> = random_star(&mut rng, 3 + round % 13).into();
+
+            let both = intersection(&a, &b);
+            let either = union(&a, &b);
+            let only_a = difference(&a, &b);
+            let only_b = difference(&b, &a);
+            let exactly_one = xor(&a, &b);
+            for shapes in [&both, &either, &only_a, &only_b, &exactly_one].iter() {
+                assert_well_formed(shapes);
+            }
+
+            let (area_a, area_b) = (a.area(), b.area());
+            let common = total_area(&both);
+            let tolerance = 1e-9 * (area_a + area_b);
+            assert!((total_area(&either) - (area_a + area_b - common)).abs() < tolerance);
+            assert!((total_area(&only_a) - (area_a - common)).abs() < tolerance);
+            assert!((total_area(&only_b) - (area_b - common)).abs() < tolerance);
+            let expected = area_a + area_b - 2.0 * common;
+            assert!((total_area(&exactly_one) - expected).abs() < tolerance);
+        }
+    }
+
+    #[test]
+    fn produces_area_consistent_results_on_degenerate_rectangles() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..300 {
+            let mut coordinate = || (rng.next() % 5) as f64;
+            let mut random_rectangle = || {
+                let (x1, x2) = (coordinate(), coordinate());
+                let (y1, y2) = (coordinate(), coordinate());
+                let (x1, x2) = (x1.min(x2), x1.max(x2) + 1.0);
+                let (y1, y2) = (y1.min(y2), y1.max(y2) + 1.0);
+                rectangle(x1, y1, x2, y2)
+            };
+            let a = random_rectangle();
+            let b = random_rectangle();
+
+            let common = total_area(&intersection(&a, &b));
+            let expected = {
+                let (pa, pb) = (&a.exterior.vertices, &b.exterior.vertices);
+                let width = (pa[2].x.min(pb[2].x) - pa[0].x.max(pb[0].x)).max(0.0);
+                let height = (pa[2].y.min(pb[2].y) - pa[0].y.max(pb[0].y)).max(0.0);
+                width * height
+            };
+            assert_eq!(common, expected, "{:?} {:?}", a, b);
+            assert_eq!(total_area(&union(&a, &b)), a.area() + b.area() - common);
+            assert_eq!(total_area(&difference(&a, &b)), a.area() - common);
+        }
+    }
+}
@@ -0,0 +1,1091 @@
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::hull::convex_hull;
+use crate::meta::Coord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::meta::FloatCoord;
+use crate::polygon::Polygon;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::primitives::{orientation_by_ref, to_f64, to_f64_point};
+use crate::primitives::{Point, Segment};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::vectors::Orientation;
+use crate::vectors::Vector;
+#[cfg(any(feature = "std", feature = "libm"))]
+use alloc::vec::Vec;
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
+use num_traits::Bounded;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::{Float, FloatConst, ToPrimitive};
+
+/// An axis-aligned bounding box, closed on every side. `min` never exceeds `max` in either
+/// coordinate, so a box may degenerate to a segment or a single point but is never empty, except
+/// for the inverted `Aabb::default()`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb<T>
+where
+    T: Coord,
+{
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T> Aabb<T>
+where
+    T: Coord,
+{
+    /// Same as `with_corners`.
+    pub fn new(a: Point<T>, b: Point<T>) -> Self {
+        Self::with_corners(a, b)
+    }
+
+    /// Spans the box between two opposite corners given in either order.
+    pub fn with_corners(a: Point<T>, b: Point<T>) -> Self {
+        let (min_x, max_x) = min_max(a.x, b.x);
+        let (min_y, max_y) = min_max(a.y, b.y);
+        Self {
+            min: Point::with_coords(min_x, min_y),
+            max: Point::with_coords(max_x, max_y),
+        }
+    }
+
+    /// Returns the smallest box containing every point, or `None` if there are no points.
+    pub fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut aabb = Self {
+            min: first.clone(),
+            max: first,
+        };
+        for p in points {
+            aabb.extend_to(p);
+        }
+        Some(aabb)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x.clone() - self.min.x.clone()
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y.clone() - self.min.y.clone()
+    }
+
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    /// Grows the box just enough to contain `p`.
+    pub fn extend_to(&mut self, p: Point<T>) {
+        if p.x < self.min.x {
+            self.min.x = p.x.clone();
+        }
+        if p.x > self.max.x {
+            self.max.x = p.x;
+        }
+        if p.y < self.min.y {
+            self.min.y = p.y.clone();
+        }
+        if p.y > self.max.y {
+            self.max.y = p.y;
+        }
+    }
+
+    /// Points on the boundary are contained.
+    pub fn contains_point(&self, p: Point<T>) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+    }
+
+    /// Tests whether `other` lies inside, possibly touching the boundary from within.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.min.x <= other.min.x
+            && other.max.x <= self.max.x
+            && self.min.y <= other.min.y
+            && other.max.y <= self.max.y
+    }
+
+    /// Boxes that only share boundary points intersect.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// Returns the smallest box containing both.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Point::with_coords(
+                lesser(&self.min.x, &other.min.x).clone(),
+                lesser(&self.min.y, &other.min.y).clone(),
+            ),
+            max: Point::with_coords(
+                greater(&self.max.x, &other.max.x).clone(),
+                greater(&self.max.y, &other.max.y).clone(),
+            ),
+        }
+    }
+
+    /// Returns the overlap, which is degenerate if the boxes only touch, or `None` if they do not
+    /// intersect.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Self {
+            min: Point::with_coords(
+                greater(&self.min.x, &other.min.x).clone(),
+                greater(&self.min.y, &other.min.y).clone(),
+            ),
+            max: Point::with_coords(
+                lesser(&self.max.x, &other.max.x).clone(),
+                lesser(&self.max.y, &other.max.y).clone(),
+            ),
+        })
+    }
+}
+
+/// Returns an empty box with `min` at the largest and `max` at the smallest coordinates, which
+/// contains no point and leaves any box unchanged under `union`, so it can start a fold. Its
+/// width, height and area are meaningless and overflow for integer coordinates.
+impl<T> Default for Aabb<T>
+where
+    T: Coord + Bounded,
+{
+    fn default() -> Self {
+        Self {
+            min: Point::with_coords(T::max_value(), T::max_value()),
+            max: Point::with_coords(T::min_value(), T::min_value()),
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Circle<T>
+where
+    T: Coord,
+{
+    pub center: Point<T>,
+    pub radius: T,
+}
+
+impl<T> Circle<T>
+where
+    T: Coord,
+{
+    /// Same as `with_center_and_radius`.
+    pub fn new(center: Point<T>, radius: T) -> Self {
+        Self::with_center_and_radius(center, radius)
+    }
+
+    pub fn with_center_and_radius(center: Point<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+
+    /// Points on the circle are contained. Squared distances are compared, so the test is exact
+    /// for integer coordinates.
+    pub fn contains_point(&self, p: Point<T>) -> bool {
+        (p - &self.center).norm_squared() <= self.radius.clone() * self.radius.clone()
+    }
+
+    /// Circles touching the box intersect it. The point of the box closest to the center is
+    /// found by clamping, so the test is exact for integer coordinates.
+    pub fn intersects_aabb(&self, aabb: &Aabb<T>) -> bool {
+        let clamp = |c: &T, min: &T, max: &T| greater(min, lesser(c, max)).clone();
+        let closest = Point::with_coords(
+            clamp(&self.center.x, &aabb.min.x, &aabb.max.x),
+            clamp(&self.center.y, &aabb.min.y, &aabb.max.y),
+        );
+        self.contains_point(closest)
+    }
+
+    /// Tests whether the circle overlaps the area enclosed by the polygon, including the case of
+    /// either lying inside the other. Distances to edges are compared squared, so the test is
+    /// exact for integer coordinates small enough for fourth-degree products not to overflow.
+    pub fn intersects_polygon(&self, polygon: &Polygon<T>) -> bool {
+        polygon.contains_point(self.center.clone())
+            || polygon.edges().any(|edge| self.reaches_segment(&edge))
+    }
+
+    fn reaches_segment(&self, segment: &Segment<T>) -> bool {
+        let (a, b, p) = (&segment.start, &segment.end, &self.center);
+        let direction = b - a;
+        if (p - a).dot(direction.clone()) <= T::zero() {
+            return self.contains_point(a.clone());
+        }
+        if (p - b).dot(-direction.clone()) <= T::zero() {
+            return self.contains_point(b.clone());
+        }
+        let cross = direction.clone().cross(p - a);
+        let radius_squared = self.radius.clone() * self.radius.clone();
+        cross.clone() * cross <= radius_squared * direction.norm_squared()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Circle<T>
+where
+    T: Coord + ToPrimitive,
+{
+    /// Returns the circle through the three points, or `None` if they are collinear, which is
+    /// decided exactly.
+    pub fn from_three_points(a: Point<T>, b: Point<T>, c: Point<T>) -> Option<Circle<f64>> {
+        if orientation_by_ref(&a, &b, &c) == Orientation::Collinear {
+            return None;
+        }
+        Some(circumcircle(
+            to_f64_point(&a),
+            to_f64_point(&b),
+            to_f64_point(&c),
+        ))
+    }
+
+    /// Places a circle of `radius` so that it touches `tangent_to` from outside, with its center
+    /// on the ray from the center of `tangent_to` through `p`. Returns `None` if `p` is that
+    /// center, which leaves the direction undefined.
+    pub fn tangent_externally(
+        p: Point<T>,
+        radius: T,
+        tangent_to: &Circle<T>,
+    ) -> Option<Circle<f64>> {
+        let center = to_f64_point(&tangent_to.center);
+        let direction = to_f64_point(&p) - center;
+        if direction == Vector::zero() {
+            return None;
+        }
+        let radius = to_f64(&radius);
+        let distance = to_f64(&tangent_to.radius) + radius;
+        Some(Circle::with_center_and_radius(
+            center + direction.normalized() * distance,
+            radius,
+        ))
+    }
+
+    pub fn circumference(&self) -> f64 {
+        f64::TAU() * to_f64(&self.radius)
+    }
+
+    /// Returns the length of an arc covering `radians`, which is negative for negative angles.
+    pub fn arc_length(&self, radians: f64) -> f64 {
+        radians * to_f64(&self.radius)
+    }
+
+    /// Returns the point of the circle at `radians` counterclockwise from the positive x axis.
+    pub fn point_at_angle(&self, radians: f64) -> Point<f64> {
+        let (sin, cos) = Float::sin_cos(radians);
+        to_f64_point(&self.center) + Vector::with_coords(cos, sin) * to_f64(&self.radius)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Circle<T>
+where
+    T: Coord + AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.abs_diff_eq(&other.center, epsilon)
+            && self.radius.abs_diff_eq(&other.radius, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> RelativeEq for Circle<T>
+where
+    T: Coord + RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.center
+            .relative_eq(&other.center, epsilon, max_relative)
+            && self
+                .radius
+                .relative_eq(&other.radius, epsilon, max_relative)
+    }
+}
+
+/// A rectangle in any orientation. `axis` is the unit direction of one pair of sides, and
+/// `half_extents` holds half the side lengths along `axis` and perpendicular to it.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrientedBox<T>
+where
+    T: Coord,
+{
+    pub center: Point<T>,
+    pub axis: Vector<T>,
+    pub half_extents: Vector<T>,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> OrientedBox<T>
+where
+    T: FloatCoord,
+{
+    pub fn area(&self) -> T {
+        let two = T::one() + T::one();
+        self.half_extents.x * two * self.half_extents.y * two
+    }
+
+    /// Returns the corners counterclockwise, starting from the one furthest against both axes.
+    pub fn corners(&self) -> [Point<T>; 4] {
+        let u = self.axis * self.half_extents.x;
+        let v = Vector::with_coords(-self.axis.y, self.axis.x) * self.half_extents.y;
+        [
+            self.center - u - v,
+            self.center + u - v,
+            self.center + u + v,
+            self.center - u + v,
+        ]
+    }
+}
+
+/// Computes the smallest circle containing every point, or `None` if there are no points, with
+/// the iterative form of Welzl's algorithm.
+///
+/// The points are first shuffled with a fixed seed, which gives expected linear time unless the
+/// input happens to be ordered against that particular shuffle. Points within a relative
+/// distance of `epsilon.sqrt()` outside the current circle count as contained, so that rounding
+/// in the circumcenters cannot make points on the circle grow it again.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn min_enclosing_circle<T>(points: &[Point<T>]) -> Option<Circle<T>>
+where
+    T: FloatCoord,
+{
+    let mut points = points.to_vec();
+    shuffle(&mut points);
+
+    let mut circle = Circle::with_center_and_radius(*points.first()?, T::zero());
+    for i in 1..points.len() {
+        if covers(&circle, points[i]) {
+            continue;
+        }
+        circle = Circle::with_center_and_radius(points[i], T::zero());
+        for j in 0..i {
+            if covers(&circle, points[j]) {
+                continue;
+            }
+            circle = diametral_circle(points[i], points[j]);
+            for k in 0..j {
+                if !covers(&circle, points[k]) {
+                    circle = circumcircle(points[i], points[j], points[k]);
+                }
+            }
+        }
+    }
+    Some(circle)
+}
+
+/// Returns a circle around the mean of the points that reaches the farthest of them, or `None` if
+/// there are no points. It is cheaper than `min_enclosing_circle` but may be up to twice as
+/// large, which suits quick culling.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn bounding_circle<T>(points: &[Point<T>]) -> Option<Circle<f64>>
+where
+    T: Coord + ToPrimitive,
+{
+    if points.is_empty() {
+        return None;
+    }
+    let points: Vec<Point<f64>> = points.iter().map(to_f64_point).collect();
+    let sum = points
+        .iter()
+        .fold(Vector::zero(), |sum, &p| sum + (p - Point::origin()));
+    let center = Point::origin() + sum / points.len() as f64;
+    Some(circle_around(center, &points))
+}
+
+/// Lays out circles of `radius` inside `bounds` in rows from the minimum corner, each touching its
+/// neighbours without overlapping. Rows are stacked squarely, or with `hex` offset by a radius and
+/// moved closer in the hexagonal arrangement. Nothing fits for a radius that is not positive.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn pack_circles_grid<T>(bounds: &Aabb<T>, radius: f64, hex: bool) -> Vec<Circle<f64>>
+where
+    T: Coord + ToPrimitive,
+{
+    let mut circles = Vec::new();
+    if radius <= 0.0 {
+        return circles;
+    }
+    let (min, max) = (to_f64_point(&bounds.min), to_f64_point(&bounds.max));
+    let row_spacing = if hex {
+        radius * Float::sqrt(3.0)
+    } else {
+        2.0 * radius
+    };
+
+    let mut y = min.y + radius;
+    let mut row = 0;
+    while y + radius <= max.y {
+        let mut x = min.x + radius;
+        if hex && row % 2 == 1 {
+            x += radius;
+        }
+        while x + radius <= max.x {
+            circles.push(Circle::with_center_and_radius(
+                Point::with_coords(x, y),
+                radius,
+            ));
+            x += 2.0 * radius;
+        }
+        y += row_spacing;
+        row += 1;
+    }
+    circles
+}
+
+/// Returns the smallest circle about `center` for which `Circle::contains_point` holds for every
+/// point.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) fn circle_around(center: Point<f64>, points: &[Point<f64>]) -> Circle<f64> {
+    let farthest = points
+        .iter()
+        .map(|&p| (p - center).norm_squared())
+        .fold(0.0, f64::max);
+    // The square root rounds to nearest, so squaring it back may fall short by an ulp or two.
+    let mut radius = Float::sqrt(farthest);
+    while radius * radius < farthest {
+        radius += radius * f64::EPSILON;
+    }
+    Circle::with_center_and_radius(center, radius)
+}
+
+/// Computes the minimum-area enclosing rectangle, or `None` if there are no points, with rotating
+/// calipers over the convex hull; one side of such a rectangle always lies along a hull edge.
+///
+/// Apart from computing the hull this takes linear time. A single point gives a box aligned with
+/// the x axis with zero extents, and collinear points give a box of zero width along their line.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn oriented_bounding_box<T>(points: &[Point<T>]) -> Option<OrientedBox<T>>
+where
+    T: FloatCoord,
+{
+    let hull = convex_hull(points).vertices;
+    let n = hull.len();
+    match n {
+        0 => return None,
+        1 => {
+            return Some(OrientedBox {
+                center: hull[0],
+                axis: Vector::i_hat(),
+                half_extents: Vector::zero(),
+            })
+        }
+        _ => {}
+    }
+
+    // `right`, `top` and `left` track the vertices furthest along the edge, away from it and
+    // against it; as the edge turns counterclockwise they only ever move forward.
+    let two = T::one() + T::one();
+    let (mut right, mut top, mut left) = (1, 1, 1);
+    let mut best: Option<(T, OrientedBox<T>)> = None;
+    for i in 0..n {
+        let origin = hull[i];
+        let u = (hull[(i + 1) % n] - origin).normalized();
+        let v = Vector::with_coords(-u.y, u.x);
+        let along = |k: usize| (hull[k] - origin).dot(u);
+        let across = |k: usize| (hull[k] - origin).dot(v);
+
+        right = climb(right, n, along);
+        if i == 0 {
+            top = right;
+        }
+        top = climb(top, n, across);
+        if i == 0 {
+            left = top;
+        }
+        left = climb(left, n, |k| -along(k));
+
+        let (min_u, max_u, max_v) = (along(left), along(right), across(top));
+        let area = (max_u - min_u) * max_v;
+        let smaller = match &best {
+            Some((smallest, _)) => area < *smallest,
+            None => true,
+        };
+        if smaller {
+            let candidate = OrientedBox {
+                center: origin + u * ((min_u + max_u) / two) + v * (max_v / two),
+                axis: u,
+                half_extents: Vector::with_coords((max_u - min_u) / two, max_v / two),
+            };
+            best = Some((area, candidate));
+        }
+    }
+    best.map(|(_, candidate)| candidate)
+}
+
+// Walks forward around the hull from `k` while `value` increases, which ends at the maximum
+// because `value` is unimodal around a convex polygon.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn climb<T>(mut k: usize, n: usize, value: impl Fn(usize) -> T) -> usize
+where
+    T: FloatCoord,
+{
+    while value((k + 1) % n) > value(k) {
+        k = (k + 1) % n;
+    }
+    k
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn covers<T>(circle: &Circle<T>, p: Point<T>) -> bool
+where
+    T: FloatCoord,
+{
+    (p - circle.center).magnitude() <= circle.radius * (T::one() + T::epsilon().sqrt())
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn diametral_circle<T>(a: Point<T>, b: Point<T>) -> Circle<T>
+where
+    T: FloatCoord,
+{
+    let half = (b - a) / (T::one() + T::one());
+    Circle::with_center_and_radius(a + half, half.magnitude())
+}
+
+// Falls back to the widest diametral circle if the points are collinear, which only happens for
+// inputs that rounding has pushed off their circle.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn circumcircle<T>(a: Point<T>, b: Point<T>, c: Point<T>) -> Circle<T>
+where
+    T: FloatCoord,
+{
+    let (ab, ac) = (b - a, c - a);
+    let denominator = ab.cross(ac) * (T::one() + T::one());
+    if denominator.is_zero() {
+        let mut widest = diametral_circle(a, b);
+        for circle in [diametral_circle(a, c), diametral_circle(b, c)] {
+            if circle.radius > widest.radius {
+                widest = circle;
+            }
+        }
+        return widest;
+    }
+
+    let (ab_squared, ac_squared) = (ab.norm_squared(), ac.norm_squared());
+    let offset = Vector::with_coords(
+        ac.y * ab_squared - ab.y * ac_squared,
+        ab.x * ac_squared - ac.x * ab_squared,
+    ) / denominator;
+    Circle::with_center_and_radius(a + offset, offset.magnitude())
+}
+
+// A Fisher–Yates shuffle driven by xorshift with a fixed seed, so that results are reproducible.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn shuffle<T>(items: &mut [T]) {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        items.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+fn min_max<T>(a: T, b: T) -> (T, T)
+where
+    T: PartialOrd,
+{
+    if b < a {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
+
+fn lesser<'a, T>(a: &'a T, b: &'a T) -> &'a T
+where
+    T: PartialOrd,
+{
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn greater<'a, T>(a: &'a T, b: &'a T) -> &'a T
+where
+    T: PartialOrd,
+{
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aabb(x1: i64, y1: i64, x2: i64, y2: i64) -> Aabb<i64> {
+        Aabb::with_corners(Point::with_coords(x1, y1), Point::with_coords(x2, y2))
+    }
+
+    #[test]
+    fn builds_aabb_from_points() {
+        let points = [
+            Point::with_coords(3, -1),
+            Point::with_coords(-2, 4),
+            Point::with_coords(0, 0),
+            Point::with_coords(5, 2),
+        ];
+        let bounds = Aabb::from_points(points.iter().copied()).unwrap();
+        assert_eq!(bounds, aabb(-2, -1, 5, 4));
+        assert_eq!((bounds.width(), bounds.height(), bounds.area()), (7, 5, 35));
+        assert!(points.iter().all(|&p| bounds.contains_point(p)));
+
+        assert_eq!(Aabb::<i64>::from_points(Vec::new()), None);
+        let single = Aabb::from_points(vec![Point::with_coords(1, 1)]).unwrap();
+        assert_eq!(single.area(), 0);
+    }
+
+    #[test]
+    fn normalizes_aabb_corners() {
+        assert_eq!(aabb(4, 0, 1, 3), aabb(1, 3, 4, 0));
+        assert_eq!(aabb(4, 0, 1, 3).min, Point::with_coords(1, 0));
+        assert_eq!(aabb(4, 0, 1, 3).max, Point::with_coords(4, 3));
+    }
+
+    #[test]
+    fn extends_aabb_to_points() {
+        let mut bounds = aabb(0, 0, 1, 1);
+        bounds.extend_to(Point::with_coords(3, -2));
+        bounds.extend_to(Point::with_coords(-1, 0));
+        assert_eq!(bounds, aabb(-1, -2, 3, 1));
+
+        let mut empty = Aabb::default();
+        empty.extend_to(Point::with_coords(2, 5));
+        assert_eq!(empty, aabb(2, 5, 2, 5));
+    }
+
+    #[test]
+    fn folds_points_into_default_aabb() {
+        let points = [
+            Point::with_coords(3, -1),
+            Point::with_coords(-2, 4),
+            Point::with_coords(5, 2),
+        ];
+        let folded = points
+            .iter()
+            .fold(Aabb::default(), |bounds, &p| bounds.union(&Aabb::new(p, p)));
+        assert_eq!(Some(folded), Aabb::from_points(points.iter().copied()));
+
+        let empty: Aabb<i64> = Aabb::default();
+        assert_eq!(empty.union(&aabb(0, 0, 1, 1)), aabb(0, 0, 1, 1));
+        assert!(!empty.contains_point(Point::origin()));
+        assert!(!empty.intersects(&aabb(-5, -5, 5, 5)));
+    }
+
+    #[test]
+    fn constructs_shapes_with_new() {
+        let (p, q) = (Point::with_coords(1, 2), Point::with_coords(-3, 4));
+        assert_eq!(Segment::new(p, q), Segment::with_endpoints(p, q));
+        assert_eq!(Circle::new(p, 3), Circle::with_center_and_radius(p, 3));
+        assert_eq!(Aabb::new(p, q), Aabb::with_corners(p, q));
+        assert_eq!(Polygon::new(vec![p, q]), Polygon::with_vertices(vec![p, q]));
+    }
+
+    #[test]
+    fn tests_containment_in_aabb() {
+        let bounds = aabb(0, 0, 4, 2);
+        assert!(bounds.contains_point(Point::with_coords(4, 1)));
+        assert!(!bounds.contains_point(Point::with_coords(5, 1)));
+        assert!(bounds.contains(&aabb(1, 0, 4, 1)));
+        assert!(bounds.contains(&bounds));
+        assert!(!bounds.contains(&aabb(1, 1, 5, 2)));
+    }
+
+    #[test]
+    fn combines_aabbs() {
+        let (a, b) = (aabb(0, 0, 4, 2), aabb(2, 1, 6, 5));
+        assert_eq!(a.union(&b), aabb(0, 0, 6, 5));
+        assert_eq!(a.intersection(&b), Some(aabb(2, 1, 4, 2)));
+        assert!(a.intersects(&b));
+
+        let touching = aabb(4, 2, 7, 3);
+        assert_eq!(a.intersection(&touching), Some(aabb(4, 2, 4, 2)));
+        let disjoint = aabb(5, 0, 6, 1);
+        assert!(!a.intersects(&disjoint));
+        assert_eq!(a.intersection(&disjoint), None);
+        assert_eq!(a.union(&disjoint), aabb(0, 0, 6, 2));
+    }
+
+    #[test]
+    fn tests_overlap_of_circle_and_aabb() {
+        let bounds = aabb(0, 0, 4, 3);
+        let circle = |x, y, r| Circle::with_center_and_radius(Point::with_coords(x, y), r);
+        assert!(circle(6, 5, 3).intersects_aabb(&bounds));
+        assert!(!circle(7, 6, 4).intersects_aabb(&bounds));
+        assert!(circle(7, 6, 5).intersects_aabb(&bounds));
+        assert!(circle(2, 1, 1).intersects_aabb(&bounds));
+        assert!(circle(2, 1, 100).intersects_aabb(&bounds));
+        assert!(circle(2, 5, 2).intersects_aabb(&bounds));
+        assert!(!circle(2, 6, 2).intersects_aabb(&bounds));
+        assert!(!circle(-10, 1, 3).intersects_aabb(&bounds));
+    }
+
+    #[test]
+    fn tests_overlap_of_circle_and_polygon() {
+        let triangle = Polygon::with_vertices(vec![
+            Point::with_coords(0, 0),
+            Point::with_coords(8, 0),
+            Point::with_coords(0, 8),
+        ]);
+        let circle = |x, y, r| Circle::with_center_and_radius(Point::with_coords(x, y), r);
+        assert!(circle(2, 2, 1).intersects_polygon(&triangle));
+        assert!(circle(2, 2, 50).intersects_polygon(&triangle));
+        assert!(circle(7, 7, 5).intersects_polygon(&triangle));
+        assert!(!circle(7, 7, 4).intersects_polygon(&triangle));
+        assert!(circle(-3, -4, 5).intersects_polygon(&triangle));
+        assert!(!circle(-3, -4, 4).intersects_polygon(&triangle));
+        assert!(circle(4, -2, 2).intersects_polygon(&triangle));
+        assert!(!circle(20, 3, 5).intersects_polygon(&triangle));
+    }
+
+    #[test]
+    fn tests_containment_in_circle_exactly() {
+        let circle = Circle::with_center_and_radius(Point::with_coords(1, 1), 5);
+        assert!(circle.contains_point(Point::with_coords(4, 5)));
+        assert!(!circle.contains_point(Point::with_coords(5, 5)));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    mod float {
+        use super::*;
+        use crate::test_utils::random_points;
+        use alloc::vec::Vec;
+
+        #[test]
+        fn packs_circles_in_grid() {
+            let bounds = Aabb::with_corners(Point::with_coords(0, 0), Point::with_coords(10, 6));
+            let check = |circles: &[Circle<f64>]| {
+                for (i, a) in circles.iter().enumerate() {
+                    assert!(a.center.x - a.radius >= 0.0 && a.center.x + a.radius <= 10.0);
+                    assert!(a.center.y - a.radius >= 0.0 && a.center.y + a.radius <= 6.0);
+                    for b in &circles[i + 1..] {
+                        assert!((a.center - b.center).magnitude() >= 2.0 - 1e-9);
+                    }
+                }
+            };
+
+            let square = pack_circles_grid(&bounds, 1.0, false);
+            assert_eq!(square.len(), 15);
+            assert_eq!(square[0].center, Point::with_coords(1.0, 1.0));
+            check(&square);
+
+            let hex = pack_circles_grid(&bounds, 1.0, true);
+            assert_eq!(hex.len(), 14);
+            assert_eq!(hex[5].center.x, 2.0);
+            check(&hex);
+
+            assert!(pack_circles_grid(&bounds, 4.0, false).is_empty());
+            assert!(pack_circles_grid(&bounds, 0.0, true).is_empty());
+        }
+
+        fn float_points(seed: u64, n: usize, range: i64) -> Vec<Point<f64>> {
+            random_points(seed, n, range)
+                .into_iter()
+                .map(|p| p.cast::<f64>().unwrap())
+                .collect()
+        }
+
+        fn encloses(circle: &Circle<f64>, points: &[Point<f64>]) -> bool {
+            points
+                .iter()
+                .all(|&p| (p - circle.center).magnitude() <= circle.radius + 1e-9)
+        }
+
+        fn brute_force_radius(points: &[Point<f64>]) -> f64 {
+            let mut best = f64::INFINITY;
+            for (i, &a) in points.iter().enumerate() {
+                for (j, &b) in points.iter().enumerate().skip(i + 1) {
+                    let mut candidates = vec![diametral_circle(a, b)];
+                    for &c in &points[j + 1..] {
+                        if (b - a).cross(c - a) != 0.0 {
+                            candidates.push(circumcircle(a, b, c));
+                        }
+                    }
+                    for circle in candidates {
+                        if encloses(&circle, points) {
+                            best = best.min(circle.radius);
+                        }
+                    }
+                }
+            }
+            best
+        }
+
+        fn brute_force_box_area(points: &[Point<f64>]) -> f64 {
+            let hull = convex_hull(points);
+            hull.edges()
+                .map(|edge| {
+                    let u = (edge.end - edge.start).normalized();
+                    let v = Vector::with_coords(-u.y, u.x);
+                    let extent = |axis: Vector<f64>| {
+                        let projections = points.iter().map(|&p| (p - edge.start).dot(axis));
+                        let max = projections.clone().fold(f64::NEG_INFINITY, f64::max);
+                        max - projections.fold(f64::INFINITY, f64::min)
+                    };
+                    extent(u) * extent(v)
+                })
+                .fold(f64::INFINITY, f64::min)
+        }
+
+        fn assert_encloses(obb: &OrientedBox<f64>, points: &[Point<f64>]) {
+            let normal = Vector::with_coords(-obb.axis.y, obb.axis.x);
+            for &p in points {
+                let offset = p - obb.center;
+                assert!(offset.dot(obb.axis).abs() <= obb.half_extents.x + 1e-9);
+                assert!(offset.dot(normal).abs() <= obb.half_extents.y + 1e-9);
+            }
+        }
+
+        #[test]
+        fn finds_circle_through_three_points() {
+            let circle = Circle::from_three_points(
+                Point::with_coords(0, 0),
+                Point::with_coords(1, 0),
+                Point::with_coords(1, 1),
+            )
+            .unwrap();
+            assert!((circle.center - Point::with_coords(0.5, 0.5)).magnitude() < 1e-12);
+            assert!((circle.radius - 0.5f64.sqrt()).abs() < 1e-12);
+            assert_eq!(
+                Circle::from_three_points(
+                    Point::with_coords(0, 0),
+                    Point::with_coords(1, 1),
+                    Point::with_coords(3, 3)
+                ),
+                None
+            );
+        }
+
+        #[test]
+        fn places_circle_tangent_to_another() {
+            let unit = Circle::with_center_and_radius(Point::with_coords(1, 1), 1);
+            assert_eq!(
+                Circle::tangent_externally(Point::with_coords(1, 5), 2, &unit),
+                Some(Circle::with_center_and_radius(
+                    Point::with_coords(1.0, 4.0),
+                    2.0
+                ))
+            );
+
+            let circle = Circle::tangent_externally(Point::with_coords(4, 5), 3, &unit).unwrap();
+            assert!(
+                ((circle.center - Point::with_coords(1.0, 1.0)).magnitude() - 4.0).abs() < 1e-12
+            );
+            assert!((circle.center - Point::with_coords(3.4, 4.2)).magnitude() < 1e-12);
+            assert_eq!(
+                Circle::tangent_externally(Point::with_coords(1, 1), 2, &unit),
+                None
+            );
+        }
+
+        #[test]
+        fn measures_circumference_and_arcs() {
+            let circle = Circle::with_center_and_radius(Point::with_coords(1, 2), 3);
+            assert_eq!(circle.circumference(), 6.0 * core::f64::consts::PI);
+            assert_eq!(
+                circle.arc_length(core::f64::consts::PI),
+                circle.circumference() / 2.0
+            );
+            assert_eq!(circle.arc_length(-2.0), -6.0);
+            assert_eq!(circle.point_at_angle(0.0), Point::with_coords(4.0, 2.0));
+            let top = circle.point_at_angle(core::f64::consts::FRAC_PI_2);
+            assert!((top - Point::with_coords(1.0, 5.0)).magnitude() < 1e-12);
+        }
+
+        #[test]
+        fn bounds_points_with_circle_around_their_mean() {
+            let points = [
+                Point::with_coords(0, 0),
+                Point::with_coords(6, 0),
+                Point::with_coords(0, 3),
+                Point::with_coords(6, 3),
+                Point::with_coords(3, 9),
+            ];
+            let circle = bounding_circle(&points).unwrap();
+            assert_eq!(circle.center, Point::with_coords(3.0, 3.0));
+            assert_eq!(circle.radius, 6.0);
+            assert_eq!(bounding_circle::<i64>(&[]), None);
+
+            for seed in 1..20 {
+                let points = float_points(seed, 50, 1000);
+                let circle = bounding_circle(&points).unwrap();
+                assert!(points.iter().all(|&p| circle.contains_point(p)));
+            }
+        }
+
+        #[test]
+        fn computes_min_enclosing_circle_of_square() {
+            let points = [
+                Point::with_coords(0.0, 0.0),
+                Point::with_coords(2.0, 0.0),
+                Point::with_coords(1.0, 1.5),
+                Point::with_coords(2.0, 2.0),
+                Point::with_coords(0.0, 2.0),
+            ];
+            let circle = min_enclosing_circle(&points).unwrap();
+            assert!((circle.center - Point::with_coords(1.0, 1.0)).magnitude() < 1e-12);
+            assert!((circle.radius - 2f64.sqrt()).abs() < 1e-12);
+        }
+
+        #[cfg(feature = "approx")]
+        #[test]
+        fn compares_enclosing_circles_approximately() {
+            let points = [
+                Point::with_coords(0.1, 0.2),
+                Point::with_coords(0.7, 0.2),
+                Point::with_coords(0.4, 0.6),
+            ];
+            let circle = min_enclosing_circle(&points).unwrap();
+            let expected = Circle::with_center_and_radius(Point::with_coords(0.4, 0.2875), 0.3125);
+            approx::assert_abs_diff_eq!(circle, expected, epsilon = 1e-12);
+            approx::assert_relative_eq!(circle, expected);
+            approx::assert_relative_ne!(
+                circle,
+                Circle::with_center_and_radius(expected.center, 0.32)
+            );
+        }
+
+        #[test]
+        fn computes_min_enclosing_circle_of_degenerate_inputs() {
+            assert_eq!(min_enclosing_circle::<f64>(&[]), None);
+
+            let p = Point::with_coords(3.0, -1.0);
+            assert_eq!(
+                min_enclosing_circle(&[p, p, p]),
+                Some(Circle::with_center_and_radius(p, 0.0))
+            );
+
+            let collinear: Vec<_> = [2.0, -3.0, 0.0, 7.0, 1.0]
+                .iter()
+                .map(|&t| Point::with_coords(t, 2.0 * t))
+                .collect();
+            let circle = min_enclosing_circle(&collinear).unwrap();
+            assert!((circle.center - Point::with_coords(2.0, 4.0)).magnitude() < 1e-12);
+            assert!((circle.radius - 5.0 * 5f64.sqrt()).abs() < 1e-12);
+        }
+
+        #[test]
+        fn matches_brute_force_min_enclosing_circle() {
+            for seed in 1..=60 {
+                let points = float_points(seed * 6_700_417, 2 + seed as usize % 13, 50);
+                let circle = min_enclosing_circle(&points).unwrap();
+                assert!(encloses(&circle, &points), "seed {}", seed);
+                let expected = brute_force_radius(&points);
+                assert!(
+                    (circle.radius - expected).abs() < 1e-9,
+                    "seed {}: {} != {}",
+                    seed,
+                    circle.radius,
+                    expected
+                );
+            }
+        }
+
+        #[test]
+        fn recovers_rotated_rectangle_as_oriented_box() {
+            let (sin, cos) = 0.5f64.sin_cos();
+            let (axis, normal) = (
+                Vector::with_coords(cos, sin),
+                Vector::with_coords(-sin, cos),
+            );
+            let center = Point::with_coords(5.0, -3.0);
+            let points: Vec<_> = [
+                (-2.0, -1.0),
+                (2.0, -1.0),
+                (2.0, 1.0),
+                (-2.0, 1.0),
+                (0.5, 0.2),
+            ]
+            .iter()
+            .map(|&(a, b)| center + axis * a + normal * b)
+            .collect();
+
+            let obb = oriented_bounding_box(&points).unwrap();
+            assert!((obb.area() - 8.0).abs() < 1e-9);
+            assert!((obb.center - center).magnitude() < 1e-9);
+            assert!(obb.axis.cross(axis).abs() < 1e-9 || obb.axis.dot(axis).abs() < 1e-9);
+            assert_encloses(&obb, &points);
+        }
+
+        #[test]
+        fn lists_oriented_box_corners_counterclockwise() {
+            let obb = OrientedBox {
+                center: Point::with_coords(1.0, 1.0),
+                axis: Vector::with_coords(0.0, 1.0),
+                half_extents: Vector::with_coords(2.0, 1.0),
+            };
+            assert_eq!(
+                obb.corners(),
+                [
+                    Point::with_coords(2.0, -1.0),
+                    Point::with_coords(2.0, 3.0),
+                    Point::with_coords(0.0, 3.0),
+                    Point::with_coords(0.0, -1.0),
+                ]
+            );
+            assert_eq!(obb.area(), 8.0);
+        }
+
+        #[test]
+        fn computes_oriented_box_of_degenerate_inputs() {
+            assert_eq!(oriented_bounding_box::<f64>(&[]), None);
+
+            let p = Point::with_coords(1.0, 2.0);
+            let obb = oriented_bounding_box(&[p, p]).unwrap();
+            assert_eq!((obb.center, obb.half_extents), (p, Vector::zero()));
+
+            let collinear: [Point<f64>; 3] = [
+                Point::with_coords(0.0, 0.0),
+                Point::with_coords(3.0, 4.0),
+                Point::with_coords(-3.0, -4.0),
+            ];
+            let obb = oriented_bounding_box(&collinear).unwrap();
+            assert_eq!(obb.area(), 0.0);
+            assert!((obb.half_extents.x - 5.0).abs() < 1e-12);
+            assert!(obb.center.x.abs() < 1e-12 && obb.center.y.abs() < 1e-12);
+        }
+
+        #[test]
+        fn matches_brute_force_oriented_box() {
+            for seed in 1..=60 {
+                let points = float_points(seed * 104_729, 3 + seed as usize * 3, 40);
+                let obb = oriented_bounding_box(&points).unwrap();
+                assert_encloses(&obb, &points);
+                let expected = brute_force_box_area(&points);
+                assert!(
+                    (obb.area() - expected).abs() < 1e-6,
+                    "seed {}: {} != {}",
+                    seed,
+                    obb.area(),
+                    expected
+                );
+                let aabb = Aabb::from_points(points.iter().copied()).unwrap();
+                assert!(obb.area() <= aabb.area() + 1e-9);
+            }
+        }
+    }
+}
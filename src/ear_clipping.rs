@@ -0,0 +1,456 @@
+use crate::meta::Coord;
+use crate::polygon::{Polygon, PolygonWithHoles};
+use crate::primitives::{orientation, Point, Segment};
+use crate::vectors::Orientation;
+use alloc::vec::Vec;
+
+impl<T> Polygon<T>
+where
+    T: Coord + Copy,
+{
+    /// Triangulates a simple polygon of either winding by ear clipping, returning counterclockwise
+    /// triangles as indices into `vertices`.
+    ///
+    /// Collinear and repeated vertices are skipped, so they may not appear in any triangle, and a
+    /// polygon with zero area has no triangles. Self-intersecting polygons are triangulated only
+    /// until no ear is left. Every ear test scans the remaining reflex vertices, so the running
+    /// time is quadratic in the worst case.
+    pub fn triangulate(&self) -> Vec<[usize; 3]> {
+        ear_clip(&[self])
+    }
+}
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Coord + Copy,
+{
+    /// Triangulates like `Polygon::triangulate`, after connecting every hole to the exterior by a
+    /// bridge to the nearest visible vertex. Indices refer to the exterior vertices followed by
+    /// the vertices of each hole in order. Holes that cannot be connected, which only happens for
+    /// invalid input, are ignored.
+    pub fn triangulate(&self) -> Vec<[usize; 3]> {
+        let rings: Vec<_> = core::iter::once(&self.exterior)
+            .chain(&self.holes)
+            .collect();
+        ear_clip(&rings)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Node {
+    vertex: usize,
+    prev: usize,
+    next: usize,
+}
+
+// A doubly linked list of ring vertices. Bridging a hole duplicates the two bridge endpoints, so
+// several nodes may refer to the same vertex.
+struct Rings<'a, T>
+where
+    T: Coord + Copy,
+{
+    points: &'a [Point<T>],
+    nodes: Vec<Node>,
+}
+
+fn ear_clip<T>(rings: &[&Polygon<T>]) -> Vec<[usize; 3]>
+where
+    T: Coord + Copy,
+{
+    let points: Vec<_> = rings
+        .iter()
+        .flat_map(|ring| ring.vertices.iter().copied())
+        .collect();
+    let mut list = Rings {
+        points: &points,
+        nodes: Vec::with_capacity(points.len() + 2 * rings.len()),
+    };
+
+    let mut offset = 0;
+    let mut starts = Vec::with_capacity(rings.len());
+    for (i, ring) in rings.iter().enumerate() {
+        let expected = if i == 0 {
+            Orientation::Counterclockwise
+        } else {
+            Orientation::Clockwise
+        };
+        let winding = ring.winding();
+        if winding != Orientation::Collinear {
+            starts.push(list.add_ring(offset, ring.vertices.len(), winding != expected));
+        } else if i == 0 {
+            return Vec::new();
+        }
+        offset += ring.vertices.len();
+    }
+
+    let outer = starts[0];
+    let mut holes: Vec<_> = starts[1..].iter().map(|&h| list.rightmost(h)).collect();
+    holes.sort_by(|&m, &n| {
+        list.point(n)
+            .partial_cmp(&list.point(m))
+            .expect("coordinates are comparable")
+    });
+    for i in 0..holes.len() {
+        if let Some(bridge) = list.find_bridge(outer, holes[i], &holes[i + 1..]) {
+            list.split(bridge, holes[i]);
+        }
+    }
+    list.clip_ears(outer)
+}
+
+impl<'a, T> Rings<'a, T>
+where
+    T: Coord + Copy,
+{
+    fn point(&self, node: usize) -> Point<T> {
+        self.points[self.nodes[node].vertex]
+    }
+
+    fn prev(&self, node: usize) -> usize {
+        self.nodes[node].prev
+    }
+
+    fn next(&self, node: usize) -> usize {
+        self.nodes[node].next
+    }
+
+    fn add_ring(&mut self, offset: usize, len: usize, reversed: bool) -> usize {
+        let start = self.nodes.len();
+        for k in 0..len {
+            let vertex = if reversed {
+                offset + len - 1 - k
+            } else {
+                offset + k
+            };
+            self.nodes.push(Node {
+                vertex,
+                prev: start + (k + len - 1) % len,
+                next: start + (k + 1) % len,
+            });
+        }
+        start
+    }
+
+    fn ring(&self, start: usize) -> impl Iterator<Item = usize> + '_ {
+        let mut node = Some(start);
+        core::iter::from_fn(move || {
+            let current = node?;
+            let next = self.next(current);
+            node = if next == start { None } else { Some(next) };
+            Some(current)
+        })
+    }
+
+    fn rightmost(&self, start: usize) -> usize {
+        self.ring(start)
+            .max_by(|&m, &n| {
+                self.point(m)
+                    .partial_cmp(&self.point(n))
+                    .expect("coordinates are comparable")
+            })
+            .expect("rings are not empty")
+    }
+
+    // Tests whether `p` lies strictly inside the angle the ring makes at `node`, on the side of
+    // the interior, which is always to the left.
+    fn locally_inside(&self, node: usize, p: Point<T>) -> bool {
+        let (a, b, c) = (
+            self.point(self.prev(node)),
+            self.point(node),
+            self.point(self.next(node)),
+        );
+        let left_of_incoming = orientation(a, b, p) == Orientation::Counterclockwise;
+        let left_of_outgoing = orientation(b, c, p) == Orientation::Counterclockwise;
+        if orientation(a, b, c) == Orientation::Clockwise {
+            left_of_incoming || left_of_outgoing
+        } else {
+            left_of_incoming && left_of_outgoing
+        }
+    }
+
+    // Picks the outer vertex nearest to the hole vertex `hole` that it can be joined to without
+    // the bridge crossing any edge of the outer ring or of the holes still to be bridged.
+    fn find_bridge(&self, outer: usize, hole: usize, unbridged: &[usize]) -> Option<usize> {
+        let m = self.point(hole);
+        let mut candidates: Vec<_> = self.ring(outer).collect();
+        candidates.sort_by(|&p, &q| {
+            let (p, q) = (self.point(p) - m, self.point(q) - m);
+            p.dot(p)
+                .partial_cmp(&q.dot(q))
+                .expect("coordinates are comparable")
+        });
+
+        candidates.into_iter().find(|&candidate| {
+            let p = self.point(candidate);
+            if p == m {
+                return true;
+            }
+            if !self.locally_inside(candidate, m) || !self.locally_inside(hole, p) {
+                return false;
+            }
+            let bridge = Segment::with_endpoints(m, p);
+            let rings = core::iter::once(outer)
+                .chain(core::iter::once(hole))
+                .chain(unbridged.iter().copied());
+            !rings.flat_map(|start| self.ring(start)).any(|node| {
+                let (a, b) = (self.point(node), self.point(self.next(node)));
+                a != m
+                    && b != m
+                    && a != p
+                    && b != p
+                    && bridge.intersects(&Segment::with_endpoints(a, b))
+            })
+        })
+    }
+
+    // Joins the ring through `a` with the ring through `b` by a pair of edges between them,
+    // duplicating both nodes.
+    fn split(&mut self, a: usize, b: usize) {
+        let (a2, b2) = (self.nodes.len(), self.nodes.len() + 1);
+        let (an, bp) = (self.next(a), self.prev(b));
+        self.nodes.push(Node {
+            vertex: self.nodes[a].vertex,
+            prev: b2,
+            next: an,
+        });
+        self.nodes.push(Node {
+            vertex: self.nodes[b].vertex,
+            prev: bp,
+            next: a2,
+        });
+        self.nodes[a].next = b;
+        self.nodes[b].prev = a;
+        self.nodes[an].prev = a2;
+        self.nodes[bp].next = b2;
+    }
+
+    fn remove(&mut self, node: usize) {
+        let Node { prev, next, .. } = self.nodes[node];
+        self.nodes[prev].next = next;
+        self.nodes[next].prev = prev;
+    }
+
+    fn is_ear(&self, ear: usize) -> bool {
+        let (prev, next) = (self.prev(ear), self.next(ear));
+        let (a, b, c) = (self.point(prev), self.point(ear), self.point(next));
+        let mut others = self.ring(self.next(next)).take_while(|&node| node != prev);
+        !others.any(|node| {
+            let p = self.point(node);
+            p != a
+                && p != b
+                && p != c
+                && orientation(self.point(self.prev(node)), p, self.point(self.next(node)))
+                    != Orientation::Counterclockwise
+                && orientation(a, b, p) != Orientation::Clockwise
+                && orientation(b, c, p) != Orientation::Clockwise
+                && orientation(c, a, p) != Orientation::Clockwise
+        })
+    }
+
+    fn clip_ears(&mut self, start: usize) -> Vec<[usize; 3]> {
+        let mut triangles = Vec::new();
+        let mut remaining = self.ring(start).count();
+        let mut ear = start;
+        let mut stalled = 0;
+        while remaining > 2 && stalled < remaining {
+            let (prev, next) = (self.prev(ear), self.next(ear));
+            match orientation(self.point(prev), self.point(ear), self.point(next)) {
+                Orientation::Collinear => {}
+                Orientation::Counterclockwise if self.is_ear(ear) => triangles.push([
+                    self.nodes[prev].vertex,
+                    self.nodes[ear].vertex,
+                    self.nodes[next].vertex,
+                ]),
+                _ => {
+                    ear = next;
+                    stalled += 1;
+                    continue;
+                }
+            }
+            self.remove(ear);
+            remaining -= 1;
+            ear = prev;
+            stalled = 0;
+        }
+        triangles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::Rng;
+    use alloc::vec;
+
+    fn polygon(coords: &[(i64, i64)]) -> Polygon<i64> {
+        Polygon::with_vertices(
+            coords
+                .iter()
+                .map(|&(x, y)| Point::with_coords(x, y))
+                .collect(),
+        )
+    }
+
+    fn twice_area(points: &[Point<i64>], [a, b, c]: [usize; 3]) -> i64 {
+        (points[b] - points[a]).cross(points[c] - points[a])
+    }
+
+    // Checks that every triangle is counterclockwise and that together they cover exactly the
+    // area of the shape.
+    fn assert_tiles(shape: &PolygonWithHoles<i64>, triangles: &[[usize; 3]]) {
+        let points: Vec<_> = core::iter::once(&shape.exterior)
+            .chain(&shape.holes)
+            .flat_map(|ring| ring.vertices.iter().copied())
+            .collect();
+        for &triangle in triangles {
+            assert!(twice_area(&points, triangle) > 0, "{:?}", triangle);
+        }
+        let area: i64 = triangles.iter().map(|&t| twice_area(&points, t)).sum();
+        let expected = shape
+            .holes
+            .iter()
+            .fold(shape.exterior.twice_signed_area().abs(), |area, hole| {
+                area - hole.twice_signed_area().abs()
+            });
+        assert_eq!(area, expected);
+    }
+
+    // Builds a star-shaped polygon with vertices at increasing angles around `center`, which is
+    // simple by construction and contains the disk of radius `min_radius / 2`.
+    fn random_star(rng: &mut Rng, center: (i64, i64), n: usize, min_radius: i64) -> Polygon<i64> {
+        let vertices = (0..n)
+            .map(|k| {
+                let angle = std::f64::consts::TAU * (k as f64 + 0.5) / n as f64;
+                let radius = rng.range(min_radius, 2 * min_radius) as f64;
+                Point::with_coords(
+                    center.0 + (radius * angle.cos()).round() as i64,
+                    center.1 + (radius * angle.sin()).round() as i64,
+                )
+            })
+            .collect();
+        Polygon::with_vertices(vertices)
+    }
+
+    #[test]
+    fn triangulates_convex_polygon() {
+        let square = polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]);
+        let triangles = square.triangulate();
+        assert_eq!(triangles.len(), 2);
+        assert_tiles(&square.into(), &triangles);
+    }
+
+    #[test]
+    fn triangulates_concave_polygon() {
+        let comb = polygon(&[
+            (0, 0),
+            (10, 0),
+            (10, 10),
+            (8, 10),
+            (8, 2),
+            (6, 2),
+            (6, 10),
+            (4, 10),
+            (4, 2),
+            (2, 2),
+            (2, 10),
+            (0, 10),
+        ]);
+        let triangles = comb.triangulate();
+        assert_eq!(triangles.len(), 10);
+        assert_tiles(&comb.into(), &triangles);
+    }
+
+    #[test]
+    fn triangulates_clockwise_polygon_into_counterclockwise_triangles() {
+        let arrow = polygon(&[(0, 0), (2, 4), (4, 0), (2, 1)]);
+        let triangles = arrow.triangulate();
+        assert_eq!(triangles.len(), 2);
+        assert_tiles(&arrow.into(), &triangles);
+    }
+
+    #[test]
+    fn skips_collinear_and_repeated_vertices() {
+        let square = polygon(&[(0, 0), (1, 0), (2, 0), (2, 0), (2, 2), (0, 2), (0, 1)]);
+        let triangles = square.triangulate();
+        assert!(triangles.len() < square.vertices.len() - 2);
+        assert_tiles(&square.into(), &triangles);
+        assert!(!triangles.iter().any(|t| t.contains(&2) && t.contains(&3)));
+    }
+
+    #[test]
+    fn produces_no_triangles_for_degenerate_polygons() {
+        assert!(polygon(&[]).triangulate().is_empty());
+        assert!(polygon(&[(2, 3)]).triangulate().is_empty());
+        assert!(polygon(&[(2, 3), (2, 3), (2, 3)]).triangulate().is_empty());
+        assert!(polygon(&[(0, 0), (1, 1)]).triangulate().is_empty());
+        assert!(polygon(&[(0, 0), (1, 1), (3, 3)]).triangulate().is_empty());
+    }
+
+    #[test]
+    fn triangulates_polygon_with_hole() {
+        let frame = PolygonWithHoles::with_exterior_and_holes(
+            polygon(&[(0, 0), (6, 0), (6, 6), (0, 6)]),
+            vec![polygon(&[(2, 2), (4, 2), (4, 4), (2, 4)])],
+        );
+        let triangles = frame.triangulate();
+        assert_eq!(triangles.len(), 8);
+        assert_tiles(&frame, &triangles);
+        assert!(triangles.iter().flatten().all(|&i| i < 8));
+    }
+
+    #[test]
+    fn triangulates_holes_touching_exterior_and_each_other() {
+        let shape = PolygonWithHoles::with_exterior_and_holes(
+            polygon(&[(0, 0), (8, 0), (8, 4), (0, 4)]),
+            vec![
+                polygon(&[(0, 0), (2, 2), (4, 1)]),
+                polygon(&[(4, 1), (6, 3), (7, 1)]),
+            ],
+        );
+        assert_tiles(&shape, &shape.triangulate());
+    }
+
+    #[test]
+    fn tiles_random_star_shaped_polygons() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for round in 0..300 {
+            let star = random_star(&mut rng, (0, 0), 3 + round % 40, 500);
+            let triangles = star.triangulate();
+            assert_eq!(triangles.len(), star.vertices.len() - 2);
+            assert_tiles(&star.into(), &triangles);
+        }
+    }
+
+    #[test]
+    fn tiles_random_polygons_with_holes() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for round in 0..200 {
+            let exterior = random_star(&mut rng, (0, 0), 8 + round % 30, 1000);
+            let holes = [(-250, 0), (250, 0), (0, 250)]
+                .iter()
+                .take(round % 4)
+                .map(|&center| random_star(&mut rng, center, 3 + round % 9, 40))
+                .collect();
+            let shape = PolygonWithHoles::with_exterior_and_holes(exterior, holes);
+            assert_tiles(&shape, &shape.triangulate());
+        }
+    }
+
+    #[test]
+    fn triangulates_float_polygon() {
+        let l = Polygon::with_vertices(vec![
+            Point::with_coords(0.0, 0.0),
+            Point::with_coords(2.0, 0.0),
+            Point::with_coords(2.0, 1.0),
+            Point::with_coords(1.0, 1.0),
+            Point::with_coords(1.0, 2.0),
+            Point::with_coords(0.0, 2.0),
+        ]);
+        let area: f64 = l
+            .triangulate()
+            .iter()
+            .map(|&[a, b, c]| (l.vertices[b] - l.vertices[a]).cross(l.vertices[c] - l.vertices[a]))
+            .sum();
+        assert_eq!(area, 6.0);
+    }
+}
@@ -0,0 +1,419 @@
+use crate::vectors::Vector;
+use core::convert::TryFrom;
+use core::fmt;
+use core::num::ParseIntError;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use num_traits::float::FloatCore;
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Num, NumCast, One, Signed, ToPrimitive, Zero,
+};
+
+const FRACTION_BITS: u32 = 32;
+const QUARTER_TURN_STEPS: i128 = 256;
+
+// sin(k * pi / 512) for k in 0..=256, i.e. a quarter turn, as 32.32 bits.
+const SINE_TABLE: [i64; 257] = [
+    0, 26353424, 52705856, 79056303, 105403774, 131747276, 158085819, 184418409, 210744057,
+    237061769, 263370557, 289669429, 315957395, 342233465, 368496651, 394745962, 420980412,
+    447199012, 473400776, 499584716, 525749847, 551895183, 578019742, 604122538, 630202589,
+    656258914, 682290530, 708296459, 734275721, 760227338, 786150333, 812043729, 837906553,
+    863737830, 889536587, 915301854, 941032661, 966728038, 992387019, 1018008636, 1043591926,
+    1069135926, 1094639673, 1120102207, 1145522571, 1170899806, 1196232957, 1221521071, 1246763195,
+    1271958380, 1297105676, 1322204136, 1347252816, 1372250773, 1397197066, 1422090755, 1446930903,
+    1471716574, 1496446837, 1521120759, 1545737412, 1570295869, 1594795204, 1619234497, 1643612827,
+    1667929275, 1692182927, 1716372869, 1740498191, 1764557983, 1788551342, 1812477362, 1836335144,
+    1860123788, 1883842400, 1907490086, 1931065957, 1954569124, 1977998702, 2001353810, 2024633568,
+    2047837100, 2070963532, 2094011993, 2116981616, 2139871536, 2162680890, 2185408821, 2208054473,
+    2230616993, 2253095531, 2275489241, 2297797281, 2320018810, 2342152991, 2364198992, 2386155981,
+    2408023134, 2429799626, 2451484637, 2473077351, 2494576955, 2515982640, 2537293599, 2558509031,
+    2579628136, 2600650120, 2621574191, 2642399561, 2663125446, 2683751066, 2704275644, 2724698408,
+    2745018589, 2765235421, 2785348143, 2805355999, 2825258235, 2845054101, 2864742853, 2884323748,
+    2903796051, 2923159027, 2942411948, 2961554089, 2980584729, 2999503152, 3018308645, 3037000500,
+    3055578014, 3074040487, 3092387225, 3110617535, 3128730733, 3146726136, 3164603066, 3182360851,
+    3199998822, 3217516315, 3234912670, 3252187232, 3269339351, 3286368382, 3303273682, 3320054617,
+    3336710553, 3353240863, 3369644927, 3385922125, 3402071844, 3418093478, 3433986423, 3449750080,
+    3465383855, 3480887161, 3496259414, 3511500034, 3526608449, 3541584088, 3556426389, 3571134792,
+    3585708745, 3600147697, 3614451106, 3628618433, 3642649144, 3656542712, 3670298613, 3683916329,
+    3697395348, 3710735162, 3723935269, 3736995171, 3749914379, 3762692404, 3775328765, 3787822988,
+    3800174601, 3812383140, 3824448145, 3836369162, 3848145741, 3859777440, 3871263820, 3882604450,
+    3893798902, 3904846754, 3915747591, 3926501002, 3937106583, 3947563934, 3957872662, 3968032378,
+    3978042699, 3987903250, 3997613658, 4007173558, 4016582591, 4025840401, 4034946641, 4043900968,
+    4052703044, 4061352537, 4069849124, 4078192482, 4086382299, 4094418266, 4102300081, 4110027446,
+    4117600071, 4125017671, 4132279966, 4139386683, 4146337555, 4153132319, 4159770720, 4166252509,
+    4172577440, 4178745276, 4184755784, 4190608739, 4196303920, 4201841112, 4207220108, 4212440704,
+    4217502704, 4222405917, 4227150159, 4231735252, 4236161021, 4240427302, 4244533933, 4248480760,
+    4252267634, 4255894413, 4259360959, 4262667143, 4265812840, 4268797931, 4271622305, 4274285855,
+    4276788480, 4279130086, 4281310585, 4283329896, 4285187942, 4286884652, 4288419964, 4289793820,
+    4291006167, 4292056960, 4292946160, 4293673732, 4294239650, 4294643893, 4294886444, 4294967296,
+];
+
+/// A 32.32 fixed-point number. The arithmetic operators panic on overflow in every build
+/// profile, while the `Checked*` traits return `None` instead.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    pub const PI: Self = Self(13_493_037_705);
+    pub const FRAC_PI_2: Self = Self(6_746_518_852);
+    pub const TAU: Self = Self(26_986_075_409);
+
+    pub fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    pub fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    pub fn from_int(value: i32) -> Self {
+        Self((value as i64) << FRACTION_BITS)
+    }
+
+    pub fn from_f64(value: f64) -> Self {
+        Self(FloatCore::round(value * (1u64 << FRACTION_BITS) as f64) as i64)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << FRACTION_BITS) as f64
+    }
+
+    pub fn sin(self) -> Self {
+        let turn = Self::TAU.0 as i128;
+        let steps = self.0.rem_euclid(Self::TAU.0) as i128 * 4 * QUARTER_TURN_STEPS;
+        let (step, remainder) = (steps / turn, steps % turn);
+        let (quadrant, index) = (
+            step / QUARTER_TURN_STEPS,
+            (step % QUARTER_TURN_STEPS) as usize,
+        );
+
+        let (from, to) = if quadrant % 2 == 0 {
+            (SINE_TABLE[index], SINE_TABLE[index + 1])
+        } else {
+            (SINE_TABLE[256 - index], SINE_TABLE[255 - index])
+        };
+        let value = from as i128 + (to - from) as i128 * remainder / turn;
+        if quadrant < 2 {
+            Self(value as i64)
+        } else {
+            Self(-value as i64)
+        }
+    }
+
+    pub fn cos(self) -> Self {
+        (self + Self::FRAC_PI_2).sin()
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Fixed::to_f64(*self))
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.checked_neg().expect("fixed-point overflow"))
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.checked_add(&other).expect("fixed-point overflow")
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.checked_sub(&other).expect("fixed-point overflow")
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.checked_mul(&other).expect("fixed-point overflow")
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        self.checked_div(&other).expect("fixed-point overflow")
+    }
+}
+
+impl CheckedAdd for Fixed {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+}
+
+impl CheckedSub for Fixed {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+}
+
+impl CheckedMul for Fixed {
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let product = (self.0 as i128 * other.0 as i128) >> FRACTION_BITS;
+        i64::try_from(product).ok().map(Self)
+    }
+}
+
+impl CheckedDiv for Fixed {
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.0 == 0 {
+            return None;
+        }
+        let quotient = ((self.0 as i128) << FRACTION_BITS) / other.0 as i128;
+        i64::try_from(quotient).ok().map(Self)
+    }
+}
+
+impl Rem for Fixed {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self::Output {
+        Self(self.0.checked_rem(other.0).expect("fixed-point overflow"))
+    }
+}
+
+impl Zero for Fixed {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Fixed {
+    fn one() -> Self {
+        Self(1 << FRACTION_BITS)
+    }
+}
+
+impl Num for Fixed {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        i32::from_str_radix(s, radix).map(Self::from_int)
+    }
+}
+
+impl Signed for Fixed {
+    fn abs(&self) -> Self {
+        Self(self.0.checked_abs().expect("fixed-point overflow"))
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        Self::from_int(self.0.signum() as i32)
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0 > 0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+}
+
+impl ToPrimitive for Fixed {
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.0 / (1 << FRACTION_BITS))
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_i64()?.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(Fixed::to_f64(*self))
+    }
+}
+
+impl NumCast for Fixed {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        let value = n.to_f64()? * (1u64 << FRACTION_BITS) as f64;
+        if value.is_finite() && FloatCore::abs(value) < i64::MAX as f64 {
+            Some(Self(FloatCore::round(value) as i64))
+        } else {
+            None
+        }
+    }
+}
+
+impl Vector<Fixed> {
+    pub fn rotate(self, radians: Fixed) -> Self {
+        let (sin, cos) = (radians.sin(), radians.cos());
+        Self::with_coords(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Fixed, b: f64) {
+        assert!((a.to_f64() - b).abs() < 1e-5, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn converts_integers_to_fixed_point() {
+        assert_eq!(Fixed::from_int(3).to_bits(), 3 << 32);
+        assert_eq!(Fixed::from_int(3), 3.into());
+        assert_eq!(Fixed::from_int(-3).to_f64(), -3.0);
+    }
+
+    #[test]
+    fn adds_and_subtracts_fixed_point_values() {
+        let (a, b) = (Fixed::from_f64(1.5), Fixed::from_f64(2.25));
+        assert_eq!(a + b, Fixed::from_f64(3.75));
+        assert_eq!(a - b, Fixed::from_f64(-0.75));
+    }
+
+    #[test]
+    fn multiplies_and_divides_fixed_point_values() {
+        let (a, b) = (Fixed::from_f64(1.5), Fixed::from_f64(-2.25));
+        assert_eq!(a * b, Fixed::from_f64(-3.375));
+        assert_eq!(b / a, Fixed::from_f64(-1.5));
+    }
+
+    #[test]
+    fn detects_fixed_point_overflow() {
+        let (max, half) = (Fixed::from_bits(i64::MAX), Fixed::from_f64(0.5));
+        assert_eq!(max.checked_add(&Fixed::from_bits(1)), None);
+        assert_eq!(
+            Fixed::from_bits(i64::MIN).checked_sub(&Fixed::from_bits(1)),
+            None
+        );
+        assert_eq!(max.checked_mul(&Fixed::from_int(2)), None);
+        assert_eq!(max.checked_div(&half), None);
+        assert_eq!(half.checked_div(&Fixed::zero()), None);
+        assert_eq!(
+            Fixed::from_int(3).checked_mul(&half),
+            Some(Fixed::from_f64(1.5))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point overflow")]
+    fn panics_on_fixed_point_overflow() {
+        let _ = Fixed::from_int(i32::MAX) * Fixed::from_int(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point overflow")]
+    fn panics_on_fixed_point_division_overflow() {
+        let _ = Fixed::from_bits(i64::MAX) / Fixed::from_f64(0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point overflow")]
+    fn panics_on_fixed_point_division_by_zero() {
+        let _ = Fixed::one() / Fixed::zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point overflow")]
+    fn panics_on_fixed_point_remainder_by_zero() {
+        let _ = Fixed::one() % Fixed::zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point overflow")]
+    fn panics_on_fixed_point_remainder_overflow() {
+        let _ = Fixed::from_bits(i64::MIN) % Fixed::from_bits(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed-point overflow")]
+    fn panics_on_fixed_point_abs_overflow() {
+        let _ = Fixed::from_bits(i64::MIN).abs();
+    }
+
+    #[test]
+    fn adds_fixed_point_vectors_with_overflow_checks() {
+        let v = Vector::with_coords(Fixed::from_int(1), Fixed::from_bits(i64::MAX));
+        assert_eq!(
+            v.checked_add(Vector::with_coords(Fixed::one(), Fixed::zero())),
+            Some(Vector::with_coords(
+                Fixed::from_int(2),
+                Fixed::from_bits(i64::MAX)
+            ))
+        );
+        assert_eq!(v.checked_add(v), None);
+    }
+
+    #[test]
+    fn computes_sign_related_values_of_fixed_point_values() {
+        let a = Fixed::from_f64(-0.5);
+        assert_eq!(a.abs(), Fixed::from_f64(0.5));
+        assert_eq!(a.signum(), Fixed::from_int(-1));
+        assert!(a.is_negative());
+    }
+
+    #[test]
+    fn computes_dot_product_of_fixed_point_vectors() {
+        let v = Vector::with_coords(Fixed::from_f64(1.5), Fixed::from_int(2));
+        let w = Vector::with_coords(Fixed::from_int(4), Fixed::from_f64(-0.25));
+        assert_eq!(v.dot(w), Fixed::from_f64(5.5));
+    }
+
+    #[test]
+    fn computes_sine_and_cosine_from_table() {
+        for i in -20..=20 {
+            let angle = i as f64 * 0.37;
+            assert_close(Fixed::from_f64(angle).sin(), angle.sin());
+            assert_close(Fixed::from_f64(angle).cos(), angle.cos());
+        }
+    }
+
+    #[test]
+    fn rotates_fixed_point_vector() {
+        let v = Vector::with_coords(Fixed::from_int(2), Fixed::zero()).rotate(Fixed::FRAC_PI_2);
+        assert_close(v.x, 0.0);
+        assert_close(v.y, 2.0);
+    }
+
+    #[test]
+    fn casts_fixed_point_vector_to_floats() {
+        let v = Vector::with_coords(Fixed::from_f64(0.5), Fixed::from_int(-2));
+        assert_eq!(v.cast::<f64>(), Some(Vector::with_coords(0.5, -2.0)));
+    }
+
+    #[test]
+    fn truncates_fixed_point_values_toward_zero() {
+        assert_eq!(Fixed::from_f64(-0.5).to_i64(), Some(0));
+        assert_eq!(Fixed::from_f64(-1.5).to_i64(), Some(-1));
+        assert_eq!(Fixed::from_f64(2.75).to_i64(), Some(2));
+        assert_eq!(Fixed::from_f64(-0.5).to_u64(), Some(0));
+        assert_eq!(Fixed::from_f64(-1.5).to_u64(), None);
+        assert_eq!(ToPrimitive::to_i32(&Fixed::from_int(-3)), Some(-3));
+    }
+}
@@ -0,0 +1,840 @@
+//! Reads and writes geometries as Well-Known Text and GeoJSON, and writes them as SVG path data.
+//!
+//! Only two-dimensional points, linestrings and polygons without holes are supported. Coordinates
+//! are written with their `Display` impl and read with their `FromStr` impl, so for instance an
+//! integer geometry rejects fractional coordinates.
+
+use crate::bounding::Circle;
+use crate::meta::Coord;
+use crate::polygon::Polygon;
+use crate::polyline::Polyline;
+use crate::primitives::Point;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Geometry<T>
+where
+    T: Coord,
+{
+    Point(Point<T>),
+    LineString(Polyline<T>),
+    Polygon(Polygon<T>),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// The input ended in the middle of a geometry.
+    UnexpectedEnd,
+    /// The character at the given byte offset does not fit the syntax.
+    UnexpectedCharacter(usize),
+    /// The coordinate at the given byte offset does not parse as the coordinate type.
+    InvalidNumber(usize),
+    /// The geometry type is missing or is not a point, linestring or polygon.
+    UnsupportedGeometry,
+    /// A position does not consist of exactly two coordinates.
+    InvalidPosition,
+    /// A polygon ring does not end where it starts.
+    UnclosedRing,
+    /// A polygon has interior rings.
+    UnsupportedHoles,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Self::UnexpectedCharacter(offset) => {
+                write!(f, "unexpected character at offset {}", offset)
+            }
+            Self::InvalidNumber(offset) => write!(f, "invalid coordinate at offset {}", offset),
+            Self::UnsupportedGeometry => write!(f, "unsupported geometry type"),
+            Self::InvalidPosition => write!(f, "position is not a pair of coordinates"),
+            Self::UnclosedRing => write!(f, "polygon ring is not closed"),
+            Self::UnsupportedHoles => write!(f, "polygons with holes are not supported"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl<T> From<Point<T>> for Geometry<T>
+where
+    T: Coord,
+{
+    fn from(p: Point<T>) -> Self {
+        Self::Point(p)
+    }
+}
+
+impl<T> From<Vec<Point<T>>> for Geometry<T>
+where
+    T: Coord,
+{
+    fn from(points: Vec<Point<T>>) -> Self {
+        Self::LineString(Polyline::with_vertices(points))
+    }
+}
+
+impl<T> From<Polyline<T>> for Geometry<T>
+where
+    T: Coord,
+{
+    fn from(polyline: Polyline<T>) -> Self {
+        Self::LineString(polyline)
+    }
+}
+
+impl<T> From<Polygon<T>> for Geometry<T>
+where
+    T: Coord,
+{
+    fn from(polygon: Polygon<T>) -> Self {
+        Self::Polygon(polygon)
+    }
+}
+
+impl<T> Geometry<T>
+where
+    T: Coord + fmt::Display,
+{
+    /// Writes polygons as a single ring that repeats the first vertex at the end.
+    pub fn to_wkt(&self) -> String {
+        let mut out = String::new();
+        match self {
+            Self::Point(p) => {
+                out.push_str("POINT (");
+                write_wkt_position(&mut out, p);
+                out.push(')');
+            }
+            Self::LineString(polyline) if polyline.vertices.is_empty() => {
+                out.push_str("LINESTRING EMPTY")
+            }
+            Self::LineString(polyline) => {
+                out.push_str("LINESTRING ");
+                write_wkt_positions(&mut out, &polyline.vertices);
+            }
+            Self::Polygon(polygon) if polygon.vertices.is_empty() => out.push_str("POLYGON EMPTY"),
+            Self::Polygon(polygon) => {
+                out.push_str("POLYGON (");
+                write_wkt_positions(&mut out, ring(polygon));
+                out.push(')');
+            }
+        }
+        out
+    }
+
+    /// Writes a GeoJSON geometry object, closing polygon rings like `to_wkt`.
+    pub fn to_geojson(&self) -> String {
+        let mut out = String::new();
+        let kind = match self {
+            Self::Point(_) => "Point",
+            Self::LineString(_) => "LineString",
+            Self::Polygon(_) => "Polygon",
+        };
+        write!(out, r#"{{"type":"{}","coordinates":"#, kind).expect("writing to a string");
+        match self {
+            Self::Point(p) => write_geojson_position(&mut out, p),
+            Self::LineString(polyline) => write_geojson_positions(&mut out, &polyline.vertices),
+            Self::Polygon(polygon) if polygon.vertices.is_empty() => out.push_str("[]"),
+            Self::Polygon(polygon) => {
+                out.push('[');
+                write_geojson_positions(&mut out, ring(polygon));
+                out.push(']');
+            }
+        }
+        out.push('}');
+        out
+    }
+}
+
+impl<T> Geometry<T>
+where
+    T: Coord + FromStr,
+{
+    /// Parses a `POINT`, `LINESTRING` or `POLYGON`, with keywords in any case. The closing vertex
+    /// of a polygon ring is dropped.
+    pub fn from_wkt(input: &str) -> Result<Self, ParseError> {
+        let mut cursor = Cursor::new(input);
+        let kind = cursor.word();
+        let empty = {
+            let mut lookahead = cursor.clone();
+            let empty = lookahead.word().eq_ignore_ascii_case("EMPTY");
+            if empty {
+                cursor = lookahead;
+            }
+            empty
+        };
+
+        let geometry = if kind.eq_ignore_ascii_case("POINT") {
+            if empty {
+                return Err(ParseError::UnsupportedGeometry);
+            }
+            cursor.expect(b'(')?;
+            let p = cursor.wkt_position()?;
+            cursor.expect(b')')?;
+            Self::Point(p)
+        } else if kind.eq_ignore_ascii_case("LINESTRING") {
+            if empty {
+                Self::LineString(Polyline::with_vertices(Vec::new()))
+            } else {
+                Self::LineString(Polyline::with_vertices(cursor.wkt_positions()?))
+            }
+        } else if kind.eq_ignore_ascii_case("POLYGON") {
+            let mut rings = Vec::new();
+            if !empty {
+                cursor.expect(b'(')?;
+                rings.push(cursor.wkt_positions()?);
+                while cursor.eat(b',') {
+                    rings.push(cursor.wkt_positions()?);
+                }
+                cursor.expect(b')')?;
+            }
+            Self::Polygon(polygon_from_rings(rings)?)
+        } else {
+            return Err(ParseError::UnsupportedGeometry);
+        };
+        cursor.finish()?;
+        Ok(geometry)
+    }
+
+    /// Parses a GeoJSON geometry object, ignoring members other than `type` and `coordinates`.
+    pub fn from_geojson(input: &str) -> Result<Self, ParseError> {
+        let mut cursor = Cursor::new(input);
+        let value = cursor.json_value()?;
+        cursor.finish()?;
+
+        let members = match value {
+            Json::Object(members) => members,
+            _ => return Err(ParseError::UnsupportedGeometry),
+        };
+        let member = |key: &str| {
+            members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value)
+        };
+        let coordinates = member("coordinates").ok_or(ParseError::UnsupportedGeometry)?;
+        match member("type") {
+            Some(Json::String(kind)) if kind == "Point" => Ok(Self::Point(position(coordinates)?)),
+            Some(Json::String(kind)) if kind == "LineString" => Ok(Self::LineString(
+                Polyline::with_vertices(positions(coordinates)?),
+            )),
+            Some(Json::String(kind)) if kind == "Polygon" => {
+                let rings = elements(coordinates)?
+                    .iter()
+                    .map(positions)
+                    .collect::<Result<_, _>>()?;
+                Ok(Self::Polygon(polygon_from_rings(rings)?))
+            }
+            _ => Err(ParseError::UnsupportedGeometry),
+        }
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Coord + fmt::Display,
+{
+    /// Writes the path data of an SVG `path` element, such as `M 0 0 L 1 0 L 0 1 Z`, which is
+    /// empty for a polygon without vertices.
+    pub fn to_svg_path(&self) -> String {
+        let mut out = write_svg_positions(&self.vertices);
+        if !out.is_empty() {
+            out.push_str(" Z");
+        }
+        out
+    }
+}
+
+impl<T> Polyline<T>
+where
+    T: Coord + fmt::Display,
+{
+    /// Writes SVG path data like `Polygon::to_svg_path`, without closing the path.
+    pub fn to_svg_path(&self) -> String {
+        write_svg_positions(&self.vertices)
+    }
+}
+
+impl<T> Circle<T>
+where
+    T: Coord + fmt::Display,
+{
+    /// Writes SVG path data tracing the circle as two half-circle arcs from its leftmost point.
+    pub fn to_svg_path(&self) -> String {
+        let (x, y, r) = (&self.center.x, &self.center.y, &self.radius);
+        let (left, right) = (x.clone() - r.clone(), x.clone() + r.clone());
+        let mut out = String::new();
+        write!(
+            out,
+            "M {left} {y} A {r} {r} 0 1 0 {right} {y} A {r} {r} 0 1 0 {left} {y} Z",
+            left = left,
+            right = right,
+            y = y,
+            r = r
+        )
+        .expect("writing to a string");
+        out
+    }
+}
+
+fn ring<T>(polygon: &Polygon<T>) -> impl Iterator<Item = &Point<T>>
+where
+    T: Coord,
+{
+    polygon.vertices.iter().chain(polygon.vertices.first())
+}
+
+fn polygon_from_rings<T>(mut rings: Vec<Vec<Point<T>>>) -> Result<Polygon<T>, ParseError>
+where
+    T: Coord,
+{
+    if rings.len() > 1 {
+        return Err(ParseError::UnsupportedHoles);
+    }
+    let mut vertices = rings.pop().unwrap_or_default();
+    if !vertices.is_empty() {
+        if vertices.first() != vertices.last() {
+            return Err(ParseError::UnclosedRing);
+        }
+        vertices.pop();
+    }
+    Ok(Polygon::with_vertices(vertices))
+}
+
+fn write_wkt_position<T>(out: &mut String, p: &Point<T>)
+where
+    T: Coord + fmt::Display,
+{
+    write!(out, "{} {}", p.x, p.y).expect("writing to a string");
+}
+
+fn write_wkt_positions<'a, T>(out: &mut String, points: impl IntoIterator<Item = &'a Point<T>>)
+where
+    T: Coord + fmt::Display,
+{
+    out.push('(');
+    for (i, p) in points.into_iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_wkt_position(out, p);
+    }
+    out.push(')');
+}
+
+fn write_geojson_position<T>(out: &mut String, p: &Point<T>)
+where
+    T: Coord + fmt::Display,
+{
+    write!(out, "[{},{}]", p.x, p.y).expect("writing to a string");
+}
+
+fn write_geojson_positions<'a, T>(out: &mut String, points: impl IntoIterator<Item = &'a Point<T>>)
+where
+    T: Coord + fmt::Display,
+{
+    out.push('[');
+    for (i, p) in points.into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_geojson_position(out, p);
+    }
+    out.push(']');
+}
+
+fn write_svg_positions<T>(points: &[Point<T>]) -> String
+where
+    T: Coord + fmt::Display,
+{
+    let mut out = String::new();
+    for (i, p) in points.iter().enumerate() {
+        let command = if i == 0 { "M" } else { " L" };
+        write!(out, "{} {} {}", command, p.x, p.y).expect("writing to a string");
+    }
+    out
+}
+
+enum Json<'a> {
+    Number(usize, &'a str),
+    String(String),
+    Array(Vec<Json<'a>>),
+    Object(Vec<(String, Json<'a>)>),
+    Literal,
+}
+
+fn elements<'v, 'a>(value: &'v Json<'a>) -> Result<&'v [Json<'a>], ParseError> {
+    match value {
+        Json::Array(elements) => Ok(elements),
+        _ => Err(ParseError::InvalidPosition),
+    }
+}
+
+fn position<T>(value: &Json<'_>) -> Result<Point<T>, ParseError>
+where
+    T: Coord + FromStr,
+{
+    let coordinate = |value: &Json<'_>| match *value {
+        Json::Number(offset, token) => token.parse().map_err(|_| ParseError::InvalidNumber(offset)),
+        _ => Err(ParseError::InvalidPosition),
+    };
+    match elements(value)? {
+        [x, y] => Ok(Point::with_coords(coordinate(x)?, coordinate(y)?)),
+        _ => Err(ParseError::InvalidPosition),
+    }
+}
+
+fn positions<T>(value: &Json<'_>) -> Result<Vec<Point<T>>, ParseError>
+where
+    T: Coord + FromStr,
+{
+    elements(value)?.iter().map(position).collect()
+}
+
+#[derive(Clone)]
+struct Cursor<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+        self.input.as_bytes().get(self.position).copied()
+    }
+
+    fn unexpected(&mut self) -> ParseError {
+        match self.peek() {
+            Some(_) => ParseError::UnexpectedCharacter(self.position),
+            None => ParseError::UnexpectedEnd,
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ParseError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(_) => Err(self.unexpected()),
+            None => Ok(()),
+        }
+    }
+
+    fn take_while(&mut self, accept: impl Fn(u8) -> bool) -> (usize, &'a str) {
+        self.peek();
+        let start = self.position;
+        let length = self.input.as_bytes()[start..]
+            .iter()
+            .take_while(|&&byte| accept(byte))
+            .count();
+        self.position += length;
+        (start, &self.input[start..self.position])
+    }
+
+    fn word(&mut self) -> &'a str {
+        self.take_while(|byte| byte.is_ascii_alphabetic()).1
+    }
+
+    fn number_token(&mut self) -> Result<(usize, &'a str), ParseError> {
+        let (start, token) = self.take_while(|byte| {
+            byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.' | b'e' | b'E')
+        });
+        if token.is_empty() {
+            return Err(self.unexpected());
+        }
+        Ok((start, token))
+    }
+
+    fn coordinate<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+    {
+        let (start, token) = self.number_token()?;
+        token.parse().map_err(|_| ParseError::InvalidNumber(start))
+    }
+
+    fn wkt_position<T>(&mut self) -> Result<Point<T>, ParseError>
+    where
+        T: Coord + FromStr,
+    {
+        let p = Point::with_coords(self.coordinate()?, self.coordinate()?);
+        match self.peek() {
+            Some(byte) if byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.') => {
+                Err(ParseError::InvalidPosition)
+            }
+            _ => Ok(p),
+        }
+    }
+
+    fn wkt_positions<T>(&mut self) -> Result<Vec<Point<T>>, ParseError>
+    where
+        T: Coord + FromStr,
+    {
+        self.expect(b'(')?;
+        let mut points = vec![self.wkt_position()?];
+        while self.eat(b',') {
+            points.push(self.wkt_position()?);
+        }
+        self.expect(b')')?;
+        Ok(points)
+    }
+
+    fn json_value(&mut self) -> Result<Json<'a>, ParseError> {
+        match self.peek() {
+            Some(b'{') => {
+                self.position += 1;
+                let mut members = Vec::new();
+                if !self.eat(b'}') {
+                    loop {
+                        let name = self.json_string()?;
+                        self.expect(b':')?;
+                        members.push((name, self.json_value()?));
+                        if !self.eat(b',') {
+                            break;
+                        }
+                    }
+                    self.expect(b'}')?;
+                }
+                Ok(Json::Object(members))
+            }
+            Some(b'[') => {
+                self.position += 1;
+                let mut elements = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        elements.push(self.json_value()?);
+                        if !self.eat(b',') {
+                            break;
+                        }
+                    }
+                    self.expect(b']')?;
+                }
+                Ok(Json::Array(elements))
+            }
+            Some(b'"') => Ok(Json::String(self.json_string()?)),
+            Some(byte) if byte.is_ascii_alphabetic() => {
+                let start = self.position;
+                match self.word() {
+                    "true" | "false" | "null" => Ok(Json::Literal),
+                    _ => Err(ParseError::UnexpectedCharacter(start)),
+                }
+            }
+            _ => {
+                let (start, token) = self.number_token()?;
+                Ok(Json::Number(start, token))
+            }
+        }
+    }
+
+    fn json_string(&mut self) -> Result<String, ParseError> {
+        self.expect(b'"')?;
+        let start = self.position;
+        let mut chars = self.input[start..].char_indices();
+        let mut string = String::new();
+        loop {
+            let (offset, c) = chars.next().ok_or(ParseError::UnexpectedEnd)?;
+            match c {
+                '"' => {
+                    self.position = start + offset + 1;
+                    return Ok(string);
+                }
+                '\\' => {
+                    let (offset, escape) = chars.next().ok_or(ParseError::UnexpectedEnd)?;
+                    let unescaped = match escape {
+                        '"' | '\\' | '/' => escape,
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => {
+                            let digits = self
+                                .input
+                                .get(start + offset + 1..start + offset + 5)
+                                .ok_or(ParseError::UnexpectedEnd)?;
+                            let code = u32::from_str_radix(digits, 16)
+                                .map_err(|_| ParseError::UnexpectedCharacter(start + offset + 1))?;
+                            chars.nth(3);
+                            core::char::from_u32(code).unwrap_or(core::char::REPLACEMENT_CHARACTER)
+                        }
+                        _ => return Err(ParseError::UnexpectedCharacter(start + offset)),
+                    };
+                    string.push(unescaped);
+                }
+                c => string.push(c),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Polygon<i32> {
+        Polygon::with_vertices(vec![
+            Point::with_coords(0, 0),
+            Point::with_coords(4, 0),
+            Point::with_coords(4, 4),
+            Point::with_coords(0, 4),
+        ])
+    }
+
+    fn round_trip<T>(geometry: Geometry<T>)
+    where
+        T: Coord + fmt::Display + FromStr + fmt::Debug,
+    {
+        assert_eq!(Geometry::from_wkt(&geometry.to_wkt()), Ok(geometry.clone()));
+        assert_eq!(Geometry::from_geojson(&geometry.to_geojson()), Ok(geometry));
+    }
+
+    #[test]
+    fn writes_wkt() {
+        assert_eq!(
+            Geometry::Point(Point::with_coords(3, -4)).to_wkt(),
+            "POINT (3 -4)"
+        );
+        assert_eq!(
+            Geometry::from(vec![Point::with_coords(0, 0), Point::with_coords(1, 2)]).to_wkt(),
+            "LINESTRING (0 0, 1 2)"
+        );
+        assert_eq!(
+            Geometry::Polygon(square()).to_wkt(),
+            "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))"
+        );
+        assert_eq!(
+            Geometry::<i32>::LineString(Polyline::default()).to_wkt(),
+            "LINESTRING EMPTY"
+        );
+        assert_eq!(
+            Geometry::Polygon(Polygon::<i32>::with_vertices(Vec::new())).to_wkt(),
+            "POLYGON EMPTY"
+        );
+    }
+
+    #[test]
+    fn writes_geojson() {
+        assert_eq!(
+            Geometry::Point(Point::with_coords(3, -4)).to_geojson(),
+            r#"{"type":"Point","coordinates":[3,-4]}"#
+        );
+        assert_eq!(
+            Geometry::from(vec![Point::with_coords(0, 0), Point::with_coords(1, 2)]).to_geojson(),
+            r#"{"type":"LineString","coordinates":[[0,0],[1,2]]}"#
+        );
+        assert_eq!(
+            Geometry::Polygon(square()).to_geojson(),
+            r#"{"type":"Polygon","coordinates":[[[0,0],[4,0],[4,4],[0,4],[0,0]]]}"#
+        );
+    }
+
+    #[test]
+    fn writes_svg_paths() {
+        let unit = Polygon::with_vertices(vec![
+            Point::with_coords(0, 0),
+            Point::with_coords(1, 0),
+            Point::with_coords(1, 1),
+            Point::with_coords(0, 1),
+        ]);
+        assert_eq!(unit.to_svg_path(), "M 0 0 L 1 0 L 1 1 L 0 1 Z");
+        assert_eq!(Polygon::<i32>::with_vertices(Vec::new()).to_svg_path(), "");
+
+        let path = Polyline::with_vertices(vec![
+            Point::with_coords(0.5, -1.0),
+            Point::with_coords(2.0, 3.25),
+        ]);
+        assert_eq!(path.to_svg_path(), "M 0.5 -1 L 2 3.25");
+
+        let circle = Circle::with_center_and_radius(Point::with_coords(3, 4), 2);
+        assert_eq!(
+            circle.to_svg_path(),
+            "M 1 4 A 2 2 0 1 0 5 4 A 2 2 0 1 0 1 4 Z"
+        );
+    }
+
+    #[test]
+    fn round_trips_geometries() {
+        round_trip(Geometry::Point(Point::with_coords(-7, 12)));
+        round_trip(Geometry::LineString(Polyline::with_vertices(vec![
+            Point::with_coords(1, 1),
+            Point::with_coords(-3, 5),
+            Point::with_coords(8, 0),
+        ])));
+        round_trip(Geometry::LineString(Polyline::<i32>::default()));
+        round_trip(Geometry::Polygon(square()));
+        round_trip(Geometry::Polygon(Polygon::<i32>::with_vertices(Vec::new())));
+    }
+
+    #[test]
+    fn round_trips_float_geometries_exactly() {
+        round_trip(Geometry::Point(Point::with_coords(0.1 + 0.2, -1e-300)));
+        round_trip(Geometry::Polygon(Polygon::with_vertices(vec![
+            Point::with_coords(0.5, -0.25),
+            Point::with_coords(1.0 / 3.0, 2.0),
+            Point::with_coords(-1e21, 6.02e23),
+        ])));
+        round_trip(Geometry::LineString(Polyline::with_vertices(vec![
+            Point::with_coords(1.5f32, 2.25f32),
+        ])));
+    }
+
+    #[test]
+    fn parses_wkt_with_free_whitespace_and_keyword_case() {
+        assert_eq!(
+            Geometry::from_wkt("  point(1.5   -2e3 ) "),
+            Ok(Geometry::Point(Point::with_coords(1.5, -2000.0)))
+        );
+        assert_eq!(
+            Geometry::from_wkt("Polygon((0 0,4 0,4 4,0 4,0 0))"),
+            Ok(Geometry::Polygon(square()))
+        );
+        assert_eq!(
+            Geometry::from_wkt("linestring empty"),
+            Ok(Geometry::<i32>::LineString(Polyline::default()))
+        );
+    }
+
+    #[test]
+    fn parses_geojson_with_members_in_any_order() {
+        let input = r#"{
+            "bbox": [0, 0, 4, 4],
+            "coordinates": [[[0, 0], [4, 0], [4, 4], [0, 4], [0, 0]]],
+            "properties": {"name": "a \"square\"!", "tags": [true, null]},
+            "type": "Polygon"
+        }"#;
+        assert_eq!(
+            Geometry::from_geojson(input),
+            Ok(Geometry::Polygon(square()))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_wkt() {
+        assert_eq!(
+            Geometry::<i32>::from_wkt("POINT (1 2"),
+            Err(ParseError::UnexpectedEnd)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_wkt("POINT (1, 2)"),
+            Err(ParseError::UnexpectedCharacter(8))
+        );
+        assert_eq!(
+            Geometry::<i32>::from_wkt("POINT (1.5 2)"),
+            Err(ParseError::InvalidNumber(7))
+        );
+        assert_eq!(
+            Geometry::<i32>::from_wkt("POINT (1 2 3)"),
+            Err(ParseError::InvalidPosition)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_wkt("POINT (1 2) x"),
+            Err(ParseError::UnexpectedCharacter(12))
+        );
+        assert_eq!(
+            Geometry::<i32>::from_wkt("POINT EMPTY"),
+            Err(ParseError::UnsupportedGeometry)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_wkt("MULTIPOINT ((1 2))"),
+            Err(ParseError::UnsupportedGeometry)
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_polygon_rings() {
+        assert_eq!(
+            Geometry::<i32>::from_wkt("POLYGON ((0 0, 4 0, 4 4))"),
+            Err(ParseError::UnclosedRing)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_wkt("POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))"),
+            Err(ParseError::UnsupportedHoles)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_geojson(
+                r#"{"type":"Polygon","coordinates":[[[0,0],[4,0],[4,4]]]}"#
+            ),
+            Err(ParseError::UnclosedRing)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_geojson() {
+        assert_eq!(
+            Geometry::<i32>::from_geojson(r#"{"type":"Point","coordinates":[1,2,3]}"#),
+            Err(ParseError::InvalidPosition)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_geojson(r#"{"type":"Point","coordinates":[1,"2"]}"#),
+            Err(ParseError::InvalidPosition)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_geojson(r#"{"type":"Point","coordinates":[1,0.5]}"#),
+            Err(ParseError::InvalidNumber(33))
+        );
+        assert_eq!(
+            Geometry::<i32>::from_geojson(r#"{"type":"MultiPoint","coordinates":[[1,2]]}"#),
+            Err(ParseError::UnsupportedGeometry)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_geojson(r#"{"type":"Point"}"#),
+            Err(ParseError::UnsupportedGeometry)
+        );
+        assert_eq!(
+            Geometry::<i32>::from_geojson(r#"{"type":"Point","coordinates":[1,2]"#),
+            Err(ParseError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn converts_primitives_into_geometries() {
+        assert_eq!(
+            Geometry::from(Point::with_coords(1, 2)),
+            Geometry::Point(Point::with_coords(1, 2))
+        );
+        assert_eq!(Geometry::from(square()), Geometry::Polygon(square()));
+        let path =
+            Polyline::with_vertices(vec![Point::with_coords(0, 0), Point::with_coords(3, 1)]);
+        assert_eq!(
+            Geometry::from(path.clone()),
+            Geometry::LineString(path.clone())
+        );
+        assert_eq!(
+            Geometry::from(path.vertices.clone()),
+            Geometry::LineString(path)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn emits_geojson_readable_by_serde_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&Geometry::Polygon(square()).to_geojson()).unwrap();
+        assert_eq!(json["type"], "Polygon");
+        assert_eq!(json["coordinates"][0][2], serde_json::json!([4, 4]));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::meta::FloatCoord;
+use crate::primitives::Point;
+
+/// Maps world coordinates onto a grid of square cells, where cell `(0, 0)` has its lower left
+/// corner at `origin` and every cell is `cell_size` wide, which must be positive.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CoordMap<T>
+where
+    T: FloatCoord,
+{
+    pub origin: Point<T>,
+    pub cell_size: T,
+}
+
+impl<T> CoordMap<T>
+where
+    T: FloatCoord,
+{
+    pub fn with_origin_and_cell_size(origin: Point<T>, cell_size: T) -> Self {
+        Self { origin, cell_size }
+    }
+
+    /// Returns the cell containing `p`, flooring towards negative infinity so that points just
+    /// below or left of the origin fall into cell `-1`. Points on a cell boundary belong to the
+    /// cell above or right of it.
+    pub fn world_to_cell(&self, p: Point<T>) -> Point<i64> {
+        let cell = |c: T, origin: T| {
+            ((c - origin) / self.cell_size)
+                .floor()
+                .to_i64()
+                .expect("cell index representable as i64")
+        };
+        Point::with_coords(cell(p.x, self.origin.x), cell(p.y, self.origin.y))
+    }
+
+    /// Returns the center of `cell`.
+    pub fn cell_to_world(&self, cell: Point<i64>) -> Point<T> {
+        let half = T::one() / (T::one() + T::one());
+        let world = |index: i64, origin: T| {
+            let index = T::from(index).expect("cell index representable as a coordinate");
+            origin + (index + half) * self.cell_size
+        };
+        Point::with_coords(world(cell.x, self.origin.x), world(cell.y, self.origin.y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_points_to_cells() {
+        let map = CoordMap::with_origin_and_cell_size(Point::with_coords(10.0, -5.0), 2.0);
+        assert_eq!(
+            map.world_to_cell(Point::with_coords(13.9, -5.1)),
+            Point::with_coords(1, -1)
+        );
+        assert_eq!(
+            map.world_to_cell(Point::with_coords(10.0, -3.0)),
+            Point::with_coords(0, 1)
+        );
+    }
+
+    #[test]
+    fn floors_points_just_below_origin_into_negative_cells() {
+        let map = CoordMap::with_origin_and_cell_size(Point::origin(), 1.0);
+        assert_eq!(
+            map.world_to_cell(Point::with_coords(-0.01, 0.5)),
+            Point::with_coords(-1, 0)
+        );
+        assert_eq!(
+            map.world_to_cell(Point::with_coords(0.5, -1e-9)),
+            Point::with_coords(0, -1)
+        );
+    }
+
+    #[test]
+    fn maps_cells_to_their_centers() {
+        let map = CoordMap::with_origin_and_cell_size(Point::with_coords(10.0, -5.0), 2.0);
+        assert_eq!(
+            map.cell_to_world(Point::with_coords(1, -1)),
+            Point::with_coords(13.0, -6.0)
+        );
+        for &(x, y) in &[(0, 0), (-3, 7), (12, -40)] {
+            let cell = Point::with_coords(x, y);
+            assert_eq!(map.world_to_cell(map.cell_to_world(cell)), cell);
+        }
+    }
+}
@@ -0,0 +1,722 @@
+use crate::meta::Coord;
+use crate::polygon::Polygon;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::primitives::to_f64_point;
+use crate::primitives::{orientation_by_ref, Point};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::triangulation::delaunay;
+use crate::vectors::Orientation;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cell::OnceCell;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::Bound;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::ToPrimitive;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum HullAlgorithm {
+    MonotoneChain,
+    GrahamScan,
+}
+
+/// Computes the convex hull with Andrew's monotone chain; see `convex_hull_with`.
+pub fn convex_hull<T>(points: &[Point<T>]) -> Polygon<T>
+where
+    T: Coord,
+{
+    convex_hull_with(points, HullAlgorithm::MonotoneChain)
+}
+
+/// Computes the convex hull of points from any iterator, such as an adapter chain, in the same
+/// way as `convex_hull`.
+pub fn convex_hull_iter<T>(points: impl IntoIterator<Item = Point<T>>) -> Polygon<T>
+where
+    T: Coord,
+{
+    hull_of(points.into_iter().collect(), HullAlgorithm::MonotoneChain)
+}
+
+/// Returns the hull vertices in counterclockwise order, starting from the lexicographically
+/// smallest point. Points lying on hull edges are not vertices. Degenerate inputs give an empty
+/// polygon, a single vertex (all points identical) or the two extremes (all points collinear).
+pub fn convex_hull_with<T>(points: &[Point<T>], algorithm: HullAlgorithm) -> Polygon<T>
+where
+    T: Coord,
+{
+    hull_of(points.to_vec(), algorithm)
+}
+
+/// Computes the hull vertices with Graham's scan around the lowest point, in the same order as
+/// `convex_hull` gives them.
+pub fn graham_scan<T>(points: &[Point<T>]) -> Vec<Point<T>>
+where
+    T: Coord,
+{
+    convex_hull_with(points, HullAlgorithm::GrahamScan).vertices
+}
+
+/// Computes the alpha shape of the points: the union of the Delaunay triangles whose circumradius
+/// is at most `alpha`, outlined counterclockwise without collinear vertices. Smaller values follow
+/// concave outlines more closely, while an `alpha` above every circumradius, such as infinity,
+/// gives the convex hull. Only the ring enclosing the largest area is returned, so holes and
+/// separate pieces are dropped; the polygon is empty if no triangle is small enough.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn concave_hull<T>(points: &[Point<T>], alpha: f64) -> Polygon<f64>
+where
+    T: Coord + Copy + ToPrimitive,
+{
+    let triangulation = delaunay(points);
+    let points: Vec<Point<f64>> = triangulation.points.iter().map(to_f64_point).collect();
+    let kept: Vec<bool> = triangulation
+        .triangles
+        .iter()
+        .map(|triangle| {
+            let [a, b, c] = triangle.vertices.map(|i| points[i]);
+            let sides = (b - a).magnitude() * (c - b).magnitude() * (a - c).magnitude();
+            sides <= alpha * 2.0 * (b - a).cross(c - a)
+        })
+        .collect();
+
+    // Edges of kept triangles whose neighbour across is missing or dropped run counterclockwise
+    // around the shape.
+    let mut boundary: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (triangle, _) in triangulation
+        .triangles
+        .iter()
+        .zip(&kept)
+        .filter(|(_, &k)| k)
+    {
+        for i in 0..3 {
+            if triangle.neighbors[i].is_none_or(|n| !kept[n]) {
+                let (a, b) = (
+                    triangle.vertices[(i + 1) % 3],
+                    triangle.vertices[(i + 2) % 3],
+                );
+                boundary.entry(a).or_default().push(b);
+            }
+        }
+    }
+
+    let mut best = Polygon::with_vertices(Vec::new());
+    let mut best_area = 0.0;
+    while let Some((&start, _)) = boundary.iter().next() {
+        let mut ring = Vec::new();
+        let mut current = start;
+        while let Some(next) = boundary.get_mut(&current).and_then(|ends| ends.pop()) {
+            if boundary[&current].is_empty() {
+                boundary.remove(&current);
+            }
+            ring.push(points[current]);
+            current = next;
+            if current == start {
+                break;
+            }
+        }
+        let mut ring = Polygon::with_vertices(ring);
+        let area = ring.twice_signed_area();
+        if area > best_area {
+            ring.simplify_collinear();
+            best = ring;
+            best_area = area;
+        }
+    }
+    best
+}
+
+/// A convex hull kept up to date as points are inserted one at a time, in the same form as
+/// `convex_hull` returns.
+///
+/// The lower and upper chains are kept in ordered maps from x to y, so an insertion takes
+/// O(log n) amortised time for a hull of n vertices: locating the point is logarithmic, and every
+/// vertex it removes was added by an earlier insertion. `hull` lists the vertices again, in linear
+/// time, only when the hull has changed since it was last called.
+#[derive(Clone, Debug)]
+pub struct DynamicHull<T>
+where
+    T: Coord,
+{
+    lower: Chain<T>,
+    upper: Chain<T>,
+    vertices: OnceCell<Vec<Point<T>>>,
+}
+
+impl<T> DynamicHull<T>
+where
+    T: Coord,
+{
+    pub fn new() -> Self {
+        Self {
+            lower: Chain::new(Orientation::Counterclockwise),
+            upper: Chain::new(Orientation::Clockwise),
+            vertices: OnceCell::new(),
+        }
+    }
+
+    /// Returns the hull vertices counterclockwise from the lexicographically smallest one.
+    pub fn hull(&self) -> &[Point<T>] {
+        self.vertices.get_or_init(|| {
+            let mut vertices: Vec<_> = self.lower.points().collect();
+            let mut upper: Vec<_> = self.upper.points().collect();
+            // The chains share their extreme vertices unless the hull has a vertical side there.
+            if upper.last() == vertices.last() {
+                upper.pop();
+            }
+            if !upper.is_empty() && upper.first() == vertices.first() {
+                upper.remove(0);
+            }
+            vertices.extend(upper.into_iter().rev());
+            vertices
+        })
+    }
+
+    /// Adds `p`, leaving the hull unchanged if `p` lies inside or on it.
+    pub fn insert(&mut self, p: Point<T>) {
+        let lower = self.lower.insert(p.clone());
+        let upper = self.upper.insert(p);
+        if lower || upper {
+            self.vertices = OnceCell::new();
+        }
+    }
+}
+
+impl<T> Default for DynamicHull<T>
+where
+    T: Coord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PartialEq for DynamicHull<T>
+where
+    T: Coord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.hull() == other.hull()
+    }
+}
+
+impl<T> Eq for DynamicHull<T> where T: Coord {}
+
+impl<T> Hash for DynamicHull<T>
+where
+    T: Coord + Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hull().hash(state);
+    }
+}
+
+impl<T> Extend<Point<T>> for DynamicHull<T>
+where
+    T: Coord,
+{
+    fn extend<I: IntoIterator<Item = Point<T>>>(&mut self, points: I) {
+        for p in points {
+            self.insert(p);
+        }
+    }
+}
+
+// One chain of a `DynamicHull` from left to right, with a single vertex per x. Consecutive edges
+// turn by `turn`: counterclockwise along the lower chain and clockwise along the upper one.
+#[derive(Clone, Debug)]
+struct Chain<T>
+where
+    T: Coord,
+{
+    vertices: BTreeMap<Abscissa<T>, T>,
+    turn: Orientation,
+}
+
+impl<T> Chain<T>
+where
+    T: Coord,
+{
+    fn new(turn: Orientation) -> Self {
+        Self {
+            vertices: BTreeMap::new(),
+            turn,
+        }
+    }
+
+    fn points(&self) -> impl DoubleEndedIterator<Item = Point<T>> + '_ {
+        self.vertices
+            .iter()
+            .map(|(x, y)| Point::with_coords(x.0.clone(), y.clone()))
+    }
+
+    // Adds `p` unless it lies on the inner side of the chain, telling whether it did.
+    fn insert(&mut self, p: Point<T>) -> bool {
+        let key = Abscissa(p.x.clone());
+        if let Some(y) = self.vertices.get(&key) {
+            let outward = match self.turn {
+                Orientation::Clockwise => p.y > *y,
+                _ => p.y < *y,
+            };
+            if !outward {
+                return false;
+            }
+        }
+        let before = self
+            .neighbours(Bound::Unbounded, Bound::Excluded(&key))
+            .next_back();
+        let after = self
+            .neighbours(Bound::Excluded(&key), Bound::Unbounded)
+            .next();
+        if let (Some(a), Some(b)) = (before, after) {
+            if orientation_by_ref(&a, &p, &b) != self.turn {
+                return false;
+            }
+        }
+
+        self.vertices.insert(key.clone(), p.y.clone());
+        loop {
+            let mut next = self.neighbours(Bound::Excluded(&key), Bound::Unbounded);
+            let pair = (next.next(), next.next());
+            drop(next);
+            match pair {
+                (Some(b), Some(c)) if orientation_by_ref(&p, &b, &c) != self.turn => {
+                    self.vertices.remove(&Abscissa(b.x));
+                }
+                _ => break,
+            }
+        }
+        loop {
+            let mut previous = self.neighbours(Bound::Unbounded, Bound::Excluded(&key));
+            let pair = (previous.next_back(), previous.next_back());
+            drop(previous);
+            match pair {
+                (Some(b), Some(c)) if orientation_by_ref(&c, &b, &p) != self.turn => {
+                    self.vertices.remove(&Abscissa(b.x));
+                }
+                _ => break,
+            }
+        }
+        true
+    }
+
+    fn neighbours(
+        &self,
+        from: Bound<&Abscissa<T>>,
+        to: Bound<&Abscissa<T>>,
+    ) -> impl DoubleEndedIterator<Item = Point<T>> + '_ {
+        self.vertices
+            .range::<Abscissa<T>, _>((from, to))
+            .map(|(x, y)| Point::with_coords(x.0.clone(), y.clone()))
+    }
+}
+
+// An x-coordinate as a map key, ordered as coordinates compare.
+#[derive(Clone, Debug)]
+struct Abscissa<T>(T);
+
+impl<T> PartialEq for Abscissa<T>
+where
+    T: PartialOrd,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Abscissa<T> where T: PartialOrd {}
+
+impl<T> PartialOrd for Abscissa<T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Abscissa<T>
+where
+    T: PartialOrd,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .partial_cmp(&other.0)
+            .expect("coordinates are comparable")
+    }
+}
+
+fn hull_of<T>(mut points: Vec<Point<T>>, algorithm: HullAlgorithm) -> Polygon<T>
+where
+    T: Coord,
+{
+    points.sort_by(|p, q| p.partial_cmp(q).expect("coordinates are comparable"));
+    points.dedup();
+    if points.len() < 3 {
+        return Polygon::with_vertices(points);
+    }
+
+    let vertices = match algorithm {
+        HullAlgorithm::MonotoneChain => monotone_chain(&points),
+        HullAlgorithm::GrahamScan => scan_around_pivot(points),
+    };
+    Polygon::with_vertices(vertices)
+}
+
+fn monotone_chain<T>(sorted: &[Point<T>]) -> Vec<Point<T>>
+where
+    T: Coord,
+{
+    let mut lower = Vec::new();
+    push_chain(&mut lower, sorted.iter().cloned());
+    let mut upper = Vec::new();
+    push_chain(&mut upper, sorted.iter().rev().cloned());
+
+    lower.pop();
+    upper.pop();
+    lower.append(&mut upper);
+    lower
+}
+
+fn scan_around_pivot<T>(mut points: Vec<Point<T>>) -> Vec<Point<T>>
+where
+    T: Coord,
+{
+    let (pivot_index, _) = points
+        .iter()
+        .enumerate()
+        .min_by(|(_, p), (_, q)| {
+            (&p.y, &p.x)
+                .partial_cmp(&(&q.y, &q.x))
+                .expect("coordinates are comparable")
+        })
+        .expect("at least three points");
+    let pivot = points.swap_remove(pivot_index);
+
+    points.sort_by(|p, q| match orientation_by_ref(&pivot, p, q) {
+        Orientation::Counterclockwise => Ordering::Less,
+        Orientation::Clockwise => Ordering::Greater,
+        Orientation::Collinear => (p - &pivot)
+            .norm_squared()
+            .partial_cmp(&(q - &pivot).norm_squared())
+            .expect("coordinates are comparable"),
+    });
+
+    let mut hull = Vec::with_capacity(points.len() + 1);
+    hull.push(pivot);
+    push_chain(&mut hull, points.into_iter());
+
+    let start = hull
+        .iter()
+        .enumerate()
+        .min_by(|(_, p), (_, q)| p.partial_cmp(q).expect("coordinates are comparable"))
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    hull.rotate_left(start);
+    hull
+}
+
+fn push_chain<T>(chain: &mut Vec<Point<T>>, points: impl Iterator<Item = Point<T>>)
+where
+    T: Coord,
+{
+    for p in points {
+        while chain.len() >= 2
+            && orientation_by_ref(&chain[chain.len() - 2], &chain[chain.len() - 1], &p)
+                != Orientation::Counterclockwise
+        {
+            chain.pop();
+        }
+        chain.push(p);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::orientation;
+    use crate::test_utils::random_points;
+
+    const ALGORITHMS: [HullAlgorithm; 2] =
+        [HullAlgorithm::MonotoneChain, HullAlgorithm::GrahamScan];
+
+    fn points(coords: &[(i64, i64)]) -> Vec<Point<i64>> {
+        coords
+            .iter()
+            .map(|&(x, y)| Point::with_coords(x, y))
+            .collect()
+    }
+
+    fn brute_force_hull_vertices(points: &[Point<i64>]) -> Vec<Point<i64>> {
+        let mut vertices: Vec<_> = points
+            .iter()
+            .copied()
+            .filter(|&p| {
+                points.iter().any(|&q| {
+                    q != p
+                        && points.iter().all(|&r| match orientation(p, q, r) {
+                            Orientation::Counterclockwise => true,
+                            Orientation::Clockwise => false,
+                            Orientation::Collinear => {
+                                (r - p).dot(q - p) >= 0 && (r - p).dot(r - q) <= 0
+                            }
+                        })
+                })
+            })
+            .collect();
+        vertices.sort();
+        vertices.dedup();
+        vertices
+    }
+
+    fn is_strictly_convex_counterclockwise(vertices: &[Point<i64>]) -> bool {
+        let n = vertices.len();
+        (0..n).all(|i| {
+            orientation(vertices[i], vertices[(i + 1) % n], vertices[(i + 2) % n])
+                == Orientation::Counterclockwise
+        })
+    }
+
+    #[test]
+    fn computes_hull_of_square_with_interior_points() {
+        let input = points(&[(1, 1), (0, 0), (2, 0), (1, 2), (2, 2), (0, 2), (1, 0)]);
+        for &algorithm in &ALGORITHMS {
+            let hull = convex_hull_with(&input, algorithm);
+            assert_eq!(hull.vertices, points(&[(0, 0), (2, 0), (2, 2), (0, 2)]));
+        }
+    }
+
+    #[test]
+    fn uses_monotone_chain_by_default() {
+        let input = random_points(7, 50, 20);
+        assert_eq!(
+            convex_hull(&input),
+            convex_hull_with(&input, HullAlgorithm::MonotoneChain)
+        );
+    }
+
+    #[test]
+    fn skips_collinear_points_on_hull_edges() {
+        let input = points(&[
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (3, 0),
+            (3, 3),
+            (0, 3),
+            (0, 1),
+            (2, 1),
+        ]);
+        for &algorithm in &ALGORITHMS {
+            let hull = convex_hull_with(&input, algorithm);
+            assert_eq!(hull.vertices, points(&[(0, 0), (3, 0), (3, 3), (0, 3)]));
+        }
+    }
+
+    #[test]
+    fn computes_empty_hull_of_no_points() {
+        for &algorithm in &ALGORITHMS {
+            assert!(convex_hull_with::<i64>(&[], algorithm).vertices.is_empty());
+        }
+    }
+
+    #[test]
+    fn computes_hull_of_single_point() {
+        let input = points(&[(4, 2)]);
+        for &algorithm in &ALGORITHMS {
+            assert_eq!(convex_hull_with(&input, algorithm).vertices, input);
+        }
+    }
+
+    #[test]
+    fn computes_hull_of_identical_points() {
+        let input = points(&[(4, 2), (4, 2), (4, 2)]);
+        for &algorithm in &ALGORITHMS {
+            assert_eq!(
+                convex_hull_with(&input, algorithm).vertices,
+                points(&[(4, 2)])
+            );
+        }
+    }
+
+    #[test]
+    fn computes_hull_of_collinear_points() {
+        let input = points(&[(2, 2), (0, 0), (3, 3), (1, 1), (3, 3)]);
+        for &algorithm in &ALGORITHMS {
+            assert_eq!(
+                convex_hull_with(&input, algorithm).vertices,
+                points(&[(0, 0), (3, 3)])
+            );
+        }
+    }
+
+    #[test]
+    fn computes_hull_of_points_near_integer_limits() {
+        let (min, max) = (i64::MIN, i64::MAX);
+        let input = points(&[
+            (0, 0),
+            (max, max),
+            (min, 0),
+            (max - 1, 0),
+            (min, min),
+            (max, min),
+            (min, max),
+        ]);
+        assert_eq!(
+            convex_hull(&input).vertices,
+            points(&[(min, min), (max, min), (max, max), (min, max)])
+        );
+    }
+
+    #[test]
+    fn computes_hull_of_float_points() {
+        let input = [
+            Point::with_coords(0.5, 0.5),
+            Point::with_coords(0.0, 0.0),
+            Point::with_coords(1.0, 0.0),
+            Point::with_coords(0.0, 1.0),
+        ];
+        assert_eq!(
+            convex_hull(&input).vertices,
+            vec![input[1], input[2], input[3]]
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn computes_concave_hull() {
+        let u_shape: Vec<_> = (0..=6)
+            .flat_map(|x| (0..=6).map(move |y| Point::with_coords(x, y)))
+            .filter(|p| !((2..=4).contains(&p.x) && p.y >= 2))
+            .collect();
+
+        // The notch keeps the small triangles cutting its inner corners.
+        let hull = concave_hull(&u_shape, 1.0);
+        assert_eq!(hull.area(), 17.0);
+        assert_eq!(hull.winding(), Orientation::Counterclockwise);
+        assert_eq!(hull.vertices.len(), 10);
+        assert!(!hull.contains_point(Point::with_coords(3.0, 4.0)));
+
+        let convex = concave_hull(&u_shape, f64::INFINITY);
+        assert_eq!(convex.area(), 36.0);
+        assert_eq!(convex.vertices.len(), 4);
+        for p in convex_hull(&u_shape).vertices {
+            assert!(convex.vertices.contains(&p.cast().unwrap()));
+        }
+        assert!(concave_hull(&u_shape, 0.5).vertices.is_empty());
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn matches_convex_hull_area_for_large_alpha() {
+        let points = random_points(4099, 200, 1000);
+        let convex = convex_hull(&points);
+        let concave = concave_hull(&points, f64::INFINITY);
+        assert_eq!(
+            concave.twice_signed_area(),
+            convex.twice_signed_area() as f64
+        );
+    }
+
+    #[test]
+    fn maintains_hull_under_insertion() {
+        let mut hull = DynamicHull::new();
+        hull.extend(points(&[(0, 0), (2, 0), (1, 0)]));
+        assert_eq!(hull.hull(), &points(&[(0, 0), (2, 0)])[..]);
+        hull.extend(points(&[(2, 2), (0, 2)]));
+        assert_eq!(hull.hull(), &points(&[(0, 0), (2, 0), (2, 2), (0, 2)])[..]);
+
+        let before = hull.clone();
+        hull.extend(points(&[(1, 1), (2, 1), (0, 0)]));
+        assert_eq!(hull, before);
+
+        hull.insert(Point::with_coords(3, 0));
+        assert_eq!(hull.hull(), &points(&[(0, 0), (3, 0), (2, 2), (0, 2)])[..]);
+        hull.insert(Point::with_coords(-1, -1));
+        assert_eq!(
+            hull.hull(),
+            &points(&[(-1, -1), (3, 0), (2, 2), (0, 2)])[..]
+        );
+    }
+
+    #[test]
+    fn matches_batch_hull_when_inserting_one_at_a_time() {
+        for seed in 1..=20 {
+            let points = random_points(seed * 104_729, 300, 40);
+            let mut hull = DynamicHull::new();
+            for (i, &p) in points.iter().enumerate() {
+                hull.insert(p);
+                if i % 50 == 0 {
+                    assert_eq!(hull.hull(), &convex_hull(&points[..=i]).vertices[..]);
+                }
+            }
+            assert_eq!(
+                hull.hull(),
+                &convex_hull(&points).vertices[..],
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn computes_hull_from_iterator() {
+        let coords = [(0, 0), (4, 0), (2, 1), (4, 4), (0, 4), (9, 9)];
+        let hull = convex_hull_iter(
+            coords
+                .iter()
+                .filter(|&&(x, _)| x < 9)
+                .map(|&(x, y)| Point::with_coords(x, y)),
+        );
+        assert_eq!(
+            hull,
+            convex_hull(&points(&[(0, 0), (4, 0), (2, 1), (4, 4), (0, 4)]))
+        );
+        assert_eq!(hull.vertices.len(), 4);
+        assert!(convex_hull_iter(core::iter::empty::<Point<i64>>())
+            .vertices
+            .is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force_hull_on_random_points() {
+        for seed in 1..=40 {
+            let input = random_points(seed * 7919, 5 + seed as usize * 3, 15);
+            let expected = brute_force_hull_vertices(&input);
+            for &algorithm in &ALGORITHMS {
+                let hull = convex_hull_with(&input, algorithm);
+                let mut vertices = hull.vertices.clone();
+                vertices.sort();
+                assert_eq!(vertices, expected, "seed {} with {:?}", seed, algorithm);
+                assert!(is_strictly_convex_counterclockwise(&hull.vertices));
+            }
+        }
+    }
+
+    #[test]
+    fn produces_same_hull_with_both_algorithms() {
+        for seed in 1..=20 {
+            let input = random_points(seed * 104_729, 200, 1000);
+            assert_eq!(
+                convex_hull_with(&input, HullAlgorithm::MonotoneChain),
+                convex_hull_with(&input, HullAlgorithm::GrahamScan)
+            );
+        }
+    }
+
+    #[test]
+    fn computes_hull_vertices_with_graham_scan() {
+        let square = points(&[(2, 2), (0, 0), (1, 1), (2, 0), (0, 2), (1, 0)]);
+        assert_eq!(
+            graham_scan(&square),
+            points(&[(0, 0), (2, 0), (2, 2), (0, 2)])
+        );
+        assert_eq!(
+            graham_scan(&points(&[(3, 3), (1, 1), (2, 2)])),
+            points(&[(1, 1), (3, 3)])
+        );
+        assert!(graham_scan::<i64>(&[]).is_empty());
+
+        let input = random_points(7, 200, 1000);
+        assert_eq!(graham_scan(&input), convex_hull(&input).vertices);
+    }
+}
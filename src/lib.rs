@@ -1,4 +1,66 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+
+pub mod angles;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod arc;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod boolean;
+mod bounding;
+mod ear_clipping;
+mod fixed;
+pub mod formats;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod grid;
+mod hull;
+mod matrix;
 pub mod meta;
+mod monotone;
+mod polygon;
+mod polyline;
+mod primitives;
+mod proximity;
+mod quadtree;
+mod rect;
+pub mod robust;
+#[cfg(feature = "serde")]
+pub mod serde_array;
+mod spatial;
+pub mod sweep;
+#[cfg(test)]
+mod test_utils;
+mod triangulation;
 mod vectors;
+mod vectors3;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod visibility;
+#[cfg(feature = "wkt")]
+pub mod wkt;
 
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use crate::arc::*;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use crate::boolean::*;
+pub use crate::bounding::*;
+pub use crate::fixed::*;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use crate::grid::*;
+pub use crate::hull::*;
+pub use crate::matrix::*;
+pub use crate::polygon::*;
+pub use crate::polyline::*;
+pub use crate::primitives::*;
+pub use crate::proximity::*;
+pub use crate::quadtree::*;
+pub use crate::rect::*;
+pub use crate::spatial::*;
+pub use crate::sweep::{intersections, overlapping_box_pairs, Intersection};
+pub use crate::triangulation::*;
 pub use crate::vectors::*;
+pub use crate::vectors3::*;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use crate::visibility::*;
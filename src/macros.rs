@@ -0,0 +1,67 @@
+// Implements the by-reference forms of an operator in terms of the by-value one by cloning the
+// operands, which is free for types that are `Copy`.
+
+macro_rules! forward_ref_unop {
+    (impl $imp:ident, $method:ident for $t:ty) => {
+        impl<'a, T> $imp for &'a $t
+        where
+            T: Coord,
+        {
+            type Output = <$t as $imp>::Output;
+
+            fn $method(self) -> Self::Output {
+                $imp::$method(self.clone())
+            }
+        }
+    };
+}
+
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident for $t:ty, $rhs:ty) => {
+        impl<'a, T> $imp<$rhs> for &'a $t
+        where
+            T: Coord,
+        {
+            type Output = <$t as $imp<$rhs>>::Output;
+
+            fn $method(self, other: $rhs) -> Self::Output {
+                $imp::$method(self.clone(), other)
+            }
+        }
+
+        impl<'a, T> $imp<&'a $rhs> for $t
+        where
+            T: Coord,
+        {
+            type Output = <$t as $imp<$rhs>>::Output;
+
+            fn $method(self, other: &'a $rhs) -> Self::Output {
+                $imp::$method(self, other.clone())
+            }
+        }
+
+        impl<'a, 'b, T> $imp<&'a $rhs> for &'b $t
+        where
+            T: Coord,
+        {
+            type Output = <$t as $imp<$rhs>>::Output;
+
+            fn $method(self, other: &'a $rhs) -> Self::Output {
+                $imp::$method(self.clone(), other.clone())
+            }
+        }
+    };
+}
+
+macro_rules! forward_ref_op_assign {
+    (impl $imp:ident, $method:ident for $t:ty, $rhs:ty) => {
+        impl<'a, T> $imp<&'a $rhs> for $t
+        where
+            T: Coord,
+        {
+            fn $method(&mut self, other: &'a $rhs) {
+                $imp::$method(self, other.clone());
+            }
+        }
+    };
+}
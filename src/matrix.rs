@@ -0,0 +1,339 @@
+use crate::meta::Coord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::meta::FloatCoord;
+use crate::primitives::Point;
+use crate::vectors::Vector;
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Mat3<T>
+where
+    T: Coord,
+{
+    pub rows: [[T; 3]; 3],
+}
+
+/// A 2D affine transform, stored as the 3×3 matrix acting on homogeneous coordinates, so that
+/// `inverse`, `inverse_transform_point` and the other `Mat3` methods serve as its API.
+pub type Transform2<T> = Mat3<T>;
+
+impl<T> Mat3<T>
+where
+    T: Coord,
+{
+    pub fn with_rows(rows: [[T; 3]; 3]) -> Self {
+        Self { rows }
+    }
+
+    pub fn identity() -> Self {
+        let (o, l) = (T::zero, T::one);
+        Self::with_rows([[l(), o(), o()], [o(), l(), o()], [o(), o(), l()]])
+    }
+
+    pub fn from_translation(offset: Vector<T>) -> Self {
+        let (o, l) = (T::zero, T::one);
+        Self::with_rows([[l(), o(), offset.x], [o(), l(), offset.y], [o(), o(), l()]])
+    }
+
+    pub fn from_scale(factors: Vector<T>) -> Self {
+        let (o, l) = (T::zero, T::one);
+        Self::with_rows([
+            [factors.x, o(), o()],
+            [o(), factors.y, o()],
+            [o(), o(), l()],
+        ])
+    }
+
+    /// Treats `p` as a position, so the translation part of the matrix applies.
+    pub fn transform_point(&self, p: Vector<T>) -> Vector<T> {
+        let row =
+            |r: &[T; 3]| r[0].clone() * p.x.clone() + r[1].clone() * p.y.clone() + r[2].clone();
+        Vector::with_coords(row(&self.rows[0]), row(&self.rows[1]))
+    }
+
+    /// Treats `v` as a direction, so the translation part of the matrix is ignored.
+    pub fn transform_vector(&self, v: Vector<T>) -> Vector<T> {
+        let row = |r: &[T; 3]| r[0].clone() * v.x.clone() + r[1].clone() * v.y.clone();
+        Vector::with_coords(row(&self.rows[0]), row(&self.rows[1]))
+    }
+
+    /// Applies a `Transform2` to a whole slice, transforming every point like `transform_point`,
+    /// in order.
+    pub fn transform_points(&self, points: &[Point<T>]) -> Vec<Point<T>> {
+        let mut transformed = points.to_vec();
+        self.transform_points_in_place(&mut transformed);
+        transformed
+    }
+
+    /// Applies a `Transform2` to a whole slice in place, like `transform_points`. The matrix is
+    /// read once for all points.
+    pub fn transform_points_in_place(&self, points: &mut [Point<T>]) {
+        let [[a, b, c], [d, e, f], _] = self.rows.clone();
+        for p in points {
+            let (x, y) = (p.x.clone(), p.y.clone());
+            p.x = a.clone() * x.clone() + b.clone() * y.clone() + c.clone();
+            p.y = d.clone() * x + e.clone() * y + f.clone();
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Mat3<T>
+where
+    T: FloatCoord,
+{
+    pub fn from_rotation(radians: T) -> Self {
+        let (o, l) = (T::zero(), T::one());
+        let (sin, cos) = radians.sin_cos();
+        Self::with_rows([[cos, -sin, o], [sin, cos, o], [o, o, l]])
+    }
+
+    /// Returns the inverse, or `None` if the determinant is zero, as for a transform that scales
+    /// by zero along some axis.
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.rows;
+        let cofactor = |i: usize, j: usize| {
+            let (i1, i2, j1, j2) = ((i + 1) % 3, (i + 2) % 3, (j + 1) % 3, (j + 2) % 3);
+            m[i1][j1] * m[i2][j2] - m[i1][j2] * m[i2][j1]
+        };
+        let determinant = (0..3).fold(T::zero(), |sum, j| sum + m[0][j] * cofactor(0, j));
+        if determinant.is_zero() {
+            return None;
+        }
+        Some(Self::with_rows(core::array::from_fn(|i| {
+            core::array::from_fn(|j| cofactor(j, i) / determinant)
+        })))
+    }
+
+    /// Maps `p` back through the transform, so that it undoes `transform_point`; see `inverse`.
+    pub fn inverse_transform_point(&self, p: Vector<T>) -> Option<Vector<T>> {
+        self.inverse().map(|inverse| inverse.transform_point(p))
+    }
+
+    /// Splits a `Transform2` built as translation * rotation * scale back into its
+    /// `(translation, radians, scale)` parts. A reflection is folded into a negative y scale.
+    /// Returns `None` for singular, sheared or projective matrices, which have no such form.
+    pub fn decompose(&self) -> Option<(Vector<T>, T, Vector<T>)> {
+        let m = &self.rows;
+        if !m[2][0].is_zero() || !m[2][1].is_zero() || m[2][2] != T::one() {
+            return None;
+        }
+
+        let (first, second) = ((m[0][0], m[1][0]), (m[0][1], m[1][1]));
+        let scale_x = first.0.hypot(first.1);
+        let scale_y = second.0.hypot(second.1);
+        if scale_x.is_zero() || scale_y.is_zero() {
+            return None;
+        }
+        let skew = first.0 * second.0 + first.1 * second.1;
+        if skew.abs() > T::epsilon().sqrt() * scale_x * scale_y {
+            return None;
+        }
+
+        let determinant = first.0 * second.1 - second.0 * first.1;
+        let radians = first.1.atan2(first.0);
+        let translation = Vector::with_coords(m[0][2], m[1][2]);
+        Some((
+            translation,
+            radians,
+            Vector::with_coords(scale_x, determinant / scale_x),
+        ))
+    }
+}
+
+impl<T> Mul for Mat3<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let (a, b) = (&self.rows, &other.rows);
+        Self::with_rows(core::array::from_fn(|i| {
+            core::array::from_fn(|j| {
+                (0..3).fold(T::zero(), |sum, k| sum + a[i][k].clone() * b[k][j].clone())
+            })
+        }))
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for Mat3<T>, Mat3<T>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use std::f64::consts::FRAC_PI_2;
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn assert_close(v: &Vector<f64>, w: &Vector<f64>) {
+        assert!((v.x - w.x).abs() < 1e-9, "{} != {}", v, w);
+        assert!((v.y - w.y).abs() < 1e-9, "{} != {}", v, w);
+    }
+
+    #[test]
+    fn leaves_point_unchanged_with_identity() {
+        let m: Mat3<i32> = Mat3::identity();
+        assert_eq!(
+            m.transform_point(Vector::with_coords(4, 2)),
+            Vector::with_coords(4, 2)
+        );
+    }
+
+    #[test]
+    fn transforms_slices_of_points() {
+        let m = Mat3::with_rows([[2, -1, 3], [1, 1, -4], [0, 0, 1]]);
+        let points = [
+            Point::with_coords(0, 0),
+            Point::with_coords(4, 2),
+            Point::with_coords(-3, 5),
+        ];
+        let expected: Vec<_> = points
+            .iter()
+            .map(|p| {
+                let v = m.transform_point(Vector::with_coords(p.x, p.y));
+                Point::with_coords(v.x, v.y)
+            })
+            .collect();
+        assert_eq!(m.transform_points(&points), expected);
+
+        let mut in_place = points;
+        m.transform_points_in_place(&mut in_place);
+        assert_eq!(in_place[..], expected[..]);
+        assert!(m.transform_points(&[]).is_empty());
+    }
+
+    #[test]
+    fn translates_point() {
+        let m = Mat3::from_translation(Vector::with_coords(1, -2));
+        assert_eq!(
+            m.transform_point(Vector::with_coords(4, 2)),
+            Vector::with_coords(5, 0)
+        );
+    }
+
+    #[test]
+    fn ignores_translation_when_transforming_vector() {
+        let m = Mat3::from_translation(Vector::with_coords(1, -2));
+        assert_eq!(
+            m.transform_vector(Vector::with_coords(4, 2)),
+            Vector::with_coords(4, 2)
+        );
+    }
+
+    #[test]
+    fn scales_point() {
+        let m = Mat3::from_scale(Vector::with_coords(2, 3));
+        assert_eq!(
+            m.transform_point(Vector::with_coords(4, 2)),
+            Vector::with_coords(8, 6)
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn rotates_point_counterclockwise() {
+        let m = Mat3::from_rotation(FRAC_PI_2);
+        assert_close(
+            &m.transform_point(Vector::with_coords(1.0, 0.0)),
+            &Vector::with_coords(0.0, 1.0),
+        );
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn multiplies_by_identity() {
+        let m = Mat3::from_translation(Vector::with_coords(1, 2)) * Mat3::identity();
+        assert_eq!(m, Mat3::from_translation(Vector::with_coords(1, 2)));
+        assert_eq!(&m * &Mat3::identity(), m);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn decomposes_translate_rotate_scale_transform() {
+        let m = Mat3::from_translation(Vector::with_coords(3.0, -1.0))
+            * Mat3::from_rotation(2.5)
+            * Mat3::from_scale(Vector::with_coords(2.0, 0.5));
+        let (translation, radians, scale) = m.decompose().unwrap();
+        assert_close(&translation, &Vector::with_coords(3.0, -1.0));
+        assert!((radians - 2.5).abs() < 1e-9);
+        assert_close(&scale, &Vector::with_coords(2.0, 0.5));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn folds_reflection_into_negative_scale_when_decomposing() {
+        let m = Mat3::from_rotation(-0.5_f64) * Mat3::from_scale(Vector::with_coords(1.0, -3.0));
+        let (_, radians, scale) = m.decompose().unwrap();
+        assert!((radians + 0.5).abs() < 1e-9);
+        assert_close(&scale, &Vector::with_coords(1.0, -3.0));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn refuses_to_decompose_sheared_transform() {
+        let shear = Mat3::with_rows([[1.0, 0.5, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_eq!(shear.decompose(), None);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn refuses_to_decompose_singular_transform() {
+        let flat = Mat3::from_scale(Vector::with_coords(0.0, 1.0));
+        assert_eq!(flat.decompose(), None);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn inverts_transform() {
+        let m: Transform2<f64> = Mat3::from_translation(Vector::with_coords(3.0, -1.0))
+            * Mat3::from_rotation(0.7)
+            * Mat3::from_scale(Vector::with_coords(2.0, -0.5));
+        let inverse = m.inverse().unwrap();
+        for &(x, y) in &[(0.0, 0.0), (2.0, 5.0), (-4.5, 1.25)] {
+            let p = Vector::with_coords(x, y);
+            assert_close(&inverse.transform_point(m.transform_point(p)), &p);
+            assert_close(
+                &m.inverse_transform_point(m.transform_point(p)).unwrap(),
+                &p,
+            );
+        }
+
+        let product = m * inverse;
+        for (i, row) in product.rows.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((value - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn refuses_to_invert_singular_transform() {
+        let flat = Mat3::from_translation(Vector::with_coords(1.0, 2.0))
+            * Mat3::from_scale(Vector::with_coords(3.0, 0.0));
+        assert_eq!(flat.inverse(), None);
+        assert_eq!(
+            flat.inverse_transform_point(Vector::with_coords(1.0, 1.0)),
+            None
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn composes_transforms_right_to_left() {
+        let translate = Mat3::from_translation(Vector::with_coords(3.0, 1.0));
+        let rotate = Mat3::from_rotation(FRAC_PI_2);
+        let composed =
+            Mat3::from_rotation(FRAC_PI_2) * Mat3::from_translation(Vector::with_coords(3.0, 1.0));
+
+        let p = Vector::with_coords(2.0, 5.0);
+        let separately =
+            rotate.transform_point(translate.transform_point(Vector::with_coords(2.0, 5.0)));
+
+        assert_close(&separately, &Vector::with_coords(-6.0, 5.0));
+        assert_close(&composed.transform_point(p), &separately);
+    }
+}
@@ -1,5 +1,256 @@
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::float::{Float, FloatConst};
 use num_traits::sign::Signed;
 
-pub trait Coord: Signed + Copy {}
+/// Coordinates only need to be `Clone`, so that exact types such as big rationals work with
+/// vectors, points, segments, polygons and hulls; with the `bigint` feature, `num_bigint::BigInt`
+/// evaluates the predicates without ever overflowing. The sweep, the triangulations and the
+/// spatial structures clone coordinates constantly and additionally require `Copy`.
+pub trait Coord: Signed + Clone + PartialOrd + 'static {}
 
-impl<T> Coord for T where T: Signed + Copy {}
+impl<T> Coord for T where T: Signed + Clone + PartialOrd + 'static {}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub trait FloatCoord: Coord + Float + FloatConst {}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> FloatCoord for T where T: Coord + Float + FloatConst {}
+
+#[cfg(test)]
+mod tests {
+    use crate::hull::convex_hull;
+    use crate::matrix::Mat3;
+    use crate::polygon::Polygon;
+    use crate::primitives::{
+        in_circle, orientation, CirclePosition, Point, Segment, SegmentIntersection,
+    };
+    use crate::vectors::{Orientation, Vector};
+    use alloc::boxed::Box;
+    use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+    use num_traits::{Num, One, Signed, ToPrimitive, Zero};
+
+    // An integer behind a heap allocation, standing in for arbitrary-precision types that cannot
+    // be `Copy`.
+    #[derive(Clone, PartialEq, PartialOrd, Debug)]
+    struct Boxed(Box<i64>);
+
+    fn boxed(value: i64) -> Boxed {
+        Boxed(Box::new(value))
+    }
+
+    fn point(x: i64, y: i64) -> Point<Boxed> {
+        Point::with_coords(boxed(x), boxed(y))
+    }
+
+    macro_rules! impl_binop {
+        ($imp:ident, $method:ident) => {
+            impl $imp for Boxed {
+                type Output = Self;
+
+                fn $method(self, other: Self) -> Self::Output {
+                    boxed($imp::$method(*self.0, *other.0))
+                }
+            }
+        };
+    }
+
+    impl_binop!(Add, add);
+    impl_binop!(Sub, sub);
+    impl_binop!(Mul, mul);
+    impl_binop!(Div, div);
+    impl_binop!(Rem, rem);
+
+    impl Neg for Boxed {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            boxed(-*self.0)
+        }
+    }
+
+    impl Zero for Boxed {
+        fn zero() -> Self {
+            boxed(0)
+        }
+
+        fn is_zero(&self) -> bool {
+            *self.0 == 0
+        }
+    }
+
+    impl One for Boxed {
+        fn one() -> Self {
+            boxed(1)
+        }
+    }
+
+    impl Num for Boxed {
+        type FromStrRadixErr = core::num::ParseIntError;
+
+        fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            i64::from_str_radix(s, radix).map(boxed)
+        }
+    }
+
+    impl Signed for Boxed {
+        fn abs(&self) -> Self {
+            boxed(self.0.abs())
+        }
+
+        fn abs_sub(&self, other: &Self) -> Self {
+            boxed(Signed::abs_sub(&*self.0, &*other.0))
+        }
+
+        fn signum(&self) -> Self {
+            boxed(self.0.signum())
+        }
+
+        fn is_positive(&self) -> bool {
+            *self.0 > 0
+        }
+
+        fn is_negative(&self) -> bool {
+            *self.0 < 0
+        }
+    }
+
+    impl ToPrimitive for Boxed {
+        fn to_i64(&self) -> Option<i64> {
+            Some(*self.0)
+        }
+
+        fn to_u64(&self) -> Option<u64> {
+            self.0.to_u64()
+        }
+    }
+
+    #[test]
+    fn computes_with_non_copy_vectors() {
+        let v = Vector::with_coords(boxed(3), boxed(-4));
+        let w = Vector::with_coords(boxed(2), boxed(5));
+        assert_eq!(v.clone().dot(w.clone()), boxed(-14));
+        assert_eq!(v.clone().cross(w.clone()), boxed(23));
+        assert_eq!(v.clone().norm_squared(), boxed(25));
+        assert_eq!(&v + &w, Vector::with_coords(boxed(5), boxed(1)));
+
+        let mut u = v.clone();
+        u -= &w;
+        u *= boxed(2);
+        assert_eq!(u, Vector::with_coords(boxed(2), boxed(-18)));
+        assert_eq!(&v * boxed(3), Vector::with_coords(boxed(9), boxed(-12)));
+    }
+
+    #[test]
+    fn evaluates_predicates_on_non_copy_coordinates() {
+        assert_eq!(
+            orientation(point(0, 0), point(4, 0), point(1, 1)),
+            Orientation::Counterclockwise
+        );
+        assert_eq!(
+            in_circle(point(0, 0), point(4, 0), point(0, 4), point(1, 1)),
+            CirclePosition::Inside
+        );
+    }
+
+    #[test]
+    fn intersects_non_copy_segments() {
+        let a = Segment::with_endpoints(point(0, 0), point(4, 4));
+        let b = Segment::with_endpoints(point(0, 4), point(4, 0));
+        assert!(a.intersects(&b));
+        assert_eq!(
+            a.intersection_point(&b),
+            SegmentIntersection::Point(Point::with_coords(2.0, 2.0))
+        );
+
+        let c = Segment::with_endpoints(point(2, 2), point(6, 6));
+        assert_eq!(
+            a.intersection_point(&c),
+            SegmentIntersection::Overlap(Segment::with_endpoints(point(2, 2), point(4, 4)))
+        );
+        assert!(a.contains_point(point(3, 3)));
+    }
+
+    #[test]
+    fn computes_hull_and_polygon_queries_with_non_copy_coordinates() {
+        let points = [
+            point(0, 0),
+            point(2, 1),
+            point(4, 0),
+            point(4, 4),
+            point(1, 2),
+            point(0, 4),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(
+            hull,
+            Polygon::with_vertices(vec![point(0, 0), point(4, 0), point(4, 4), point(0, 4)])
+        );
+        assert!(hull.is_convex());
+        assert_eq!(hull.twice_signed_area(), boxed(32));
+        assert!(hull.contains_point(point(1, 2)));
+        assert!(!hull.contains_point(point(5, 2)));
+    }
+
+    #[test]
+    fn transforms_with_non_copy_matrix() {
+        let m = Mat3::from_translation(Vector::with_coords(boxed(1), boxed(2)))
+            * Mat3::from_scale(Vector::with_coords(boxed(3), boxed(3)));
+        assert_eq!(
+            m.transform_point(Vector::with_coords(boxed(1), boxed(1))),
+            Vector::with_coords(boxed(4), boxed(5))
+        );
+        assert_eq!(
+            m.transform_vector(Vector::with_coords(boxed(1), boxed(1))),
+            Vector::with_coords(boxed(3), boxed(3))
+        );
+    }
+
+    #[cfg(feature = "bigint")]
+    mod bigint {
+        use super::*;
+        use num_bigint::BigInt;
+
+        fn huge(shift: usize, offset: i64) -> BigInt {
+            (BigInt::from(1) << shift) + offset
+        }
+
+        fn point(x: BigInt, y: BigInt) -> Point<BigInt> {
+            Point::with_coords(x, y)
+        }
+
+        #[test]
+        fn computes_orientation_beyond_i64() {
+            let (p, q) = (
+                point(BigInt::zero(), BigInt::zero()),
+                point(huge(80, 0), huge(80, 1)),
+            );
+            assert_eq!(
+                orientation(p.clone(), q.clone(), point(huge(80, -1), huge(80, 0))),
+                Orientation::Counterclockwise
+            );
+            assert_eq!(
+                orientation(p.clone(), q.clone(), point(huge(80, 1), huge(80, 2))),
+                Orientation::Clockwise
+            );
+            assert_eq!(
+                orientation(p, q, point(huge(81, 0), huge(81, 2))),
+                Orientation::Collinear
+            );
+        }
+
+        #[test]
+        fn locates_point_relative_to_circle_beyond_i64() {
+            let n = huge(70, 0);
+            let (a, b, c) = (
+                point(n.clone(), BigInt::zero()),
+                point(BigInt::zero(), n.clone()),
+                point(-n.clone(), BigInt::zero()),
+            );
+            let locate =
+                |y: BigInt| in_circle(a.clone(), b.clone(), c.clone(), point(BigInt::zero(), y));
+            assert_eq!(locate(-n.clone()), CirclePosition::OnCircle);
+            assert_eq!(locate(-n.clone() + 1), CirclePosition::Inside);
+            assert_eq!(locate(-n - 1), CirclePosition::Outside);
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use crate::meta::Coord;
+use crate::polygon::Polygon;
+use crate::primitives::{orientation, Point};
+use crate::vectors::Orientation;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+impl<T> Polygon<T>
+where
+    T: Coord + Copy,
+{
+    /// Splits a simple polygon of either winding into y-monotone pieces with the sweep of Lee and
+    /// Preparata, the first step of monotone triangulation. Pieces are counterclockwise and only
+    /// use the original vertices; repeated consecutive vertices are merged, and a polygon with
+    /// zero area has no pieces. Self-intersecting polygons give pieces that need not be monotone.
+    ///
+    /// Points with equal y are ordered by x, as if the plane were rotated slightly clockwise, so
+    /// horizontal edges are handled like any other. The sweep status is a plain list, so the
+    /// running time is quadratic in the worst case.
+    pub fn decompose_monotone(&self) -> Vec<Polygon<T>> {
+        let mut vertices = self.vertices.clone();
+        vertices.dedup();
+        while vertices.len() > 1 && vertices[0] == vertices[vertices.len() - 1] {
+            vertices.pop();
+        }
+        let mut polygon = Polygon::with_vertices(vertices);
+        match polygon.winding() {
+            Orientation::Collinear => return Vec::new(),
+            Orientation::Clockwise => polygon.vertices.reverse(),
+            Orientation::Counterclockwise => {}
+        }
+
+        let diagonals = Sweep::new(&polygon.vertices).run();
+        let mut pieces: Vec<Vec<usize>> = vec![(0..polygon.vertices.len()).collect()];
+        for (a, b) in diagonals {
+            let k = match pieces
+                .iter()
+                .position(|ring| ring.contains(&a) && ring.contains(&b))
+            {
+                Some(k) => k,
+                None => continue,
+            };
+            let ring = pieces.swap_remove(k);
+            let (i, j) = (
+                ring.iter().position(|&v| v == a).unwrap(),
+                ring.iter().position(|&v| v == b).unwrap(),
+            );
+            let (i, j) = (i.min(j), i.max(j));
+            let mut outer = ring[..=i].to_vec();
+            outer.extend_from_slice(&ring[j..]);
+            pieces.push(outer);
+            pieces.push(ring[i..=j].to_vec());
+        }
+
+        pieces
+            .into_iter()
+            .map(|ring| Polygon::with_vertices(ring.iter().map(|&v| polygon.vertices[v]).collect()))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VertexKind {
+    Start,
+    End,
+    Split,
+    Merge,
+    Regular,
+}
+
+// The state of the sweep over a counterclockwise polygon. Edge `i` runs from vertex `i` to the
+// next one; the status holds the edges crossing the sweep line with the interior to their right,
+// each with its helper, the lowest vertex above the sweep line that sees the edge horizontally.
+struct Sweep<'a, T>
+where
+    T: Coord + Copy,
+{
+    points: &'a [Point<T>],
+    status: Vec<(usize, usize)>,
+    diagonals: Vec<(usize, usize)>,
+}
+
+impl<'a, T> Sweep<'a, T>
+where
+    T: Coord + Copy,
+{
+    fn new(points: &'a [Point<T>]) -> Self {
+        Self {
+            points,
+            status: Vec::new(),
+            diagonals: Vec::new(),
+        }
+    }
+
+    fn run(mut self) -> Vec<(usize, usize)> {
+        let n = self.points.len();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| {
+            if self.above(i, j) {
+                Ordering::Less
+            } else if self.above(j, i) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        });
+
+        for v in order {
+            let previous = (v + n - 1) % n;
+            match self.kind(v) {
+                VertexKind::Start => self.status.push((v, v)),
+                VertexKind::End => {
+                    self.close(previous, v);
+                }
+                VertexKind::Split => {
+                    if let Some(left) = self.left_of(v) {
+                        self.diagonals.push((v, self.status[left].1));
+                        self.status[left].1 = v;
+                    }
+                    self.status.push((v, v));
+                }
+                VertexKind::Merge => {
+                    self.close(previous, v);
+                    self.pass_left(v);
+                }
+                VertexKind::Regular if self.above(previous, v) => {
+                    self.close(previous, v);
+                    self.status.push((v, v));
+                }
+                VertexKind::Regular => self.pass_left(v),
+            }
+        }
+        self.diagonals
+    }
+
+    // Tests whether the sweep reaches `i` before `j`.
+    fn above(&self, i: usize, j: usize) -> bool {
+        let (p, q) = (self.points[i], self.points[j]);
+        p.y > q.y || (p.y == q.y && p.x < q.x)
+    }
+
+    fn kind(&self, v: usize) -> VertexKind {
+        let n = self.points.len();
+        let (previous, next) = ((v + n - 1) % n, (v + 1) % n);
+        let convex = orientation(self.points[previous], self.points[v], self.points[next])
+            != Orientation::Clockwise;
+        match (self.above(v, previous), self.above(v, next), convex) {
+            (true, true, true) => VertexKind::Start,
+            (true, true, false) => VertexKind::Split,
+            (false, false, true) => VertexKind::End,
+            (false, false, false) => VertexKind::Merge,
+            _ => VertexKind::Regular,
+        }
+    }
+
+    // Removes `edge`, which ends at `v`, joining `v` to its helper if that is a merge vertex.
+    fn close(&mut self, edge: usize, v: usize) {
+        let k = match self.status.iter().position(|&(e, _)| e == edge) {
+            Some(k) => k,
+            None => return,
+        };
+        let (_, helper) = self.status.swap_remove(k);
+        if self.kind(helper) == VertexKind::Merge {
+            self.diagonals.push((v, helper));
+        }
+    }
+
+    // Makes `v` the helper of the edge to its left, joining it to the previous helper if that is
+    // a merge vertex.
+    fn pass_left(&mut self, v: usize) {
+        let left = match self.left_of(v) {
+            Some(left) => left,
+            None => return,
+        };
+        let helper = self.status[left].1;
+        if self.kind(helper) == VertexKind::Merge {
+            self.diagonals.push((v, helper));
+        }
+        self.status[left].1 = v;
+    }
+
+    // Returns the position in the status of the edge directly left of `v`, which always exists
+    // for simple polygons.
+    fn left_of(&self, v: usize) -> Option<usize> {
+        let mut best: Option<usize> = None;
+        for (k, &(edge, _)) in self.status.iter().enumerate() {
+            let (lower, upper) = self.endpoints(edge);
+            let is_left = orientation(self.points[lower], self.points[upper], self.points[v])
+                == Orientation::Clockwise;
+            if is_left && best.is_none_or(|b| self.right_of(edge, self.status[b].0)) {
+                best = Some(k);
+            }
+        }
+        best
+    }
+
+    // Tests whether `edge` lies right of `other` along the sweep line, given that both cross it
+    // without crossing each other. For such segments, the endpoints of at least one lie on a
+    // single side of the line through the other.
+    fn right_of(&self, edge: usize, other: usize) -> bool {
+        match self.side(edge, other) {
+            Some(side) => side == Orientation::Clockwise,
+            None => self.side(other, edge) == Some(Orientation::Counterclockwise),
+        }
+    }
+
+    // Returns on which side of the upward line through `of` the endpoints of `edge` lie, or `None`
+    // if they lie on both sides or on the line.
+    fn side(&self, edge: usize, of: usize) -> Option<Orientation> {
+        let (lower, upper) = self.endpoints(of);
+        let (a, b) = self.endpoints(edge);
+        let side = |p: usize| orientation(self.points[lower], self.points[upper], self.points[p]);
+        match (side(a), side(b)) {
+            (Orientation::Collinear, o) | (o, Orientation::Collinear)
+                if o != Orientation::Collinear =>
+            {
+                Some(o)
+            }
+            (o, p) if o == p && o != Orientation::Collinear => Some(o),
+            _ => None,
+        }
+    }
+
+    // Returns the endpoints of `edge` ordered as `(lower, upper)`.
+    fn endpoints(&self, edge: usize) -> (usize, usize) {
+        let next = (edge + 1) % self.points.len();
+        if self.above(edge, next) {
+            (next, edge)
+        } else {
+            (edge, next)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::Rng;
+
+    fn polygon(coords: &[(i64, i64)]) -> Polygon<i64> {
+        Polygon::with_vertices(
+            coords
+                .iter()
+                .map(|&(x, y)| Point::with_coords(x, y))
+                .collect(),
+        )
+    }
+
+    // A polygon is y-monotone exactly when a single vertex has both neighbours below it.
+    fn is_y_monotone(polygon: &Polygon<i64>) -> bool {
+        let n = polygon.vertices.len();
+        let above = |p: Point<i64>, q: Point<i64>| p.y > q.y || (p.y == q.y && p.x < q.x);
+        let peaks = (0..n)
+            .filter(|&i| {
+                let v = polygon.vertices[i];
+                above(v, polygon.vertices[(i + n - 1) % n])
+                    && above(v, polygon.vertices[(i + 1) % n])
+            })
+            .count();
+        peaks == 1
+    }
+
+    fn assert_partitions(shape: &Polygon<i64>, pieces: &[Polygon<i64>]) {
+        for piece in pieces {
+            assert!(is_y_monotone(piece), "{:?}", piece);
+            assert!(piece.is_counterclockwise(), "{:?}", piece);
+        }
+        let area: i64 = pieces.iter().map(Polygon::twice_signed_area).sum();
+        assert_eq!(area, shape.twice_signed_area().abs());
+    }
+
+    #[test]
+    fn keeps_monotone_polygon_whole() {
+        let l_shape = polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)]);
+        let pieces = l_shape.decompose_monotone();
+        assert_eq!(pieces, vec![l_shape.clone()]);
+
+        let mut clockwise = l_shape.clone();
+        clockwise.vertices.reverse();
+        assert_partitions(&l_shape, &clockwise.decompose_monotone());
+    }
+
+    #[test]
+    fn splits_at_merge_and_split_vertices() {
+        let cup = polygon(&[
+            (0, 0),
+            (6, 0),
+            (6, 4),
+            (4, 4),
+            (4, 2),
+            (2, 2),
+            (2, 4),
+            (0, 4),
+        ]);
+        let pieces = cup.decompose_monotone();
+        assert_eq!(pieces.len(), 2);
+        assert_partitions(&cup, &pieces);
+
+        let arch = polygon(&[
+            (0, 0),
+            (2, 0),
+            (2, 2),
+            (4, 2),
+            (4, 0),
+            (6, 0),
+            (6, 4),
+            (0, 4),
+        ]);
+        let pieces = arch.decompose_monotone();
+        assert_eq!(pieces.len(), 2);
+        assert_partitions(&arch, &pieces);
+
+        let comb = polygon(&[
+            (0, 0),
+            (2, 3),
+            (4, 0),
+            (6, 3),
+            (8, 0),
+            (8, 5),
+            (5, 5),
+            (4, 2),
+            (3, 5),
+            (0, 5),
+        ]);
+        let pieces = comb.decompose_monotone();
+        assert_eq!(pieces.len(), 4);
+        assert_partitions(&comb, &pieces);
+    }
+
+    #[test]
+    fn skips_polygons_without_area() {
+        assert!(polygon(&[(0, 0), (1, 1), (2, 2)])
+            .decompose_monotone()
+            .is_empty());
+        assert!(Polygon::<i64>::default().decompose_monotone().is_empty());
+        let repeated = polygon(&[(0, 0), (0, 0), (3, 0), (3, 3), (0, 0)]);
+        assert_eq!(repeated.decompose_monotone().len(), 1);
+    }
+
+    #[test]
+    fn partitions_random_star_polygons() {
+        let mut rng = Rng(0x5851_f42d_4c95_7f2d);
+        for round in 0..200 {
+            let n = 3 + round % 25;
+            let vertices = (0..n)
+                .map(|k| {
+                    let angle = std::f64::consts::TAU * (k as f64 + 0.5) / n as f64;
+                    let radius = rng.range(10, 40) as f64;
+                    Point::with_coords(
+                        (radius * angle.cos()).round() as i64,
+                        (radius * angle.sin()).round() as i64,
+                    )
+                })
+                .collect();
+            let star = Polygon::with_vertices(vertices);
+            assert_partitions(&star, &star.decompose_monotone());
+        }
+    }
+}
@@ -0,0 +1,1554 @@
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::bounding::{bounding_circle, circle_around, Circle};
+use crate::meta::Coord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::meta::FloatCoord;
+use crate::primitives::{orientation_by_ref, Point, Segment, SegmentIntersection};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::primitives::{to_f64, to_f64_point};
+use crate::sweep::intersections;
+use crate::vectors::{Orientation, Vector};
+use alloc::vec::Vec;
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use num_traits::ToPrimitive;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::{Float, FloatConst};
+
+// Arbitrary polygons have at most this many vertices, which keeps fuzzed inputs to quadratic
+// algorithms fast.
+#[cfg(feature = "arbitrary")]
+const MAX_ARBITRARY_VERTICES: usize = 64;
+
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon<T>
+where
+    T: Coord,
+{
+    pub vertices: Vec<Point<T>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ContainmentMethod {
+    RayCasting,
+    WindingNumber,
+}
+
+impl<T> Polygon<T>
+where
+    T: Coord,
+{
+    /// Same as `with_vertices`.
+    pub fn new(vertices: Vec<Point<T>>) -> Self {
+        Self::with_vertices(vertices)
+    }
+
+    pub fn with_vertices(vertices: Vec<Point<T>>) -> Self {
+        Self { vertices }
+    }
+
+    /// Yields the edges in vertex order, including the closing edge from the last vertex back to
+    /// the first.
+    pub fn edges(&self) -> impl Iterator<Item = Segment<T>> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| {
+            Segment::with_endpoints(self.vertices[i].clone(), self.vertices[(i + 1) % n].clone())
+        })
+    }
+
+    /// Computes the shoelace sum, which is exact for integer coordinates; it is positive for
+    /// counterclockwise polygons.
+    pub fn twice_signed_area(&self) -> T {
+        self.edges().fold(T::zero(), |sum, edge| {
+            sum + edge.start.x * edge.end.y - edge.end.x * edge.start.y
+        })
+    }
+
+    /// Polygons with zero area, including those with fewer than three vertices, are `Collinear`.
+    pub fn winding(&self) -> Orientation {
+        let twice_area = self.twice_signed_area();
+        if twice_area > T::zero() {
+            Orientation::Counterclockwise
+        } else if twice_area < T::zero() {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
+    /// Both `is_clockwise` and `is_counterclockwise` are false for polygons with zero area.
+    pub fn is_clockwise(&self) -> bool {
+        self.winding() == Orientation::Clockwise
+    }
+
+    pub fn is_counterclockwise(&self) -> bool {
+        self.winding() == Orientation::Counterclockwise
+    }
+
+    /// Reverses clockwise polygons so that the vertices run counterclockwise, the order produced
+    /// by `convex_hull`.
+    pub fn normalize_winding(&mut self) {
+        if self.winding() == Orientation::Clockwise {
+            self.vertices.reverse();
+        }
+    }
+
+    /// Returns the vertices rotated to start at the lexicographically smallest one and running in
+    /// whichever direction gives the lexicographically smaller sequence, so that polygons differing
+    /// only in starting vertex or winding get equal keys.
+    pub fn canonical_key(&self) -> Vec<Point<T>> {
+        let n = self.vertices.len();
+        let smallest = match self
+            .vertices
+            .iter()
+            .min_by(|p, q| p.partial_cmp(q).expect("coordinates are comparable"))
+        {
+            Some(smallest) => smallest,
+            None => return Vec::new(),
+        };
+        let mut best: Option<Vec<Point<T>>> = None;
+        for start in (0..n).filter(|&i| &self.vertices[i] == smallest) {
+            let forward = (0..n).map(|k| self.vertices[(start + k) % n].clone());
+            let backward = (0..n).map(|k| self.vertices[(start + n - k) % n].clone());
+            for candidate in [forward.collect::<Vec<_>>(), backward.collect()] {
+                if best.as_ref().is_none_or(|best| {
+                    candidate
+                        .partial_cmp(best)
+                        .expect("coordinates are comparable")
+                        == Ordering::Less
+                }) {
+                    best = Some(candidate);
+                }
+            }
+        }
+        best.unwrap_or_default()
+    }
+
+    pub fn translate(&mut self, offset: Vector<T>) {
+        for p in &mut self.vertices {
+            *p += offset.clone();
+        }
+    }
+
+    /// Removes every vertex collinear with its neighbours, including repeated vertices, spikes
+    /// doubling back along an edge and vertices next to the closing edge. A polygon without any
+    /// turn is left with at most two vertices.
+    pub fn simplify_collinear(&mut self) {
+        let mut vertices: Vec<Point<T>> = Vec::with_capacity(self.vertices.len());
+        for p in self.vertices.drain(..) {
+            while vertices.len() >= 2
+                && orientation_by_ref(
+                    &vertices[vertices.len() - 2],
+                    &vertices[vertices.len() - 1],
+                    &p,
+                ) == Orientation::Collinear
+            {
+                vertices.pop();
+            }
+            vertices.push(p);
+        }
+        loop {
+            let n = vertices.len();
+            if n >= 3
+                && orientation_by_ref(&vertices[n - 2], &vertices[n - 1], &vertices[0])
+                    == Orientation::Collinear
+            {
+                vertices.pop();
+            } else if n >= 3
+                && orientation_by_ref(&vertices[n - 1], &vertices[0], &vertices[1])
+                    == Orientation::Collinear
+            {
+                vertices.remove(0);
+            } else {
+                break;
+            }
+        }
+        self.vertices = vertices;
+    }
+
+    /// Collinear vertices are allowed, but polygons without any turn are not convex, nor are
+    /// self-intersecting ones such as a pentagram.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let mut turn = Orientation::Collinear;
+        for i in 0..n {
+            let (a, b, c) = (
+                &self.vertices[i],
+                &self.vertices[(i + 1) % n],
+                &self.vertices[(i + 2) % n],
+            );
+            match orientation_by_ref(a, b, c) {
+                Orientation::Collinear => {}
+                o if turn == Orientation::Collinear => turn = o,
+                o if o != turn => return false,
+                _ => {}
+            }
+        }
+
+        turn != Orientation::Collinear
+            && sign_changes(self.edges().map(|edge| edge.end.x - edge.start.x)) <= 2
+            && sign_changes(self.edges().map(|edge| edge.end.y - edge.start.y)) <= 2
+    }
+
+    /// Tests containment with the winding-number method; see `contains_point_with`.
+    pub fn contains_point(&self, p: Point<T>) -> bool {
+        self.contains_point_with(p, ContainmentMethod::WindingNumber)
+    }
+
+    /// Points on the boundary are contained. Ray casting applies the even-odd rule and winding
+    /// number the nonzero rule; they only disagree inside self-intersecting polygons.
+    pub fn contains_point_with(&self, p: Point<T>, method: ContainmentMethod) -> bool {
+        if self.is_point_on_boundary(p.clone()) {
+            return true;
+        }
+        match method {
+            ContainmentMethod::RayCasting => {
+                self.edges().filter(|edge| crossing(edge, &p) != 0).count() % 2 == 1
+            }
+            ContainmentMethod::WindingNumber => self.winding_number(p) != 0,
+        }
+    }
+
+    /// Tells whether `p` lies exactly on an edge, which `contains_point` does not distinguish from
+    /// the interior.
+    pub fn is_point_on_boundary(&self, p: Point<T>) -> bool {
+        self.edges().any(|edge| edge.contains_point(p.clone()))
+    }
+
+    /// Counts how many times the boundary winds counterclockwise around `p`; the result is
+    /// unspecified for points on the boundary.
+    pub fn winding_number(&self, p: Point<T>) -> i32 {
+        self.edges().map(|edge| crossing(&edge, &p)).sum()
+    }
+
+    /// Tells whether `inner` lies inside this polygon, with every vertex contained and no edges
+    /// meeting. Since touching edges count as meeting, an `inner` that touches the boundary is not
+    /// contained; an empty `inner` is.
+    pub fn contains_polygon(&self, inner: &Polygon<T>) -> bool {
+        inner
+            .vertices
+            .iter()
+            .all(|p| self.contains_point(p.clone()))
+            && !self
+                .edges()
+                .any(|edge| inner.edges().any(|other| edge.intersects(&other)))
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Coord + ToPrimitive,
+{
+    /// Returns the boundary point nearest to `p` with the index of its edge, which runs from
+    /// vertex `i` to the next one, or `None` for an empty polygon. Ties go to the lowest index.
+    pub fn closest_boundary_point(&self, p: Point<T>) -> Option<(Point<f64>, usize)> {
+        let target = p.clone().cast::<f64>()?;
+        let mut best: Option<(f64, Point<f64>, usize)> = None;
+        for (i, edge) in self.edges().enumerate() {
+            let closest = edge.closest_point_to(p.clone());
+            let distance = (closest - target).norm_squared();
+            if best.is_none_or(|(nearest, _, _)| distance < nearest) {
+                best = Some((distance, closest, i));
+            }
+        }
+        best.map(|(_, closest, i)| (closest, i))
+    }
+}
+
+impl<T> Polygon<T>
+where
+    T: Coord + Copy + ToPrimitive,
+{
+    /// Checks that the polygon is simple with some area, reporting the first problem found in the
+    /// order of the `PolygonValidationError` variants. Self-intersections are found with the
+    /// sweep behind `intersections`; edges meeting at their shared vertex are fine, but adjacent
+    /// edges doubling back over each other are not.
+    pub fn validate(&self) -> Result<(), PolygonValidationError> {
+        let n = self.vertices.len();
+        if n < 3 {
+            return Err(PolygonValidationError::TooFewVertices);
+        }
+        if (0..n).any(|i| self.vertices[i] == self.vertices[(i + 1) % n]) {
+            return Err(PolygonValidationError::DuplicateConsecutiveVertices);
+        }
+        if self.twice_signed_area().is_zero() {
+            return Err(PolygonValidationError::ZeroArea);
+        }
+
+        let edges: Vec<Segment<T>> = self.edges().collect();
+        let crossing = intersections(&edges).any(|intersection| {
+            let (i, j) = (intersection.first, intersection.second);
+            let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+            !adjacent || matches!(intersection.kind, SegmentIntersection::Overlap(_))
+        });
+        if crossing {
+            return Err(PolygonValidationError::SelfIntersecting);
+        }
+        Ok(())
+    }
+}
+
+/// Why `Polygon::validate` rejects a polygon.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PolygonValidationError {
+    /// The polygon has fewer than three vertices.
+    TooFewVertices,
+    /// Some vertex repeats the one before it, counting the last as coming before the first.
+    DuplicateConsecutiveVertices,
+    /// The signed area is zero, as when all vertices are collinear.
+    ZeroArea,
+    /// Two edges cross or touch other than at the vertex joining neighbouring edges.
+    SelfIntersecting,
+}
+
+impl fmt::Display for PolygonValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooFewVertices => write!(f, "polygon has fewer than three vertices"),
+            Self::DuplicateConsecutiveVertices => {
+                write!(f, "polygon repeats a vertex consecutively")
+            }
+            Self::ZeroArea => write!(f, "polygon has zero area"),
+            Self::SelfIntersecting => write!(f, "polygon intersects itself"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PolygonValidationError {}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Polygon<T>
+where
+    T: FloatCoord,
+{
+    pub fn signed_area(&self) -> T {
+        self.twice_signed_area() / (T::one() + T::one())
+    }
+
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+
+    pub fn perimeter(&self) -> T {
+        self.edges().fold(T::zero(), |sum, edge| {
+            sum + (edge.end - edge.start).magnitude()
+        })
+    }
+
+    /// Returns the centroid of the enclosed area, or `None` if the polygon has zero area.
+    pub fn centroid(&self) -> Option<Point<T>> {
+        let twice_area = self.twice_signed_area();
+        if twice_area == T::zero() {
+            return None;
+        }
+
+        let (x, y) = self.edges().fold((T::zero(), T::zero()), |(x, y), edge| {
+            let (a, b) = (edge.start, edge.end);
+            let cross = a.x * b.y - b.x * a.y;
+            (x + (a.x + b.x) * cross, y + (a.y + b.y) * cross)
+        });
+        let denominator = twice_area * (T::one() + T::one() + T::one());
+        Some(Point::with_coords(x / denominator, y / denominator))
+    }
+
+    /// Scales every vertex away from `center` by `factor`; negative factors also mirror the
+    /// polygon through `center`.
+    pub fn scale_about(&mut self, center: Point<T>, factor: T) {
+        for p in &mut self.vertices {
+            *p = center + (*p - center) * factor;
+        }
+    }
+
+    /// Rotates every vertex counterclockwise about `center`.
+    pub fn rotate_about(&mut self, center: Point<T>, radians: T) {
+        for p in &mut self.vertices {
+            *p = center + (*p - center).rotate(radians);
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Polygon<T>
+where
+    T: Coord + ToPrimitive,
+{
+    /// Returns a circle around the centroid that contains every vertex, or `None` for an empty
+    /// polygon. Polygons with zero area are centered on the mean of their vertices instead, like
+    /// `bounding_circle`.
+    pub fn bounding_circle(&self) -> Option<Circle<f64>> {
+        let polygon = Polygon::with_vertices(self.vertices.iter().map(to_f64_point).collect());
+        match polygon.centroid() {
+            Some(centroid) => Some(circle_around(centroid, &polygon.vertices)),
+            None => bounding_circle(&self.vertices),
+        }
+    }
+
+    /// Returns the minimum distance between the two polygons, which is zero if their boundaries
+    /// meet or one contains the other and infinite if either has no vertices. Every pair of edges
+    /// is compared, which takes quadratic time.
+    pub fn distance_to(&self, other: &Polygon<T>) -> f64 {
+        let (first, second) = match (self.vertices.first(), other.vertices.first()) {
+            (Some(first), Some(second)) => (first.clone(), second.clone()),
+            _ => return f64::INFINITY,
+        };
+        if self.contains_point(second) || other.contains_point(first) {
+            return 0.0;
+        }
+
+        let mut nearest = f64::INFINITY;
+        for a in self.edges() {
+            for b in other.edges() {
+                if a.intersects(&b) {
+                    return 0.0;
+                }
+                let offsets = [(&a, &b.start), (&a, &b.end), (&b, &a.start), (&b, &a.end)];
+                for (edge, p) in offsets {
+                    let offset = to_f64_point(p) - edge.closest_point_to(p.clone());
+                    nearest = nearest.min(offset.norm_squared());
+                }
+            }
+        }
+        Float::sqrt(nearest)
+    }
+
+    /// Returns the second moments of area `(Ixx, Iyy, Ixy)` about the centroid, the integrals of
+    /// y², x² and xy over the enclosed area, or `None` if the polygon has zero area. They do not
+    /// depend on the winding.
+    pub fn second_moments(&self) -> Option<(f64, f64, f64)> {
+        let polygon = Polygon::with_vertices(self.vertices.iter().map(to_f64_point).collect());
+        let centroid = polygon.centroid()?;
+        let centered = Polygon::with_vertices(
+            polygon
+                .vertices
+                .iter()
+                .map(|&p| Point::origin() + (p - centroid))
+                .collect(),
+        );
+
+        let (xx, yy, xy) = centered
+            .edges()
+            .fold((0.0, 0.0, 0.0), |(xx, yy, xy), edge| {
+                let (a, b) = (edge.start, edge.end);
+                let cross = a.x * b.y - b.x * a.y;
+                (
+                    xx + (a.y * a.y + a.y * b.y + b.y * b.y) * cross,
+                    yy + (a.x * a.x + a.x * b.x + b.x * b.x) * cross,
+                    xy + (a.x * b.y + 2.0 * a.x * a.y + 2.0 * b.x * b.y + b.x * a.y) * cross,
+                )
+            });
+        let sign = Float::signum(centered.twice_signed_area());
+        Some((sign * xx / 12.0, sign * yy / 12.0, sign * xy / 24.0))
+    }
+
+    /// Sums the signed exterior angles at the vertices in whole turns: `1` for a simple
+    /// counterclockwise polygon, `-1` for a clockwise one and `0` for a figure eight. Repeated
+    /// vertices are skipped, and a polygon without any edge of positive length gives `0`.
+    pub fn turning_number(&self) -> i32 {
+        let mut vertices: Vec<Point<f64>> = self.vertices.iter().map(to_f64_point).collect();
+        vertices.dedup();
+        while vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+
+        let n = vertices.len();
+        let total: f64 = (0..n)
+            .map(|i| {
+                let incoming = vertices[(i + 1) % n] - vertices[i];
+                let outgoing = vertices[(i + 2) % n] - vertices[(i + 1) % n];
+                Float::atan2(incoming.cross(outgoing), incoming.dot(outgoing))
+            })
+            .sum();
+        Float::round(total / f64::TAU()) as i32
+    }
+
+    /// Returns the area of the region enclosed by both the polygon and the circle, whatever the
+    /// winding. Each edge contributes the signed area its triangle with the circle's center has in
+    /// common with the circle, made up of triangles where the edge runs inside and circular
+    /// sectors where it runs outside.
+    pub fn intersection_area_with_circle(&self, circle: &Circle<T>) -> f64 {
+        let center = to_f64_point(&circle.center);
+        let radius = to_f64(&circle.radius);
+        let total: f64 = self
+            .edges()
+            .map(|edge| {
+                let a = to_f64_point(&edge.start) - center;
+                let b = to_f64_point(&edge.end) - center;
+                clipped_triangle_area(a, b, radius)
+            })
+            .sum();
+        total.abs()
+    }
+}
+
+/// Compares vertex by vertex, so the same polygon starting from another vertex differs.
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Polygon<T>
+where
+    T: Coord + AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.vertices.len() == other.vertices.len()
+            && self
+                .vertices
+                .iter()
+                .zip(&other.vertices)
+                .all(|(p, q)| p.abs_diff_eq(q, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> RelativeEq for Polygon<T>
+where
+    T: Coord + RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.vertices.len() == other.vertices.len()
+            && self
+                .vertices
+                .iter()
+                .zip(&other.vertices)
+                .all(|(p, q)| p.relative_eq(q, epsilon, max_relative))
+    }
+}
+
+/// The error converting a polygon into a type with a fixed number of vertices.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VertexCountError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl fmt::Display for VertexCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {} vertices but found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VertexCountError {}
+
+/// Takes the vertices of a triangle, in order. The crate has no triangle type: triangles are
+/// vertex triples, as `triangle_fan_area` takes them.
+impl<T> TryFrom<Polygon<T>> for [Point<T>; 3]
+where
+    T: Coord,
+{
+    type Error = VertexCountError;
+
+    fn try_from(polygon: Polygon<T>) -> Result<Self, Self::Error> {
+        let found = polygon.vertices.len();
+        match <[Point<T>; 3]>::try_from(polygon.vertices) {
+            Ok(vertices) => Ok(vertices),
+            Err(_) => Err(VertexCountError { expected: 3, found }),
+        }
+    }
+}
+
+/// Takes the segment from the first vertex of a two-vertex polygon to the second.
+impl<T> TryFrom<&Polygon<T>> for Segment<T>
+where
+    T: Coord,
+{
+    type Error = VertexCountError;
+
+    fn try_from(polygon: &Polygon<T>) -> Result<Self, Self::Error> {
+        match polygon.vertices.as_slice() {
+            [start, end] => Ok(Segment::with_endpoints(start.clone(), end.clone())),
+            vertices => Err(VertexCountError {
+                expected: 2,
+                found: vertices.len(),
+            }),
+        }
+    }
+}
+
+/// A convex polygon with its vertices in counterclockwise order. Collinear vertices are allowed,
+/// as in `Polygon::is_convex`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ConvexPolygon<T>
+where
+    T: Coord,
+{
+    pub(crate) polygon: Polygon<T>,
+}
+
+impl<T> ConvexPolygon<T>
+where
+    T: Coord,
+{
+    /// Returns `None` unless the vertices form a convex polygon; clockwise vertices are reversed.
+    pub fn from_vertices(vertices: Vec<Point<T>>) -> Option<Self> {
+        let mut polygon = Polygon::with_vertices(vertices);
+        if !polygon.is_convex() {
+            return None;
+        }
+        polygon.normalize_winding();
+        Some(Self { polygon })
+    }
+
+    /// Wraps the vertices without checking them, for callers that already know them to be convex
+    /// and counterclockwise, such as the output of `convex_hull`. Other input is not caught in
+    /// release builds and makes the queries on the result meaningless, though never unsafe.
+    pub fn from_vertices_unchecked(vertices: Vec<Point<T>>) -> Self {
+        let polygon = Polygon::with_vertices(vertices);
+        debug_assert!(polygon.is_convex() && polygon.is_counterclockwise());
+        Self { polygon }
+    }
+
+    pub fn vertices(&self) -> &[Point<T>] {
+        &self.polygon.vertices
+    }
+
+    pub fn as_polygon(&self) -> &Polygon<T> {
+        &self.polygon
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> ConvexPolygon<T>
+where
+    T: Coord + ToPrimitive,
+{
+    /// Returns the largest distance from `p` to the line of an edge, measured outwards: negative
+    /// inside, where its magnitude is how far `p` must move to leave, and positive outside, where
+    /// it may fall short of the true distance near a corner.
+    pub fn signed_distance(&self, p: Point<T>) -> f64 {
+        self.deepest_edge(p).0
+    }
+
+    /// Returns the unit outward normal of the edge that `signed_distance` measures against, along
+    /// which a point inside is pushed out most directly.
+    pub fn penetration_normal(&self, p: Point<T>) -> Vector<f64> {
+        self.deepest_edge(p).1
+    }
+
+    fn deepest_edge(&self, p: Point<T>) -> (f64, Vector<f64>) {
+        let p = to_f64_point(&p);
+        let mut deepest = (f64::NEG_INFINITY, Vector::zero());
+        for edge in self.polygon.edges() {
+            let (a, b) = (to_f64_point(&edge.start), to_f64_point(&edge.end));
+            let length = (b - a).magnitude();
+            if length == 0.0 {
+                continue;
+            }
+            let normal = Vector::with_coords(b.y - a.y, a.x - b.x) / length;
+            let distance = (p - a).dot(normal);
+            if distance > deepest.0 {
+                deepest = (distance, normal);
+            }
+        }
+        deepest
+    }
+}
+
+impl<T> From<ConvexPolygon<T>> for Polygon<T>
+where
+    T: Coord,
+{
+    fn from(convex: ConvexPolygon<T>) -> Self {
+        convex.polygon
+    }
+}
+
+/// A polygon whose exterior ring may enclose polygonal holes. Holes are expected to lie inside the
+/// exterior and not to overlap each other; either winding is accepted for every ring.
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PolygonWithHoles<T>
+where
+    T: Coord,
+{
+    pub exterior: Polygon<T>,
+    pub holes: Vec<Polygon<T>>,
+}
+
+impl<T> PolygonWithHoles<T>
+where
+    T: Coord,
+{
+    pub fn with_exterior_and_holes(exterior: Polygon<T>, holes: Vec<Polygon<T>>) -> Self {
+        Self { exterior, holes }
+    }
+
+    /// Tests containment with boundary points, including those on hole boundaries, contained.
+    pub fn contains_point(&self, p: Point<T>) -> bool {
+        self.exterior.contains_point(p.clone())
+            && self
+                .holes
+                .iter()
+                .all(|hole| !hole.contains_point(p.clone()) || hole.is_point_on_boundary(p.clone()))
+    }
+}
+
+impl<T> From<Polygon<T>> for PolygonWithHoles<T>
+where
+    T: Coord,
+{
+    fn from(exterior: Polygon<T>) -> Self {
+        Self::with_exterior_and_holes(exterior, Vec::new())
+    }
+}
+
+/// Generates up to 64 vertices, which need not form a simple polygon.
+#[cfg(feature = "arbitrary")]
+impl<'a, T> Arbitrary<'a> for Polygon<T>
+where
+    T: Coord + Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let n = u.int_in_range(0..=MAX_ARBITRARY_VERTICES)?;
+        let vertices = (0..n)
+            .map(|_| Point::arbitrary(u))
+            .collect::<arbitrary::Result<Vec<_>>>()?;
+        Ok(Self::with_vertices(vertices))
+    }
+}
+
+/// Keeps only the exterior ring and drops the holes; convert into `PolygonWithHoles` to keep
+/// them. The closing vertex repeating the first one is dropped too.
+#[cfg(feature = "geo-interop")]
+impl<T> From<geo::Polygon<T>> for Polygon<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    fn from(polygon: geo::Polygon<T>) -> Self {
+        ring_from_geo(polygon.exterior())
+    }
+}
+
+/// Produces a polygon without holes, whose exterior ring `geo` closes by repeating the first
+/// vertex.
+#[cfg(feature = "geo-interop")]
+impl<T> From<Polygon<T>> for geo::Polygon<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    fn from(polygon: Polygon<T>) -> Self {
+        geo::Polygon::new(ring_to_geo(polygon), Vec::new())
+    }
+}
+
+#[cfg(feature = "geo-interop")]
+impl<T> From<geo::Polygon<T>> for PolygonWithHoles<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    fn from(polygon: geo::Polygon<T>) -> Self {
+        Self::with_exterior_and_holes(
+            ring_from_geo(polygon.exterior()),
+            polygon.interiors().iter().map(ring_from_geo).collect(),
+        )
+    }
+}
+
+#[cfg(feature = "geo-interop")]
+impl<T> From<PolygonWithHoles<T>> for geo::Polygon<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    fn from(shape: PolygonWithHoles<T>) -> Self {
+        geo::Polygon::new(
+            ring_to_geo(shape.exterior),
+            shape.holes.into_iter().map(ring_to_geo).collect(),
+        )
+    }
+}
+
+#[cfg(feature = "geo-interop")]
+fn ring_from_geo<T>(ring: &geo::LineString<T>) -> Polygon<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    let mut vertices: Vec<Point<T>> = ring
+        .0
+        .iter()
+        .map(|c| Point::with_coords(c.x, c.y))
+        .collect();
+    if vertices.len() > 1 && vertices[0] == vertices[vertices.len() - 1] {
+        vertices.pop();
+    }
+    Polygon::with_vertices(vertices)
+}
+
+#[cfg(feature = "geo-interop")]
+fn ring_to_geo<T>(ring: Polygon<T>) -> geo::LineString<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    ring.vertices
+        .into_iter()
+        .map(|p| geo::coord! { x: p.x, y: p.y })
+        .collect()
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> PolygonWithHoles<T>
+where
+    T: FloatCoord,
+{
+    pub fn area(&self) -> T {
+        self.holes
+            .iter()
+            .fold(self.exterior.area(), |area, hole| area - hole.area())
+    }
+}
+
+// Returns the signed area shared by the triangle spanned by `a` and `b` from the origin and the
+// circle of `radius` around the origin.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn clipped_triangle_area(a: Vector<f64>, b: Vector<f64>, radius: f64) -> f64 {
+    let direction = b - a;
+    let squared_radius = radius * radius;
+    let (qa, qb, qc) = (
+        direction.norm_squared(),
+        2.0 * a.dot(direction),
+        a.norm_squared() - squared_radius,
+    );
+    let mut cuts = [0.0, 1.0, 1.0, 1.0];
+    let discriminant = qb * qb - 4.0 * qa * qc;
+    if qa > 0.0 && discriminant > 0.0 {
+        let root = Float::sqrt(discriminant);
+        for (cut, t) in cuts[1..3]
+            .iter_mut()
+            .zip([(-qb - root) / (2.0 * qa), (-qb + root) / (2.0 * qa)].iter())
+        {
+            *cut = t.clamp(0.0, 1.0);
+        }
+    }
+
+    cuts.windows(2)
+        .map(|pair| {
+            let (p, q) = (a + direction * pair[0], a + direction * pair[1]);
+            let middle = a + direction * ((pair[0] + pair[1]) / 2.0);
+            if middle.norm_squared() <= squared_radius {
+                p.cross(q) / 2.0
+            } else {
+                squared_radius * Float::atan2(p.cross(q), p.dot(q)) / 2.0
+            }
+        })
+        .sum()
+}
+
+// Returns +1 if the edge crosses the horizontal ray from `p` towards +x going upwards, -1 if it
+// crosses going downwards and 0 otherwise. Half-open bounds count a vertex on the ray once.
+fn crossing<T>(edge: &Segment<T>, p: &Point<T>) -> i32
+where
+    T: Coord,
+{
+    let (a, b) = (&edge.start, &edge.end);
+    if a.y <= p.y && p.y < b.y && orientation_by_ref(a, b, p) == Orientation::Counterclockwise {
+        1
+    } else if b.y <= p.y && p.y < a.y && orientation_by_ref(a, b, p) == Orientation::Clockwise {
+        -1
+    } else {
+        0
+    }
+}
+
+// Counts sign changes around the cyclic sequence, skipping zeros.
+fn sign_changes<T>(values: impl Iterator<Item = T>) -> usize
+where
+    T: Coord,
+{
+    let mut signs = values
+        .filter(|value| !value.is_zero())
+        .map(|value| value > T::zero());
+    let first = match signs.next() {
+        Some(sign) => sign,
+        None => return 0,
+    };
+
+    let (changes, last) = signs.fold((0, first), |(changes, previous), sign| {
+        (changes + (sign != previous) as usize, sign)
+    });
+    changes + (last != first) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(coords: &[(i32, i32)]) -> Polygon<i32> {
+        Polygon::with_vertices(
+            coords
+                .iter()
+                .map(|&(x, y)| Point::with_coords(x, y))
+                .collect(),
+        )
+    }
+
+    fn l_shape() -> Polygon<i32> {
+        polygon(&[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)])
+    }
+
+    fn pentagram() -> Polygon<i32> {
+        polygon(&[(0, 10), (6, -8), (-9, 3), (9, 3), (-6, -8)])
+    }
+
+    #[test]
+    fn creates_polygon_with_specified_vertices() {
+        let vertices = vec![
+            Point::with_coords(0, 0),
+            Point::with_coords(1, 0),
+            Point::with_coords(0, 1),
+        ];
+        let polygon = Polygon::with_vertices(vertices.clone());
+        assert_eq!(polygon.vertices, vertices);
+    }
+
+    #[test]
+    fn creates_empty_default_polygon() {
+        let polygon: Polygon<i32> = Polygon::default();
+        assert!(polygon.vertices.is_empty());
+    }
+
+    #[test]
+    fn iterates_edges_including_closing_edge() {
+        let edges: Vec<_> = polygon(&[(0, 0), (1, 0), (0, 1)]).edges().collect();
+        assert_eq!(edges.len(), 3);
+        assert_eq!(
+            edges[2],
+            Segment::with_endpoints(Point::with_coords(0, 1), Point::with_coords(0, 0))
+        );
+    }
+
+    #[test]
+    fn computes_twice_signed_area() {
+        assert_eq!(l_shape().twice_signed_area(), 24);
+        assert_eq!(polygon(&[(0, 0), (0, 1), (1, 0)]).twice_signed_area(), -1);
+        assert_eq!(polygon(&[(0, 0), (3, 3)]).twice_signed_area(), 0);
+    }
+
+    #[test]
+    fn detects_winding_order() {
+        let mut square = polygon(&[(0, 0), (0, 2), (2, 2), (2, 0)]);
+        assert_eq!(square.winding(), Orientation::Clockwise);
+        square.vertices.reverse();
+        assert_eq!(square.winding(), Orientation::Counterclockwise);
+        assert_eq!(Polygon::<i32>::default().winding(), Orientation::Collinear);
+    }
+
+    #[test]
+    fn tells_clockwise_from_counterclockwise_triangles() {
+        let clockwise = polygon(&[(0, 0), (0, 3), (2, 0)]);
+        assert!(clockwise.is_clockwise());
+        assert!(!clockwise.is_counterclockwise());
+
+        let counterclockwise = polygon(&[(0, 0), (2, 0), (0, 3)]);
+        assert!(counterclockwise.is_counterclockwise());
+        assert!(!counterclockwise.is_clockwise());
+
+        let collinear = polygon(&[(0, 0), (1, 1), (3, 3)]);
+        assert!(!collinear.is_clockwise());
+        assert!(!collinear.is_counterclockwise());
+    }
+
+    #[test]
+    fn normalizes_winding_to_counterclockwise() {
+        let mut square = polygon(&[(0, 0), (0, 2), (2, 2), (2, 0)]);
+        square.normalize_winding();
+        assert_eq!(square, polygon(&[(2, 0), (2, 2), (0, 2), (0, 0)]));
+
+        let mut l = l_shape();
+        l.normalize_winding();
+        assert_eq!(l, l_shape());
+    }
+
+    #[test]
+    fn detects_convex_polygons() {
+        assert!(polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]).is_convex());
+        assert!(polygon(&[(0, 0), (0, 2), (2, 2), (2, 0)]).is_convex());
+        assert!(polygon(&[(0, 0), (1, 0), (2, 0), (1, 1)]).is_convex());
+    }
+
+    #[test]
+    fn rejects_non_convex_polygons() {
+        assert!(!l_shape().is_convex());
+        assert!(!pentagram().is_convex());
+        assert!(!polygon(&[(0, 0), (1, 1), (2, 2)]).is_convex());
+        assert!(!Polygon::<i32>::default().is_convex());
+    }
+
+    #[test]
+    fn contains_points_inside_concave_polygon() {
+        let l = l_shape();
+        for &method in &[
+            ContainmentMethod::RayCasting,
+            ContainmentMethod::WindingNumber,
+        ] {
+            assert!(l.contains_point_with(Point::with_coords(1, 1), method));
+            assert!(l.contains_point_with(Point::with_coords(1, 3), method));
+            assert!(!l.contains_point_with(Point::with_coords(3, 3), method));
+            assert!(!l.contains_point_with(Point::with_coords(5, 1), method));
+            assert!(!l.contains_point_with(Point::with_coords(-1, 2), method));
+        }
+    }
+
+    #[test]
+    fn computes_canonical_key_regardless_of_start_and_winding() {
+        let square = polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]);
+        let rotated = polygon(&[(2, 2), (0, 2), (0, 0), (2, 0)]);
+        let reversed = polygon(&[(2, 0), (0, 0), (0, 2), (2, 2)]);
+        assert_eq!(square.canonical_key(), rotated.canonical_key());
+        assert_eq!(square.canonical_key(), reversed.canonical_key());
+        assert_eq!(
+            square.canonical_key(),
+            polygon(&[(0, 0), (0, 2), (2, 2), (2, 0)]).vertices
+        );
+        assert_ne!(
+            square.canonical_key(),
+            polygon(&[(0, 0), (2, 0), (0, 2), (2, 2)]).canonical_key()
+        );
+        assert!(Polygon::<i32>::default().canonical_key().is_empty());
+    }
+
+    #[test]
+    fn translates_polygon() {
+        let mut square = polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]);
+        square.translate(Vector::with_coords(3, -1));
+        assert_eq!(square, polygon(&[(3, -1), (5, -1), (5, 1), (3, 1)]));
+    }
+
+    #[test]
+    fn validates_polygons() {
+        assert_eq!(l_shape().validate(), Ok(()));
+        assert_eq!(polygon(&[(0, 0), (4, 0), (0, 4)]).validate(), Ok(()));
+        assert_eq!(
+            polygon(&[(0, 0), (4, 0)]).validate(),
+            Err(PolygonValidationError::TooFewVertices)
+        );
+        assert_eq!(
+            polygon(&[(0, 0), (4, 0), (4, 0), (0, 4)]).validate(),
+            Err(PolygonValidationError::DuplicateConsecutiveVertices)
+        );
+        assert_eq!(
+            polygon(&[(0, 0), (4, 0), (0, 4), (0, 0)]).validate(),
+            Err(PolygonValidationError::DuplicateConsecutiveVertices)
+        );
+        assert_eq!(
+            polygon(&[(0, 0), (2, 2), (4, 4)]).validate(),
+            Err(PolygonValidationError::ZeroArea)
+        );
+        assert_eq!(
+            pentagram().validate(),
+            Err(PolygonValidationError::SelfIntersecting)
+        );
+        assert_eq!(
+            polygon(&[(0, 0), (4, 0), (4, 4), (6, 4), (6, 2), (0, 2)]).validate(),
+            Err(PolygonValidationError::SelfIntersecting)
+        );
+        assert_eq!(
+            polygon(&[(0, 0), (4, 0), (6, 0), (4, 0), (4, 4)]).validate(),
+            Err(PolygonValidationError::SelfIntersecting)
+        );
+    }
+
+    #[test]
+    fn removes_collinear_vertices() {
+        let mut square = polygon(&[
+            (2, 0),
+            (4, 0),
+            (4, 2),
+            (4, 4),
+            (4, 4),
+            (2, 4),
+            (0, 4),
+            (0, 2),
+            (0, 0),
+            (1, 0),
+        ]);
+        square.simplify_collinear();
+        assert_eq!(square, polygon(&[(4, 0), (4, 4), (0, 4), (0, 0)]));
+
+        let mut spiked = polygon(&[(0, 0), (4, 0), (6, 0), (4, 0), (4, 4)]);
+        spiked.simplify_collinear();
+        assert_eq!(spiked, polygon(&[(0, 0), (4, 0), (4, 4)]));
+
+        let mut flat = polygon(&[(0, 0), (1, 1), (2, 2), (3, 3)]);
+        flat.simplify_collinear();
+        assert_eq!(flat, polygon(&[(0, 0), (3, 3)]));
+    }
+
+    #[test]
+    fn contains_polygon_only_when_nested() {
+        let outer = polygon(&[(0, 0), (10, 0), (10, 10), (0, 10)]);
+        assert!(outer.contains_polygon(&polygon(&[(2, 2), (4, 2), (4, 4), (2, 4)])));
+        assert!(!outer.contains_polygon(&polygon(&[(8, 8), (12, 8), (12, 12), (8, 12)])));
+        assert!(!outer.contains_polygon(&polygon(&[(20, 0), (30, 0), (30, 10), (20, 10)])));
+        assert!(!outer.contains_polygon(&polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)])));
+
+        // Every vertex of the triangle lies inside the L, but an edge cuts across the notch.
+        let l = polygon(&[(0, 0), (8, 0), (8, 4), (4, 4), (4, 8), (0, 8)]);
+        let triangle = polygon(&[(1, 1), (7, 3), (3, 7)]);
+        assert!(triangle.vertices.iter().all(|&p| l.contains_point(p)));
+        assert!(!l.contains_polygon(&triangle));
+    }
+
+    #[test]
+    fn contains_points_on_boundary() {
+        let l = l_shape();
+        for &method in &[
+            ContainmentMethod::RayCasting,
+            ContainmentMethod::WindingNumber,
+        ] {
+            assert!(l.contains_point_with(Point::with_coords(0, 0), method));
+            assert!(l.contains_point_with(Point::with_coords(3, 2), method));
+            assert!(l.contains_point_with(Point::with_coords(2, 3), method));
+        }
+    }
+
+    #[test]
+    fn detects_points_on_boundary() {
+        let shape = l_shape();
+        assert!(shape.is_point_on_boundary(Point::with_coords(3, 2)));
+        assert!(shape.is_point_on_boundary(Point::with_coords(2, 2)));
+        assert!(shape.is_point_on_boundary(Point::with_coords(0, 4)));
+        assert!(!shape.is_point_on_boundary(Point::with_coords(1, 1)));
+        assert!(!shape.is_point_on_boundary(Point::with_coords(3, 3)));
+    }
+
+    #[test]
+    fn counts_ray_crossings_through_vertices_once() {
+        let diamond = polygon(&[(0, -2), (2, 0), (0, 2), (-2, 0)]);
+        assert!(
+            diamond.contains_point_with(Point::with_coords(0, 0), ContainmentMethod::RayCasting)
+        );
+        assert!(
+            !diamond.contains_point_with(Point::with_coords(-3, 0), ContainmentMethod::RayCasting)
+        );
+        assert!(
+            !diamond.contains_point_with(Point::with_coords(-1, 2), ContainmentMethod::RayCasting)
+        );
+    }
+
+    #[test]
+    fn computes_winding_number_around_point() {
+        let mut l = l_shape();
+        assert_eq!(l.winding_number(Point::with_coords(1, 1)), 1);
+        assert_eq!(l.winding_number(Point::with_coords(3, 3)), 0);
+        l.vertices.reverse();
+        assert_eq!(l.winding_number(Point::with_coords(1, 1)), -1);
+    }
+
+    #[test]
+    fn distinguishes_containment_rules_inside_self_intersecting_polygon() {
+        let star = pentagram();
+        let center = Point::with_coords(0, 0);
+        assert_eq!(star.winding_number(center).abs(), 2);
+        assert!(star.contains_point(center));
+        assert!(!star.contains_point_with(center, ContainmentMethod::RayCasting));
+    }
+
+    #[test]
+    fn snaps_point_to_nearest_boundary_edge() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(
+            square.closest_boundary_point(Point::with_coords(6, 1)),
+            Some((Point::with_coords(4.0, 1.0), 1))
+        );
+        assert_eq!(
+            square.closest_boundary_point(Point::with_coords(1, 3)),
+            Some((Point::with_coords(1.0, 4.0), 2))
+        );
+        assert_eq!(
+            square.closest_boundary_point(Point::with_coords(-2, -1)),
+            Some((Point::with_coords(0.0, 0.0), 0))
+        );
+        assert_eq!(
+            Polygon::<i32>::default().closest_boundary_point(Point::origin()),
+            None
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn builds_arbitrary_polygon_from_raw_bytes() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        for start in 0..64 {
+            let mut u = Unstructured::new(&bytes[start * 16..]);
+            let polygon = Polygon::<i32>::arbitrary(&mut u).unwrap();
+            assert!(polygon.vertices.len() <= MAX_ARBITRARY_VERTICES);
+        }
+
+        let bytes = [2, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0];
+        let polygon = Polygon::<i32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(
+            polygon.vertices,
+            vec![Point::with_coords(1, 2), Point::with_coords(3, 4)]
+        );
+    }
+
+    #[cfg(feature = "geo-interop")]
+    #[test]
+    fn round_trips_polygon_through_geo() {
+        let exterior = geo::LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        let hole = geo::LineString::from(vec![(1.0, 1.0), (1.0, 2.0), (2.0, 2.0)]);
+        let shape = geo::Polygon::new(exterior, vec![hole]);
+
+        let polygon = Polygon::from(shape.clone());
+        assert_eq!(
+            polygon.vertices,
+            [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]
+                .iter()
+                .map(|&(x, y)| Point::with_coords(x, y))
+                .collect::<Vec<_>>()
+        );
+        let back = geo::Polygon::from(polygon);
+        assert_eq!(back.exterior(), shape.exterior());
+        assert!(back.interiors().is_empty());
+
+        let with_holes = PolygonWithHoles::from(shape.clone());
+        assert_eq!(with_holes.holes.len(), 1);
+        assert_eq!(with_holes.holes[0].vertices.len(), 3);
+        assert_eq!(geo::Polygon::from(with_holes), shape);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    mod float {
+        use super::*;
+
+        fn float_polygon(coords: &[(f64, f64)]) -> Polygon<f64> {
+            Polygon::with_vertices(
+                coords
+                    .iter()
+                    .map(|&(x, y)| Point::with_coords(x, y))
+                    .collect(),
+            )
+        }
+
+        #[test]
+        fn computes_signed_and_unsigned_area() {
+            let triangle = float_polygon(&[(0.0, 0.0), (0.0, 3.0), (4.0, 0.0)]);
+            assert_eq!(triangle.signed_area(), -6.0);
+            assert_eq!(triangle.area(), 6.0);
+        }
+
+        #[test]
+        fn computes_perimeter() {
+            let triangle = float_polygon(&[(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]);
+            assert_eq!(triangle.perimeter(), 12.0);
+            assert_eq!(Polygon::<f64>::default().perimeter(), 0.0);
+        }
+
+        #[test]
+        fn scales_and_rotates_about_center() {
+            let mut square = float_polygon(&[(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]);
+            square.rotate_about(Point::with_coords(2.0, 2.0), core::f64::consts::FRAC_PI_2);
+            let expected = [(3.0, 1.0), (3.0, 3.0), (1.0, 3.0), (1.0, 1.0)];
+            for (p, &(x, y)) in square.vertices.iter().zip(&expected) {
+                assert!((*p - Point::with_coords(x, y)).magnitude() < 1e-12);
+            }
+
+            square.scale_about(Point::with_coords(1.0, 1.0), 2.0);
+            assert_eq!(square.area(), 16.0);
+            assert_eq!(square.vertices[3], Point::with_coords(1.0, 1.0));
+        }
+
+        #[test]
+        fn computes_centroid_of_concave_polygon() {
+            let l = float_polygon(&[
+                (0.0, 0.0),
+                (4.0, 0.0),
+                (4.0, 2.0),
+                (2.0, 2.0),
+                (2.0, 4.0),
+                (0.0, 4.0),
+            ]);
+            let expected = 5.0 / 3.0;
+            let centroid = l.centroid().unwrap();
+            assert!((centroid.x - expected).abs() < 1e-12);
+            assert!((centroid.y - expected).abs() < 1e-12);
+
+            let mut reversed = l.clone();
+            reversed.vertices.reverse();
+            assert_eq!(reversed.centroid(), l.centroid());
+        }
+
+        #[test]
+        fn finds_no_centroid_of_degenerate_polygon() {
+            let segment = float_polygon(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]);
+            assert_eq!(segment.centroid(), None);
+        }
+
+        #[test]
+        fn subtracts_hole_areas() {
+            let shape = PolygonWithHoles::with_exterior_and_holes(
+                float_polygon(&[(0.0, 0.0), (6.0, 0.0), (6.0, 6.0), (0.0, 6.0)]),
+                vec![
+                    float_polygon(&[(1.0, 1.0), (1.0, 3.0), (3.0, 3.0), (3.0, 1.0)]),
+                    float_polygon(&[(4.0, 4.0), (5.0, 4.0), (5.0, 5.0)]),
+                ],
+            );
+            assert_eq!(shape.area(), 31.5);
+        }
+
+        #[test]
+        fn bounds_vertices_with_circle_around_centroid() {
+            let l = l_shape();
+            let circle = l.bounding_circle().unwrap();
+            assert_eq!(
+                Some(circle.center),
+                Polygon::with_vertices(l.vertices.iter().map(|p| p.cast().unwrap()).collect())
+                    .centroid()
+            );
+            assert!(l
+                .vertices
+                .iter()
+                .all(|p| circle.contains_point(p.cast().unwrap())));
+
+            let segment = polygon(&[(0, 0), (2, 2), (4, 4)]);
+            assert_eq!(
+                segment.bounding_circle(),
+                Some(Circle::with_center_and_radius(
+                    Point::with_coords(2.0, 2.0),
+                    8f64.sqrt()
+                ))
+            );
+            assert_eq!(Polygon::<i32>::default().bounding_circle(), None);
+        }
+
+        #[test]
+        fn measures_gap_between_polygons() {
+            let square = polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]);
+            let beside = polygon(&[(5, 1), (7, 1), (7, 3), (5, 3)]);
+            assert_eq!(square.distance_to(&beside), 3.0);
+            assert_eq!(beside.distance_to(&square), 3.0);
+
+            let diagonal = polygon(&[(5, 6), (7, 6), (7, 8), (5, 8)]);
+            assert_eq!(square.distance_to(&diagonal), 5.0);
+
+            let triangle = polygon(&[(4, 0), (6, 4), (4, 4)]);
+            assert!((square.distance_to(&triangle) - 2.0).abs() < 1e-12);
+        }
+
+        #[test]
+        fn measures_no_gap_between_overlapping_polygons() {
+            let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+            let overlapping = polygon(&[(2, 2), (6, 2), (6, 6), (2, 6)]);
+            let touching = polygon(&[(4, 1), (6, 1), (6, 3)]);
+            let inside = polygon(&[(1, 1), (2, 1), (2, 2)]);
+            assert_eq!(square.distance_to(&overlapping), 0.0);
+            assert_eq!(square.distance_to(&touching), 0.0);
+            assert_eq!(square.distance_to(&inside), 0.0);
+            assert_eq!(inside.distance_to(&square), 0.0);
+            assert_eq!(square.distance_to(&Polygon::default()), f64::INFINITY);
+        }
+
+        #[test]
+        fn computes_second_moments_of_rectangle() {
+            let (b, h) = (6.0, 2.0);
+            let mut rectangle = polygon(&[(1, 1), (7, 1), (7, 3), (1, 3)]);
+            let (xx, yy, xy) = rectangle.second_moments().unwrap();
+            assert!((xx - b * h * h * h / 12.0).abs() < 1e-9);
+            assert!((yy - h * b * b * b / 12.0).abs() < 1e-9);
+            assert!(xy.abs() < 1e-9);
+
+            rectangle.vertices.reverse();
+            let (xx, yy, _) = rectangle.second_moments().unwrap();
+            assert!((xx - 4.0).abs() < 1e-9);
+            assert!((yy - 36.0).abs() < 1e-9);
+        }
+
+        #[test]
+        fn computes_product_moment_of_asymmetric_polygon() {
+            // A right triangle with legs b and h has Ixy = -b²h²/72 about its centroid.
+            let triangle = polygon(&[(0, 0), (6, 0), (0, 3)]);
+            let (xx, yy, xy) = triangle.second_moments().unwrap();
+            assert!((xx - 6.0 * 27.0 / 36.0).abs() < 1e-9);
+            assert!((yy - 3.0 * 216.0 / 36.0).abs() < 1e-9);
+            assert!((xy + 36.0 * 9.0 / 72.0).abs() < 1e-9);
+            assert_eq!(polygon(&[(0, 0), (1, 1), (2, 2)]).second_moments(), None);
+        }
+
+        #[test]
+        fn counts_turns_around_polygon() {
+            let mut l = l_shape();
+            assert_eq!(l.turning_number(), 1);
+            l.vertices.reverse();
+            assert_eq!(l.turning_number(), -1);
+
+            let figure_eight = polygon(&[(0, 0), (2, 2), (2, 0), (0, 2)]);
+            assert_eq!(figure_eight.turning_number(), 0);
+            assert_eq!(pentagram().turning_number(), -2);
+
+            let repeated = polygon(&[(0, 0), (0, 0), (4, 0), (4, 4), (4, 4), (0, 0)]);
+            assert_eq!(repeated.turning_number(), 1);
+            assert_eq!(polygon(&[(1, 1), (1, 1)]).turning_number(), 0);
+        }
+
+        #[test]
+        fn computes_intersection_area_with_circle() {
+            let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+            let around = Circle::with_center_and_radius(Point::with_coords(2, 2), 3);
+            assert!((square.intersection_area_with_circle(&around) - 16.0).abs() < 1e-9);
+
+            let inside = Circle::with_center_and_radius(Point::with_coords(2, 2), 1);
+            let expected = core::f64::consts::PI;
+            assert!((square.intersection_area_with_circle(&inside) - expected).abs() < 1e-9);
+
+            let corner = Circle::with_center_and_radius(Point::with_coords(4, 4), 2);
+            let expected = core::f64::consts::PI;
+            assert!((square.intersection_area_with_circle(&corner) - expected).abs() < 1e-9);
+
+            let mut clockwise = square.clone();
+            clockwise.vertices.reverse();
+            assert!((clockwise.intersection_area_with_circle(&corner) - expected).abs() < 1e-9);
+
+            let apart = Circle::with_center_and_radius(Point::with_coords(10, 10), 2);
+            assert!(square.intersection_area_with_circle(&apart).abs() < 1e-9);
+        }
+
+        #[cfg(feature = "approx")]
+        #[test]
+        fn compares_rotated_polygons_approximately() {
+            let square = float_polygon(&[(1.0, 0.0), (0.0, 1.0), (-1.0, 0.0), (0.0, -1.0)]);
+            let rotated = Polygon::with_vertices(
+                square
+                    .vertices
+                    .iter()
+                    .map(|&p| Point::origin() + (p - Point::origin()).rotate(core::f64::consts::PI))
+                    .collect(),
+            );
+            let expected = float_polygon(&[(-1.0, 0.0), (0.0, -1.0), (1.0, 0.0), (0.0, 1.0)]);
+            approx::assert_abs_diff_eq!(rotated, expected, epsilon = 1e-12);
+            approx::assert_relative_ne!(rotated, square);
+            approx::assert_relative_ne!(
+                rotated,
+                Polygon::with_vertices(expected.vertices[..3].to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn converts_polygon_with_matching_vertex_count() {
+        let triangle = polygon(&[(0, 0), (3, 0), (0, 4)]);
+        let vertices = <[Point<i32>; 3]>::try_from(triangle.clone()).unwrap();
+        assert_eq!(vertices[..], triangle.vertices[..]);
+        assert_eq!(
+            <[Point<i32>; 3]>::try_from(l_shape()),
+            Err(VertexCountError {
+                expected: 3,
+                found: 6
+            })
+        );
+
+        let pair = polygon(&[(1, 2), (5, 6)]);
+        assert_eq!(
+            Segment::try_from(&pair),
+            Ok(Segment::with_endpoints(
+                Point::with_coords(1, 2),
+                Point::with_coords(5, 6)
+            ))
+        );
+        assert_eq!(
+            Segment::try_from(&triangle).unwrap_err().to_string(),
+            "expected 2 vertices but found 3"
+        );
+    }
+
+    #[test]
+    fn accepts_only_convex_vertices() {
+        let square = polygon(&[(0, 0), (0, 2), (2, 2), (2, 0)]);
+        let convex = ConvexPolygon::from_vertices(square.vertices.clone()).unwrap();
+        assert_eq!(convex.as_polygon().winding(), Orientation::Counterclockwise);
+        assert_eq!(
+            convex.vertices(),
+            polygon(&[(2, 0), (2, 2), (0, 2), (0, 0)]).vertices
+        );
+        assert_eq!(Polygon::from(convex).twice_signed_area(), 8);
+        assert_eq!(ConvexPolygon::from_vertices(l_shape().vertices), None);
+        assert_eq!(
+            ConvexPolygon::from_vertices(polygon(&[(0, 0), (1, 1), (2, 2)]).vertices),
+            None
+        );
+    }
+
+    #[test]
+    fn wraps_hull_without_checking() {
+        let points = crate::test_utils::random_points(7, 40, 100);
+        let hull = crate::hull::convex_hull(&points);
+        let checked = ConvexPolygon::from_vertices(hull.vertices.clone()).unwrap();
+        let unchecked = ConvexPolygon::from_vertices_unchecked(hull.vertices);
+        assert_eq!(unchecked, checked);
+        for p in points {
+            assert!(unchecked.as_polygon().contains_point(p));
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn measures_penetration_into_convex_polygon() {
+        let square =
+            ConvexPolygon::from_vertices(polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]).vertices)
+                .unwrap();
+        let near_right = Point::with_coords(3, 2);
+        assert_eq!(square.signed_distance(near_right), -1.0);
+        assert_eq!(
+            square.penetration_normal(near_right),
+            Vector::with_coords(1.0, 0.0)
+        );
+
+        let below = Point::with_coords(1, -3);
+        assert_eq!(square.signed_distance(below), 3.0);
+        assert_eq!(
+            square.penetration_normal(below),
+            Vector::with_coords(0.0, -1.0)
+        );
+        assert_eq!(square.signed_distance(Point::with_coords(0, 2)), 0.0);
+    }
+
+    #[test]
+    fn contains_point_outside_holes() {
+        let shape = PolygonWithHoles::with_exterior_and_holes(
+            polygon(&[(0, 0), (6, 0), (6, 6), (0, 6)]),
+            vec![polygon(&[(2, 2), (2, 4), (4, 4), (4, 2)])],
+        );
+        assert!(shape.contains_point(Point::with_coords(1, 1)));
+        assert!(shape.contains_point(Point::with_coords(6, 3)));
+        assert!(shape.contains_point(Point::with_coords(2, 3)));
+        assert!(!shape.contains_point(Point::with_coords(3, 3)));
+        assert!(!shape.contains_point(Point::with_coords(7, 3)));
+        assert!(PolygonWithHoles::from(polygon(&[(0, 0), (2, 0), (0, 2)]))
+            .contains_point(Point::with_coords(1, 0)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_polygon_through_serde() {
+        let p = polygon(&[(0, 0), (3, 0), (0, 4)]);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(
+            json,
+            r#"{"vertices":[{"x":0,"y":0},{"x":3,"y":0},{"x":0,"y":4}]}"#
+        );
+        assert_eq!(serde_json::from_str::<Polygon<i32>>(&json).unwrap(), p);
+    }
+}
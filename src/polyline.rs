@@ -0,0 +1,609 @@
+use crate::meta::Coord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::meta::FloatCoord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::polygon::Polygon;
+use crate::primitives::{to_f64_point, Point, Segment};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::vectors::Vector;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::ToPrimitive;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::{Float, FloatConst};
+
+// Miters reaching further from their vertex than this many half-widths are cut off with a bevel,
+// the default limit of SVG.
+#[cfg(any(feature = "std", feature = "libm"))]
+const MITER_LIMIT: f64 = 4.0;
+
+// Round joins and caps are approximated by chords covering at most this angle.
+#[cfg(any(feature = "std", feature = "libm"))]
+const ROUND_STEP: f64 = core::f64::consts::PI / 16.0;
+
+/// How `Polyline::stroke` fills the outside of a corner.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum JoinStyle {
+    /// Extends both sides to where they meet, falling back to `Bevel` for sharp corners.
+    Miter,
+    Round,
+    /// Joins the ends of both sides with a straight edge.
+    Bevel,
+}
+
+/// How `Polyline::stroke` finishes both ends of the path.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CapStyle {
+    /// Ends flush with the end vertex.
+    Butt,
+    Round,
+    /// Extends past the end vertex by half the width.
+    Square,
+}
+
+/// An open path through its vertices. Unlike `Polygon`, the last vertex is not joined back to the
+/// first.
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyline<T>
+where
+    T: Coord,
+{
+    pub vertices: Vec<Point<T>>,
+}
+
+impl<T> Polyline<T>
+where
+    T: Coord,
+{
+    pub fn with_vertices(vertices: Vec<Point<T>>) -> Self {
+        Self { vertices }
+    }
+
+    /// Yields the edges in vertex order; there are none for fewer than two vertices.
+    pub fn edges(&self) -> impl Iterator<Item = Segment<T>> + '_ {
+        self.vertices
+            .windows(2)
+            .map(|pair| Segment::with_endpoints(pair[0].clone(), pair[1].clone()))
+    }
+}
+
+#[cfg(feature = "geo-interop")]
+impl<T> From<geo::LineString<T>> for Polyline<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    fn from(line: geo::LineString<T>) -> Self {
+        Self::with_vertices(
+            line.0
+                .into_iter()
+                .map(|c| Point::with_coords(c.x, c.y))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "geo-interop")]
+impl<T> From<Polyline<T>> for geo::LineString<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    fn from(polyline: Polyline<T>) -> Self {
+        polyline
+            .vertices
+            .into_iter()
+            .map(|p| geo::coord! { x: p.x, y: p.y })
+            .collect()
+    }
+}
+
+impl<T> Polyline<T>
+where
+    T: Coord + ToPrimitive,
+{
+    /// Simplifies the path with `simplify_douglas_peucker`.
+    pub fn simplify(&self, tolerance: f64) -> Self {
+        Self::with_vertices(simplify_douglas_peucker(&self.vertices, tolerance))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Polyline<T>
+where
+    T: FloatCoord,
+{
+    pub fn length(&self) -> T {
+        self.edges().fold(T::zero(), |sum, edge| {
+            sum + (edge.end - edge.start).magnitude()
+        })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Polyline<T>
+where
+    T: Coord + ToPrimitive,
+{
+    /// Walks `distance` along the path from the first vertex, or returns `None` if the distance
+    /// is negative, exceeds the length or the polyline has no vertices.
+    pub fn point_at_distance(&self, distance: f64) -> Option<Point<f64>> {
+        let mut vertices = self.vertices.iter().map(|p| p.clone().cast::<f64>());
+        let mut start = vertices.next()??;
+        if distance < 0.0 {
+            return None;
+        }
+
+        let mut remaining = distance;
+        for end in vertices {
+            let end = end?;
+            let length = (end - start).magnitude();
+            if remaining <= length {
+                let t = if length > 0.0 {
+                    remaining / length
+                } else {
+                    0.0
+                };
+                return Some(start + (end - start) * t);
+            }
+            remaining -= length;
+            start = end;
+        }
+        if remaining == 0.0 {
+            Some(start)
+        } else {
+            None
+        }
+    }
+
+    /// Places points every `spacing` units of length along the path, always including both ends;
+    /// the last interval may be shorter. A point that would fall within a billionth of `spacing`
+    /// before the end is dropped in favour of the end itself. Only the ends are kept for a spacing
+    /// that is not positive.
+    pub fn resample(&self, spacing: f64) -> Vec<Point<f64>> {
+        let vertices: Vec<Point<f64>> = self.vertices.iter().map(to_f64_point).collect();
+        let first = match vertices.first() {
+            Some(&first) => first,
+            None => return Vec::new(),
+        };
+
+        let mut samples = vec![first];
+        let (mut travelled, mut count) = (0.0, 1);
+        // Also false for a NaN spacing, which would never advance.
+        if spacing > 0.0 {
+            for pair in vertices.windows(2) {
+                let (start, end) = (pair[0], pair[1]);
+                let length = (end - start).magnitude();
+                loop {
+                    let next = spacing * count as f64;
+                    if travelled + length - next <= spacing * 1e-9 {
+                        break;
+                    }
+                    samples.push(start + (end - start) * ((next - travelled) / length));
+                    count += 1;
+                }
+                travelled += length;
+            }
+        }
+        if vertices.len() > 1 {
+            samples.push(vertices[vertices.len() - 1]);
+        }
+        samples
+    }
+
+    /// Returns the point of the path nearest to `p`, the index of the edge it lies on and its
+    /// distance from `p`, or `None` if the polyline has no vertices. Ties go to the earlier edge,
+    /// and a single vertex counts as edge `0`.
+    pub fn closest_point(&self, p: Point<T>) -> Option<(Point<f64>, usize, f64)> {
+        let target = to_f64_point(&p);
+        let mut best: Option<(f64, Point<f64>, usize)> = None;
+        for (i, edge) in self.edges().enumerate() {
+            let closest = edge.closest_point_to(p.clone());
+            let distance = (closest - target).norm_squared();
+            if best.is_none_or(|(nearest, _, _)| distance < nearest) {
+                best = Some((distance, closest, i));
+            }
+        }
+        if best.is_none() {
+            let only = to_f64_point(self.vertices.first()?);
+            best = Some(((only - target).norm_squared(), only, 0));
+        }
+        best.map(|(distance, closest, i)| (closest, i, Float::sqrt(distance)))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Polyline<T>
+where
+    T: Coord + ToPrimitive,
+{
+    /// Outlines a stroke of `width` centred on the path, counterclockwise for paths that do not
+    /// cross themselves, or returns an empty polygon if all vertices coincide. Inner corners meet
+    /// where both offset lines cross unless that is past the miter limit, so edges shorter than
+    /// the width can make the outline overlap itself; it then covers the stroke under the nonzero
+    /// rule. Repeated vertices are skipped.
+    pub fn stroke(&self, width: f64, join: JoinStyle, cap: CapStyle) -> Polygon<f64> {
+        let mut path: Vec<Point<f64>> = self.vertices.iter().map(to_f64_point).collect();
+        path.dedup();
+        if path.len() < 2 {
+            return Polygon::with_vertices(Vec::new());
+        }
+
+        // One side is walked along the path and the other back against it, which runs clockwise.
+        let half = width / 2.0;
+        let mut outline = offset_left(&path, half, join);
+        push_cap(&mut outline, &path, half, cap);
+        path.reverse();
+        outline.extend(offset_left(&path, half, join));
+        push_cap(&mut outline, &path, half, cap);
+        outline.reverse();
+        Polygon::with_vertices(outline)
+    }
+}
+
+// Offsets the path by `half` to its left, joining the outside of right turns in the given style.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn offset_left(path: &[Point<f64>], half: f64, join: JoinStyle) -> Vec<Point<f64>> {
+    let directions: Vec<Vector<f64>> = path
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).normalized())
+        .collect();
+    let left = |d: Vector<f64>| Vector::with_coords(-d.y, d.x);
+
+    let mut side = vec![path[0] + left(directions[0]) * half];
+    for (i, pair) in directions.windows(2).enumerate() {
+        let (v, d0, d1) = (path[i + 1], pair[0], pair[1]);
+        let (n0, n1) = (left(d0), left(d1));
+        let turn = d0.cross(d1);
+        if turn == 0.0 && d0.dot(d1) > 0.0 {
+            side.push(v + n0 * half);
+            continue;
+        }
+
+        let miter = (n0 + n1) * (half / (1.0 + n0.dot(n1)));
+        let within_limit = miter.norm_squared() <= (MITER_LIMIT * half).powi(2);
+        // Left turns put this side on the inside, where the offset lines always meet.
+        if within_limit && (turn > 0.0 || join == JoinStyle::Miter) {
+            side.push(v + miter);
+        } else {
+            side.push(v + n0 * half);
+            if turn <= 0.0 && join == JoinStyle::Round {
+                let mut sweep = Float::atan2(n0.cross(n1), n0.dot(n1));
+                if sweep > 0.0 {
+                    sweep -= f64::TAU();
+                }
+                push_arc(&mut side, v, n0 * half, sweep);
+            }
+            side.push(v + n1 * half);
+        }
+    }
+    side.push(path[path.len() - 1] + left(directions[directions.len() - 1]) * half);
+    side
+}
+
+// Closes the outline around the last vertex of the path, turning from its left side to its right.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn push_cap(outline: &mut Vec<Point<f64>>, path: &[Point<f64>], half: f64, cap: CapStyle) {
+    let end = path[path.len() - 1];
+    let d = (end - path[path.len() - 2]).normalized() * half;
+    let n = Vector::with_coords(-d.y, d.x);
+    match cap {
+        CapStyle::Butt => {}
+        CapStyle::Round => push_arc(outline, end, n, -f64::PI()),
+        CapStyle::Square => {
+            outline.push(end + n + d);
+            outline.push(end - n + d);
+        }
+    }
+}
+
+// Pushes the points strictly between the ends of the arc around `center` that starts at
+// `center + radius` and turns by `sweep`, negative for clockwise.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn push_arc(points: &mut Vec<Point<f64>>, center: Point<f64>, radius: Vector<f64>, sweep: f64) {
+    let steps = Float::ceil(Float::abs(sweep) / ROUND_STEP) as usize;
+    for k in 1..steps {
+        let (sin, cos) = Float::sin_cos(sweep * k as f64 / steps as f64);
+        let rotated = Vector::with_coords(
+            radius.x * cos - radius.y * sin,
+            radius.x * sin + radius.y * cos,
+        );
+        points.push(center + rotated);
+    }
+}
+
+/// Keeps the points that Douglas-Peucker finds farther than `epsilon` from the simplified path,
+/// always including the first and last; a large enough `epsilon` leaves only those two.
+pub fn simplify_douglas_peucker<T>(points: &[Point<T>], epsilon: f64) -> Vec<Point<T>>
+where
+    T: Coord + ToPrimitive,
+{
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    // Spans between kept vertices are split at their farthest point until every point lies
+    // within tolerance; a stack instead of recursion keeps long traces from overflowing.
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut spans = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = spans.pop() {
+        let chord = Segment::with_endpoints(points[first].clone(), points[last].clone());
+        let farthest = (first + 1..last)
+            .map(|i| (i, squared_offset(&chord, &points[i])))
+            .fold(
+                None,
+                |farthest: Option<(usize, f64)>, (i, offset)| match farthest {
+                    Some((_, max)) if max >= offset => farthest,
+                    _ => Some((i, offset)),
+                },
+            );
+        if let Some((i, offset)) = farthest {
+            if offset > epsilon * epsilon {
+                keep[i] = true;
+                spans.push((first, i));
+                spans.push((i, last));
+            }
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, kept)| *kept)
+        .map(|(p, _)| p.clone())
+        .collect()
+}
+
+fn squared_offset<T>(chord: &Segment<T>, p: &Point<T>) -> f64
+where
+    T: Coord + ToPrimitive,
+{
+    let closest = chord.closest_point_to(p.clone());
+    (to_f64_point(p) - closest).norm_squared()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polyline(coords: &[(i32, i32)]) -> Polyline<i32> {
+        Polyline::with_vertices(
+            coords
+                .iter()
+                .map(|&(x, y)| Point::with_coords(x, y))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn iterates_edges_without_closing_edge() {
+        let path = polyline(&[(0, 0), (2, 0), (2, 3)]);
+        let edges: Vec<_> = path.edges().collect();
+        assert_eq!(
+            edges,
+            [
+                Segment::with_endpoints(Point::with_coords(0, 0), Point::with_coords(2, 0)),
+                Segment::with_endpoints(Point::with_coords(2, 0), Point::with_coords(2, 3)),
+            ]
+        );
+        assert_eq!(polyline(&[(1, 1)]).edges().count(), 0);
+    }
+
+    #[test]
+    fn simplifies_nearly_collinear_points() {
+        let path = polyline(&[(0, 0), (2, 1), (4, 0), (6, -1), (8, 0), (8, 5)]);
+        assert_eq!(path.simplify(1.5), polyline(&[(0, 0), (8, 0), (8, 5)]));
+        assert_eq!(
+            path.simplify(0.5),
+            polyline(&[(0, 0), (2, 1), (6, -1), (8, 0), (8, 5)])
+        );
+    }
+
+    #[test]
+    fn collapses_nearly_straight_run_to_endpoints() {
+        let run = polyline(&[(0, 0), (3, 1), (5, -1), (9, 0), (12, 1), (15, 0)]);
+        assert_eq!(
+            simplify_douglas_peucker(&run.vertices, 1.5),
+            [Point::with_coords(0, 0), Point::with_coords(15, 0)]
+        );
+        assert_eq!(
+            simplify_douglas_peucker(&run.vertices, f64::MAX),
+            [Point::with_coords(0, 0), Point::with_coords(15, 0)]
+        );
+    }
+
+    #[test]
+    fn preserves_sharp_corner() {
+        let corner = polyline(&[(0, 0), (5, 0), (10, 1), (10, 5), (11, 10)]);
+        assert_eq!(
+            simplify_douglas_peucker(&corner.vertices, 2.0),
+            [
+                Point::with_coords(0, 0),
+                Point::with_coords(10, 1),
+                Point::with_coords(11, 10)
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_short_inputs_unchanged() {
+        assert_eq!(simplify_douglas_peucker::<i32>(&[], 1.0), []);
+        let pair = [Point::with_coords(0, 0), Point::with_coords(1, 1)];
+        assert_eq!(simplify_douglas_peucker(&pair, 100.0), pair);
+    }
+
+    #[cfg(feature = "geo-interop")]
+    #[test]
+    fn round_trips_polyline_through_geo() {
+        let line = geo::LineString::from(vec![(0.0, 0.0), (2.0, 1.0), (3.0, -1.0)]);
+        let path = Polyline::from(line.clone());
+        assert_eq!(path.vertices.len(), 3);
+        assert_eq!(path.vertices[1], Point::with_coords(2.0, 1.0));
+        assert_eq!(geo::LineString::from(path), line);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    mod float {
+        use super::*;
+
+        #[test]
+        fn computes_length_without_closing_edge() {
+            let path = Polyline::with_vertices(vec![
+                Point::with_coords(0.0, 0.0),
+                Point::with_coords(3.0, 4.0),
+                Point::with_coords(3.0, 6.0),
+            ]);
+            assert_eq!(path.length(), 7.0);
+            assert_eq!(Polyline::<f64>::default().length(), 0.0);
+        }
+
+        #[test]
+        fn strokes_straight_segment_into_rectangle() {
+            let segment = polyline(&[(0, 0), (10, 0)]);
+            let outline = segment.stroke(2.0, JoinStyle::Miter, CapStyle::Butt);
+            assert_eq!(
+                outline.vertices,
+                [
+                    Point::with_coords(0.0, -1.0),
+                    Point::with_coords(10.0, -1.0),
+                    Point::with_coords(10.0, 1.0),
+                    Point::with_coords(0.0, 1.0)
+                ]
+            );
+
+            let square = segment.stroke(2.0, JoinStyle::Miter, CapStyle::Square);
+            assert!((square.signed_area() - 24.0).abs() < 1e-9);
+            let round = segment.stroke(2.0, JoinStyle::Miter, CapStyle::Round);
+            assert!(round.signed_area() > 22.9 && round.signed_area() < 20.0 + f64::PI());
+            assert!(polyline(&[(1, 1), (1, 1)])
+                .stroke(2.0, JoinStyle::Round, CapStyle::Round)
+                .vertices
+                .is_empty());
+        }
+
+        #[test]
+        fn strokes_corner_with_each_join() {
+            let corner = polyline(&[(0, 0), (10, 0), (10, 10)]);
+            let area = |join| corner.stroke(2.0, join, CapStyle::Butt).signed_area();
+            assert!((area(JoinStyle::Miter) - 40.0).abs() < 1e-9);
+            assert!((area(JoinStyle::Bevel) - 39.5).abs() < 1e-9);
+            let round = area(JoinStyle::Round);
+            assert!(round > 39.7 && round < 40.0 - (1.0 - f64::FRAC_PI_4()));
+
+            let outline = corner.stroke(2.0, JoinStyle::Miter, CapStyle::Butt);
+            assert!(outline.vertices.contains(&Point::with_coords(11.0, -1.0)));
+            assert!(outline.vertices.contains(&Point::with_coords(9.0, 1.0)));
+        }
+
+        #[test]
+        fn bevels_sharp_miters() {
+            let hairpin = polyline(&[(0, 0), (10, 0), (0, 1)]);
+            let outline = hairpin.stroke(2.0, JoinStyle::Miter, CapStyle::Butt);
+            // The miter would reach about 20 units past the corner.
+            assert!(outline.vertices.iter().all(|p| p.x <= 11.0));
+        }
+
+        #[test]
+        fn resamples_path_at_uniform_spacing() {
+            let line = polyline(&[(0, 0), (10, 0)]);
+            let samples = line.resample(2.0);
+            assert_eq!(
+                samples,
+                [0.0, 2.0, 4.0, 6.0, 8.0, 10.0]
+                    .iter()
+                    .map(|&x| Point::with_coords(x, 0.0))
+                    .collect::<Vec<_>>()
+            );
+
+            let bent = polyline(&[(0, 0), (3, 0), (3, 4)]);
+            assert_eq!(
+                bent.resample(2.5),
+                [
+                    Point::with_coords(0.0, 0.0),
+                    Point::with_coords(2.5, 0.0),
+                    Point::with_coords(3.0, 2.0),
+                    Point::with_coords(3.0, 4.0)
+                ]
+            );
+
+            let short = Polyline::with_vertices(vec![
+                Point::with_coords(0.0, 0.0),
+                Point::with_coords(0.1, 0.0),
+                Point::with_coords(0.3, 0.0),
+            ]);
+            assert_eq!(short.resample(0.1).len(), 4);
+            assert_eq!(
+                line.resample(25.0),
+                [Point::with_coords(0.0, 0.0), Point::with_coords(10.0, 0.0)]
+            );
+            assert_eq!(
+                polyline(&[(1, 2)]).resample(1.0),
+                [Point::with_coords(1.0, 2.0)]
+            );
+            assert!(Polyline::<i32>::default().resample(1.0).is_empty());
+        }
+
+        #[test]
+        fn keeps_only_ends_for_spacing_that_is_not_positive() {
+            let bent = polyline(&[(0, 0), (3, 0), (3, 4)]);
+            let ends = [Point::with_coords(0.0, 0.0), Point::with_coords(3.0, 4.0)];
+            assert_eq!(bent.resample(0.0), ends);
+            assert_eq!(bent.resample(-1.0), ends);
+            assert_eq!(bent.resample(f64::NAN), ends);
+        }
+
+        #[test]
+        fn finds_closest_point_on_path() {
+            let path = polyline(&[(0, 0), (4, 0), (4, 4), (8, 4), (8, 0)]);
+            assert_eq!(
+                path.closest_point(Point::with_coords(3, 2)),
+                Some((Point::with_coords(4.0, 2.0), 1, 1.0))
+            );
+            assert_eq!(
+                path.closest_point(Point::with_coords(6, 7)),
+                Some((Point::with_coords(6.0, 4.0), 2, 3.0))
+            );
+            assert_eq!(
+                path.closest_point(Point::with_coords(10, -3)),
+                Some((Point::with_coords(8.0, 0.0), 3, 13f64.sqrt()))
+            );
+            assert_eq!(
+                polyline(&[(1, 2)]).closest_point(Point::with_coords(4, 6)),
+                Some((Point::with_coords(1.0, 2.0), 0, 5.0))
+            );
+            assert_eq!(
+                Polyline::<i32>::default().closest_point(Point::origin()),
+                None
+            );
+        }
+
+        #[test]
+        fn finds_point_at_distance_along_path() {
+            let path = polyline(&[(0, 0), (4, 0), (4, 3)]);
+            assert_eq!(
+                path.point_at_distance(0.0),
+                Some(Point::with_coords(0.0, 0.0))
+            );
+            assert_eq!(
+                path.point_at_distance(2.5),
+                Some(Point::with_coords(2.5, 0.0))
+            );
+            assert_eq!(
+                path.point_at_distance(5.5),
+                Some(Point::with_coords(4.0, 1.5))
+            );
+            assert_eq!(
+                path.point_at_distance(7.0),
+                Some(Point::with_coords(4.0, 3.0))
+            );
+            assert_eq!(path.point_at_distance(7.5), None);
+            assert_eq!(path.point_at_distance(-1.0), None);
+            assert_eq!(
+                polyline(&[(2, 2)]).point_at_distance(0.0),
+                Some(Point::with_coords(2.0, 2.0))
+            );
+            assert_eq!(Polyline::<i32>::default().point_at_distance(0.0), None);
+        }
+    }
+}
@@ -0,0 +1,1053 @@
+use crate::meta::Coord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::meta::FloatCoord;
+use crate::robust;
+use crate::vectors::{Orientation, Vector};
+use alloc::vec::Vec;
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use num_traits::{NumCast, ToPrimitive};
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point<T>
+where
+    T: Coord,
+{
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T>
+where
+    T: Coord,
+{
+    pub fn with_coords(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn origin() -> Self {
+        Self::with_coords(T::zero(), T::zero())
+    }
+
+    /// Computes the squared distance, which is exact for integer coordinates.
+    pub fn distance_squared_to(self, other: Point<T>) -> T {
+        (other - self).norm_squared()
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Coord + ToPrimitive,
+{
+    pub fn cast<U>(self) -> Option<Point<U>>
+    where
+        U: Coord + NumCast,
+    {
+        Some(Point::with_coords(U::from(self.x)?, U::from(self.y)?))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Point<T>
+where
+    T: Coord + ToPrimitive,
+{
+    pub fn distance_to(self, other: Point<T>) -> f64 {
+        (to_f64_point(&other) - to_f64_point(&self)).magnitude()
+    }
+
+    /// Returns the unit vector pointing towards `target`, or `None` if the points coincide.
+    pub fn direction_to(self, target: Point<T>) -> Option<Vector<f64>> {
+        if self == target {
+            return None;
+        }
+        Some((to_f64_point(&target) - to_f64_point(&self)).normalized())
+    }
+
+    /// Rounds each coordinate to the nearest multiple of `step`. A zero `step` leaves the point
+    /// unchanged.
+    pub fn snap_to_grid(self, step: f64) -> Point<f64> {
+        Point::origin() + (to_f64_point(&self) - Point::origin()).snap_to_grid(step)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Point<T>
+where
+    T: FloatCoord,
+{
+    pub fn floor(self) -> Self {
+        Self::with_coords(self.x.floor(), self.y.floor())
+    }
+
+    pub fn ceil(self) -> Self {
+        Self::with_coords(self.x.ceil(), self.y.ceil())
+    }
+
+    pub fn round(self) -> Self {
+        Self::with_coords(self.x.round(), self.y.round())
+    }
+}
+
+impl<T> fmt::Display for Point<T>
+where
+    T: Coord + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T> Add<Vector<T>> for Point<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn add(self, offset: Vector<T>) -> Self::Output {
+        Self::with_coords(self.x + offset.x, self.y + offset.y)
+    }
+}
+
+impl<T> Sub<Vector<T>> for Point<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn sub(self, offset: Vector<T>) -> Self::Output {
+        Self::with_coords(self.x - offset.x, self.y - offset.y)
+    }
+}
+
+impl<T> Sub for Point<T>
+where
+    T: Coord,
+{
+    type Output = Vector<T>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Vector::with_coords(self.x - other.x, self.y - other.y)
+    }
+}
+
+forward_ref_binop!(impl Add, add for Point<T>, Vector<T>);
+forward_ref_binop!(impl Sub, sub for Point<T>, Vector<T>);
+forward_ref_binop!(impl Sub, sub for Point<T>, Point<T>);
+
+impl<T> AddAssign<Vector<T>> for Point<T>
+where
+    T: Coord,
+{
+    fn add_assign(&mut self, offset: Vector<T>) {
+        self.x = self.x.clone() + offset.x;
+        self.y = self.y.clone() + offset.y;
+    }
+}
+
+impl<T> SubAssign<Vector<T>> for Point<T>
+where
+    T: Coord,
+{
+    fn sub_assign(&mut self, offset: Vector<T>) {
+        self.x = self.x.clone() - offset.x;
+        self.y = self.y.clone() - offset.y;
+    }
+}
+
+forward_ref_op_assign!(impl AddAssign, add_assign for Point<T>, Vector<T>);
+forward_ref_op_assign!(impl SubAssign, sub_assign for Point<T>, Vector<T>);
+
+impl<T> From<(T, T)> for Point<T>
+where
+    T: Coord,
+{
+    fn from((x, y): (T, T)) -> Self {
+        Self::with_coords(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T)
+where
+    T: Coord,
+{
+    fn from(p: Point<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+#[cfg(feature = "geo-interop")]
+impl<T> From<geo::Point<T>> for Point<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    fn from(p: geo::Point<T>) -> Self {
+        Self::with_coords(p.x(), p.y())
+    }
+}
+
+#[cfg(feature = "geo-interop")]
+impl<T> From<Point<T>> for geo::Point<T>
+where
+    T: Coord + geo::CoordNum,
+{
+    fn from(p: Point<T>) -> Self {
+        geo::Point::new(p.x, p.y)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T> Arbitrary<'a> for Point<T>
+where
+    T: Coord + Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::with_coords(T::arbitrary(u)?, T::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(T::size_hint(depth), T::size_hint(depth))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Point<T>
+where
+    T: Coord + AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> RelativeEq for Point<T>
+where
+    T: Coord + RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+    }
+}
+
+/// Sorts the points lexicographically and removes exact duplicates, keeping one of each.
+pub fn dedup_points<T>(points: &mut Vec<Point<T>>)
+where
+    T: Coord + Ord,
+{
+    points.sort_unstable();
+    points.dedup();
+}
+
+/// Sorts the points lexicographically and drops every point within `epsilon` of one kept before
+/// it, so a chain of close points may keep more than one representative.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn dedup_points_approx<T>(points: &mut Vec<Point<T>>, epsilon: T)
+where
+    T: FloatCoord,
+{
+    points.sort_by(|p, q| p.partial_cmp(q).expect("coordinates are comparable"));
+    let mut kept = 0;
+    for i in 0..points.len() {
+        let p = points[i];
+        // Kept points are sorted by x, so only the last few can lie within `epsilon`.
+        let duplicate = points[..kept]
+            .iter()
+            .rev()
+            .take_while(|q| p.x - q.x <= epsilon)
+            .any(|&q| (p - q).norm_squared() <= epsilon * epsilon);
+        if !duplicate {
+            points[kept] = p;
+            kept += 1;
+        }
+    }
+    points.truncate(kept);
+}
+
+/// Float coordinates go through the exact predicates in `robust`, so nearly collinear points
+/// are never misclassified. Primitive integer coordinates go through `robust::orient_robust`, so
+/// large ones never overflow.
+pub fn orientation<T>(p: Point<T>, q: Point<T>, r: Point<T>) -> Orientation
+where
+    T: Coord,
+{
+    orientation_by_ref(&p, &q, &r)
+}
+
+// Same as `orientation`, for callers that would otherwise have to clone their points.
+pub(crate) fn orientation_by_ref<T>(p: &Point<T>, q: &Point<T>, r: &Point<T>) -> Orientation
+where
+    T: Coord,
+{
+    if let Some(orientation) = robust::float_orientation(p, q, r) {
+        return orientation;
+    }
+    if let Some(orientation) = robust::integer_orientation(p, q, r) {
+        return orientation;
+    }
+
+    let cross = (q - p).cross(r - p);
+    if cross > T::zero() {
+        Orientation::Counterclockwise
+    } else if cross < T::zero() {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CirclePosition {
+    Inside,
+    OnCircle,
+    Outside,
+}
+
+/// Locates `d` relative to the circle through `a`, `b` and `c`, which must be counterclockwise.
+/// Float coordinates go through the exact predicates in `robust`.
+pub fn in_circle<T>(a: Point<T>, b: Point<T>, c: Point<T>, d: Point<T>) -> CirclePosition
+where
+    T: Coord,
+{
+    let sign = match robust::float_incircle(&a, &b, &c, &d) {
+        Some(det) => det.partial_cmp(&0.0),
+        None => {
+            let (a, b, c) = (a - &d, b - &d, c - d);
+            let det = a.clone().norm_squared() * b.clone().cross(c.clone())
+                + b.clone().norm_squared() * c.clone().cross(a.clone())
+                + c.norm_squared() * a.cross(b);
+            det.partial_cmp(&T::zero())
+        }
+    };
+    match sign {
+        Some(Ordering::Greater) => CirclePosition::Inside,
+        Some(Ordering::Less) => CirclePosition::Outside,
+        _ => CirclePosition::OnCircle,
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment<T>
+where
+    T: Coord,
+{
+    pub start: Point<T>,
+    pub end: Point<T>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SegmentIntersection<T>
+where
+    T: Coord,
+{
+    None,
+    Point(Point<f64>),
+    Overlap(Segment<T>),
+}
+
+impl<T> Segment<T>
+where
+    T: Coord,
+{
+    /// Same as `with_endpoints`.
+    pub fn new(start: Point<T>, end: Point<T>) -> Self {
+        Self::with_endpoints(start, end)
+    }
+
+    pub fn with_endpoints(start: Point<T>, end: Point<T>) -> Self {
+        Self { start, end }
+    }
+
+    pub fn contains_point(&self, p: Point<T>) -> bool {
+        orientation_by_ref(&self.start, &self.end, &p) == Orientation::Collinear && self.spans(&p)
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        let (a, b) = (&self.start, &self.end);
+        let (c, d) = (&other.start, &other.end);
+        let (abc, abd) = (orientation_by_ref(a, b, c), orientation_by_ref(a, b, d));
+        let (cda, cdb) = (orientation_by_ref(c, d, a), orientation_by_ref(c, d, b));
+
+        if abc != abd
+            && cda != cdb
+            && [abc, abd, cda, cdb]
+                .iter()
+                .all(|&o| o != Orientation::Collinear)
+        {
+            return true;
+        }
+        (abc == Orientation::Collinear && self.spans(c))
+            || (abd == Orientation::Collinear && self.spans(d))
+            || (cda == Orientation::Collinear && other.spans(a))
+            || (cdb == Orientation::Collinear && other.spans(b))
+    }
+
+    fn spans(&self, p: &Point<T>) -> bool {
+        let (min_x, max_x) = min_max(&self.start.x, &self.end.x);
+        let (min_y, max_y) = min_max(&self.start.y, &self.end.y);
+        min_x <= &p.x && &p.x <= max_x && min_y <= &p.y && &p.y <= max_y
+    }
+}
+
+impl<T> Segment<T>
+where
+    T: Coord + ToPrimitive,
+{
+    pub fn intersection_point(&self, other: &Self) -> SegmentIntersection<T> {
+        let (p, r) = (&self.start, &self.end - &self.start);
+        let (q, s) = (&other.start, &other.end - &other.start);
+        let denominator = r.clone().cross(s.clone());
+        if denominator.is_zero() {
+            return self.collinear_overlap(other);
+        }
+
+        let offset = q - p;
+        let t = offset.clone().cross(s);
+        let u = offset.cross(r.clone());
+        let zero = T::zero();
+        let within = |numerator: &T| {
+            if denominator > zero {
+                &zero <= numerator && numerator <= &denominator
+            } else {
+                &denominator <= numerator && numerator <= &zero
+            }
+        };
+        if !within(&t) || !within(&u) {
+            return SegmentIntersection::None;
+        }
+
+        let ratio = to_f64(&t) / to_f64(&denominator);
+        SegmentIntersection::Point(Point::with_coords(
+            to_f64(&p.x) + to_f64(&r.x) * ratio,
+            to_f64(&p.y) + to_f64(&r.y) * ratio,
+        ))
+    }
+
+    /// Returns the point of the segment nearest to `p`, computed in `f64`. Zero-length segments
+    /// return their only point.
+    pub fn closest_point_to(&self, p: Point<T>) -> Point<f64> {
+        let (a, b) = (to_f64_point(&self.start), to_f64_point(&self.end));
+        let (direction, offset) = (b - a, to_f64_point(&p) - a);
+        let length_squared = direction.norm_squared();
+        if length_squared == 0.0 {
+            return a;
+        }
+        let t = offset.dot(direction) / length_squared;
+        a + direction * t.clamp(0.0, 1.0)
+    }
+
+    /// Returns the line through the midpoint at right angles to the segment, directed so that the
+    /// start lies to its left, or `None` if the segment has zero length.
+    pub fn perpendicular_bisector(&self) -> Option<Line<f64>> {
+        let (a, b) = (to_f64_point(&self.start), to_f64_point(&self.end));
+        let direction = b - a;
+        if direction.norm_squared() == 0.0 {
+            return None;
+        }
+        Some(Line::with_point_and_direction(
+            a + direction * 0.5,
+            Vector::with_coords(-direction.y, direction.x),
+        ))
+    }
+
+    fn collinear_overlap(&self, other: &Self) -> SegmentIntersection<T> {
+        if orientation_by_ref(&self.start, &self.end, &other.start) != Orientation::Collinear
+            || orientation_by_ref(&other.start, &other.end, &self.start) != Orientation::Collinear
+        {
+            return SegmentIntersection::None;
+        }
+
+        let (self_min, self_max) = min_max(&self.start, &self.end);
+        let (other_min, other_max) = min_max(&other.start, &other.end);
+        let low = min_max(self_min, other_min).1;
+        let high = min_max(self_max, other_max).0;
+        if high < low {
+            SegmentIntersection::None
+        } else if low == high {
+            SegmentIntersection::Point(
+                low.clone()
+                    .cast()
+                    .expect("coordinates representable as f64"),
+            )
+        } else {
+            SegmentIntersection::Overlap(Segment::with_endpoints(low.clone(), high.clone()))
+        }
+    }
+}
+
+/// An infinite line through `point` along `direction`, which must not be zero. The direction
+/// orients the line, so that points to its left lie counterclockwise from it.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line<T>
+where
+    T: Coord,
+{
+    pub point: Point<T>,
+    pub direction: Vector<T>,
+}
+
+impl<T> Line<T>
+where
+    T: Coord,
+{
+    pub fn with_point_and_direction(point: Point<T>, direction: Vector<T>) -> Self {
+        Self { point, direction }
+    }
+
+    /// Returns the line from `a` towards `b`, which must differ.
+    pub fn through_points(a: Point<T>, b: Point<T>) -> Self {
+        let direction = &b - &a;
+        Self::with_point_and_direction(a, direction)
+    }
+
+    /// Tells on which side of the line `p` lies: `Counterclockwise` to its left, `Clockwise` to
+    /// its right and `Collinear` on it.
+    pub fn side_of(&self, p: &Point<T>) -> Orientation {
+        let ahead = &self.point + &self.direction;
+        orientation_by_ref(&self.point, &ahead, p)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Segment<T>
+where
+    T: Coord + AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.start.abs_diff_eq(&other.start, epsilon) && self.end.abs_diff_eq(&other.end, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> RelativeEq for Segment<T>
+where
+    T: Coord + RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.start.relative_eq(&other.start, epsilon, max_relative)
+            && self.end.relative_eq(&other.end, epsilon, max_relative)
+    }
+}
+
+fn min_max<T>(a: T, b: T) -> (T, T)
+where
+    T: PartialOrd,
+{
+    if b < a {
+        (b, a)
+    } else {
+        (a, b)
+    }
+}
+
+pub(crate) fn to_f64<T>(c: &T) -> f64
+where
+    T: ToPrimitive,
+{
+    c.to_f64().expect("coordinates representable as f64")
+}
+
+pub(crate) fn to_f64_point<T>(p: &Point<T>) -> Point<f64>
+where
+    T: Coord + ToPrimitive,
+{
+    Point::with_coords(to_f64(&p.x), to_f64(&p.y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x1: i32, y1: i32, x2: i32, y2: i32) -> Segment<i32> {
+        Segment::with_endpoints(Point::with_coords(x1, y1), Point::with_coords(x2, y2))
+    }
+
+    #[test]
+    fn creates_point_with_specified_coordinates() {
+        let p = Point::with_coords(4, 2);
+        assert_eq!((p.x, p.y), (4, 2));
+    }
+
+    #[test]
+    fn creates_string_representation_of_point() {
+        assert_eq!(Point::with_coords(-4, 2).to_string(), "(-4, 2)");
+    }
+
+    #[test]
+    fn orders_points_lexicographically() {
+        assert!(Point::with_coords(1, 5) < Point::with_coords(2, 0));
+        assert!(Point::with_coords(1, 0) < Point::with_coords(1, 5));
+    }
+
+    #[test]
+    fn offsets_point_by_vector() {
+        let p = Point::with_coords(4, 2);
+        assert_eq!(p + Vector::with_coords(1, -1), Point::with_coords(5, 1));
+        assert_eq!(p - Vector::with_coords(1, -1), Point::with_coords(3, 3));
+    }
+
+    #[test]
+    fn subtracts_points_into_vector() {
+        let p = Point::with_coords(4, 2);
+        let q = Point::with_coords(1, 3);
+        assert_eq!(p - q, Vector::with_coords(3, -1));
+    }
+
+    #[test]
+    fn creates_origin_equal_to_default() {
+        let origin: Point<i32> = Point::origin();
+        assert_eq!(origin, Point::default());
+        assert_eq!(origin, Point::with_coords(0, 0));
+    }
+
+    #[test]
+    fn measures_squared_distance_between_points() {
+        let p = Point::with_coords(1, 2);
+        assert_eq!(p.distance_squared_to(Point::with_coords(4, -2)), 25);
+        assert_eq!(p.distance_squared_to(p), 0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn finds_direction_and_distance_to_point() {
+        let p = Point::with_coords(1, 2);
+        let q = Point::with_coords(4, -2);
+        assert_eq!(p.distance_to(q), 5.0);
+        assert_eq!(p.direction_to(q), Some(Vector::with_coords(0.6, -0.8)));
+        assert_eq!(p.direction_to(p), None);
+        assert_eq!(p.distance_to(p), 0.0);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn floors_ceils_and_rounds_point_coordinates() {
+        let p = Point::with_coords(1.5, -2.25);
+        assert_eq!(p.floor(), Point::with_coords(1.0, -3.0));
+        assert_eq!(p.ceil(), Point::with_coords(2.0, -2.0));
+        assert_eq!(p.round(), Point::with_coords(2.0, -2.0));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn snaps_point_to_grid() {
+        let p = Point::with_coords(1.2, 3.7);
+        assert_eq!(p.snap_to_grid(0.5), Point::with_coords(1.0, 3.5));
+        assert_eq!(p.snap_to_grid(0.0), p);
+        assert_eq!(
+            Point::with_coords(7, -2).snap_to_grid(5.0),
+            Point::with_coords(5.0, -0.0)
+        );
+    }
+
+    #[cfg(feature = "geo-interop")]
+    #[test]
+    fn round_trips_point_through_geo() {
+        let p: geo::Point<f64> = Point::with_coords(1.5, -2.0).into();
+        assert_eq!((p.x(), p.y()), (1.5, -2.0));
+        assert_eq!(Point::from(p), Point::with_coords(1.5, -2.0));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn builds_arbitrary_point_from_raw_bytes() {
+        let bytes = 7_i32
+            .to_le_bytes()
+            .iter()
+            .chain(&(-0x0102_0304_i32).to_le_bytes())
+            .copied()
+            .collect::<Vec<_>>();
+        let p = Point::<i32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(p, Point::with_coords(7, -0x0102_0304));
+    }
+
+    #[test]
+    fn casts_point_to_another_coordinate_type() {
+        let p = Point::with_coords(4, -2);
+        assert_eq!(p.cast::<f64>(), Some(Point::with_coords(4.0, -2.0)));
+    }
+
+    #[test]
+    fn removes_duplicate_points() {
+        let mut points = vec![
+            Point::with_coords(3, 1),
+            Point::with_coords(0, 0),
+            Point::with_coords(3, 1),
+            Point::with_coords(-2, 5),
+            Point::with_coords(0, 0),
+            Point::with_coords(3, 1),
+        ];
+        dedup_points(&mut points);
+        assert_eq!(
+            points,
+            [
+                Point::with_coords(-2, 5),
+                Point::with_coords(0, 0),
+                Point::with_coords(3, 1)
+            ]
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn removes_points_within_epsilon() {
+        let mut points = vec![
+            Point::with_coords(1.0, 1.0),
+            Point::with_coords(5.0, 0.0),
+            Point::with_coords(1.0 + 1e-9, 1.0 - 1e-9),
+            Point::with_coords(0.0, 7.0),
+            Point::with_coords(5.0, 1e-7),
+            Point::with_coords(1.0, 1.0),
+        ];
+        dedup_points_approx(&mut points, 1e-6);
+        assert_eq!(
+            points,
+            [
+                Point::with_coords(0.0, 7.0),
+                Point::with_coords(1.0, 1.0),
+                Point::with_coords(5.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn computes_orientation_of_three_points() {
+        let (p, q) = (Point::with_coords(0, 0), Point::with_coords(4, 0));
+        assert_eq!(
+            orientation(p, q, Point::with_coords(1, 1)),
+            Orientation::Counterclockwise
+        );
+        assert_eq!(
+            orientation(p, q, Point::with_coords(1, -1)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            orientation(p, q, Point::with_coords(8, 0)),
+            Orientation::Collinear
+        );
+    }
+
+    #[test]
+    fn computes_orientation_of_integer_points_near_limits() {
+        let (p, q) = (
+            Point::with_coords(i32::MIN, i32::MIN),
+            Point::with_coords(i32::MAX, i32::MAX),
+        );
+        assert_eq!(
+            orientation(p, q, Point::with_coords(0, 1)),
+            Orientation::Counterclockwise
+        );
+        assert_eq!(
+            orientation(p, q, Point::with_coords(i32::MAX, i32::MAX - 1)),
+            Orientation::Clockwise
+        );
+
+        let (p, q) = (
+            Point::with_coords(i64::MAX, i64::MIN),
+            Point::with_coords(i64::MIN, i64::MAX),
+        );
+        assert_eq!(
+            orientation(p, q, Point::with_coords(0, 0)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            orientation(p, q, Point::with_coords(-1, -1)),
+            Orientation::Counterclockwise
+        );
+        assert_eq!(
+            orientation(p, q, Point::with_coords(-5, 4)),
+            Orientation::Collinear
+        );
+    }
+
+    #[test]
+    fn computes_orientation_of_collinear_float_points() {
+        let (p, q, r) = (
+            Point::with_coords(1.0, 0.0),
+            Point::with_coords(0.5, 0.5),
+            Point::with_coords(0.0, 1.0),
+        );
+        assert_eq!(orientation(p, q, r), Orientation::Collinear);
+        assert_eq!(orientation(r, q, p), Orientation::Collinear);
+    }
+
+    #[test]
+    fn computes_orientation_of_nearly_collinear_float_points() {
+        let (p, q) = (
+            Point::with_coords(12.0, 12.0),
+            Point::with_coords(24.0, 24.0),
+        );
+        let r = Point::with_coords(0.5, 0.5 + f64::EPSILON / 2.0);
+        assert_eq!(orientation(p, q, r), Orientation::Counterclockwise);
+        assert_eq!(orientation(q, p, r), Orientation::Clockwise);
+    }
+
+    #[test]
+    fn locates_point_relative_to_circle() {
+        let (a, b, c) = (
+            Point::with_coords(2, 0),
+            Point::with_coords(0, 2),
+            Point::with_coords(-2, 0),
+        );
+        assert_eq!(
+            in_circle(a, b, c, Point::with_coords(1, 1)),
+            CirclePosition::Inside
+        );
+        assert_eq!(
+            in_circle(a, b, c, Point::with_coords(0, -2)),
+            CirclePosition::OnCircle
+        );
+        assert_eq!(
+            in_circle(a, b, c, Point::with_coords(2, 2)),
+            CirclePosition::Outside
+        );
+    }
+
+    #[test]
+    fn locates_float_point_relative_to_circle() {
+        let (a, b, c) = (
+            Point::with_coords(1.0, 0.0),
+            Point::with_coords(0.0, 1.0),
+            Point::with_coords(-1.0, 0.0),
+        );
+        assert_eq!(
+            in_circle(a, b, c, Point::with_coords(0.0, -1.0)),
+            CirclePosition::OnCircle
+        );
+        assert_eq!(
+            in_circle(a, b, c, Point::with_coords(0.0, -1.0 + f64::EPSILON)),
+            CirclePosition::Inside
+        );
+    }
+
+    #[test]
+    fn finds_point_on_segment() {
+        let s = segment(0, 0, 4, 2);
+        assert!(s.contains_point(Point::with_coords(2, 1)));
+        assert!(s.contains_point(Point::with_coords(0, 0)));
+        assert!(s.contains_point(Point::with_coords(4, 2)));
+    }
+
+    #[test]
+    fn rejects_point_off_segment() {
+        let s = segment(0, 0, 4, 2);
+        assert!(!s.contains_point(Point::with_coords(2, 2)));
+        assert!(!s.contains_point(Point::with_coords(6, 3)));
+    }
+
+    #[test]
+    fn detects_crossing_segments() {
+        assert!(segment(0, 0, 4, 4).intersects(&segment(0, 4, 4, 0)));
+    }
+
+    #[test]
+    fn detects_segments_touching_at_endpoint() {
+        assert!(segment(0, 0, 4, 4).intersects(&segment(4, 4, 8, 0)));
+        assert!(segment(0, 0, 4, 4).intersects(&segment(2, 2, 8, 0)));
+    }
+
+    #[test]
+    fn detects_overlapping_collinear_segments() {
+        assert!(segment(0, 0, 4, 0).intersects(&segment(2, 0, 8, 0)));
+    }
+
+    #[test]
+    fn rejects_disjoint_segments() {
+        assert!(!segment(0, 0, 4, 0).intersects(&segment(0, 1, 4, 1)));
+        assert!(!segment(0, 0, 4, 0).intersects(&segment(5, 0, 8, 0)));
+        assert!(!segment(0, 0, 4, 4).intersects(&segment(3, 0, 8, -4)));
+    }
+
+    #[test]
+    fn computes_intersection_point_of_crossing_segments() {
+        let s = segment(0, 0, 4, 4);
+        assert_eq!(
+            s.intersection_point(&segment(0, 3, 3, 0)),
+            SegmentIntersection::Point(Point::with_coords(1.5, 1.5))
+        );
+    }
+
+    #[test]
+    fn computes_intersection_point_of_segments_touching_at_endpoint() {
+        let s = segment(0, 0, 4, 0);
+        assert_eq!(
+            s.intersection_point(&segment(4, 0, 8, 5)),
+            SegmentIntersection::Point(Point::with_coords(4.0, 0.0))
+        );
+        assert_eq!(
+            s.intersection_point(&segment(4, 0, 9, 0)),
+            SegmentIntersection::Point(Point::with_coords(4.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn computes_overlap_of_collinear_segments() {
+        let s = segment(0, 0, 6, 3);
+        assert_eq!(
+            s.intersection_point(&segment(8, 4, 2, 1)),
+            SegmentIntersection::Overlap(segment(2, 1, 6, 3))
+        );
+    }
+
+    #[test]
+    fn computes_intersection_point_with_zero_length_segment() {
+        let s = segment(0, 0, 4, 2);
+        assert_eq!(
+            s.intersection_point(&segment(2, 1, 2, 1)),
+            SegmentIntersection::Point(Point::with_coords(2.0, 1.0))
+        );
+        assert_eq!(
+            segment(2, 1, 2, 1).intersection_point(&s),
+            SegmentIntersection::Point(Point::with_coords(2.0, 1.0))
+        );
+        assert_eq!(
+            segment(3, 0, 3, 0).intersection_point(&s),
+            SegmentIntersection::None
+        );
+    }
+
+    #[test]
+    fn finds_no_intersection_point_of_disjoint_segments() {
+        let s = segment(0, 0, 4, 0);
+        assert_eq!(
+            s.intersection_point(&segment(0, 1, 4, 1)),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            s.intersection_point(&segment(5, 0, 8, 0)),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            s.intersection_point(&segment(2, 1, 3, 5)),
+            SegmentIntersection::None
+        );
+    }
+
+    #[test]
+    fn tells_side_of_line() {
+        let line = Line::through_points(Point::with_coords(0, 0), Point::with_coords(4, 2));
+        assert_eq!(line.direction, Vector::with_coords(4, 2));
+        assert_eq!(
+            line.side_of(&Point::with_coords(1, 3)),
+            Orientation::Counterclockwise
+        );
+        assert_eq!(
+            line.side_of(&Point::with_coords(3, 0)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            line.side_of(&Point::with_coords(-8, -4)),
+            Orientation::Collinear
+        );
+    }
+
+    #[test]
+    fn computes_perpendicular_bisector() {
+        let bisector = segment(1, 2, 5, 2).perpendicular_bisector().unwrap();
+        assert_eq!(bisector.point, Point::with_coords(3.0, 2.0));
+        assert_eq!(bisector.direction.x, 0.0);
+        assert_eq!(
+            bisector.side_of(&Point::with_coords(1.0, 2.0)),
+            Orientation::Counterclockwise
+        );
+        assert_eq!(
+            bisector.side_of(&Point::with_coords(3.0, -7.0)),
+            Orientation::Collinear
+        );
+        assert_eq!(segment(2, 3, 2, 3).perpendicular_bisector(), None);
+    }
+
+    #[test]
+    fn finds_closest_point_on_segment() {
+        let s = segment(0, 0, 4, 2);
+        assert_eq!(
+            s.closest_point_to(Point::with_coords(1, 3)),
+            Point::with_coords(2.0, 1.0)
+        );
+        assert_eq!(
+            s.closest_point_to(Point::with_coords(-3, 1)),
+            Point::with_coords(0.0, 0.0)
+        );
+        assert_eq!(
+            s.closest_point_to(Point::with_coords(7, 2)),
+            Point::with_coords(4.0, 2.0)
+        );
+        assert_eq!(
+            segment(1, 1, 1, 1).closest_point_to(Point::with_coords(5, 5)),
+            Point::with_coords(1.0, 1.0)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_point_and_segment_through_serde() {
+        let p = Point::with_coords(-2, 7);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, r#"{"x":-2,"y":7}"#);
+        assert_eq!(serde_json::from_str::<Point<i32>>(&json).unwrap(), p);
+
+        let s = segment(0, 1, 4, 5);
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(json, r#"{"start":{"x":0,"y":1},"end":{"x":4,"y":5}}"#);
+        assert_eq!(serde_json::from_str::<Segment<i32>>(&json).unwrap(), s);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn offsets_point_in_place() {
+        let mut p = Point::with_coords(1, 1);
+        p += Vector::with_coords(2, 3);
+        p -= &Vector::with_coords(1, 0);
+        assert_eq!(p, Point::with_coords(2, 4));
+        assert_eq!(&p - &Point::with_coords(2, 2), Vector::with_coords(0, 2));
+    }
+
+    #[test]
+    fn converts_point_to_and_from_tuple() {
+        let p: Point<i32> = (5, -6).into();
+        assert_eq!(p, Point::with_coords(5, -6));
+        assert_eq!(<(i32, i32)>::from(p), (5, -6));
+    }
+}
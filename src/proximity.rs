@@ -0,0 +1,499 @@
+use crate::meta::Coord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::meta::FloatCoord;
+use crate::polygon::Polygon;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::primitives::to_f64_point;
+use crate::primitives::Point;
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::ToPrimitive;
+
+/// Returns the indices `(i, j)`, `i < j`, of two points at minimum distance, or `None` for fewer
+/// than two points. Distances are compared exactly as squared values in `T`.
+pub fn closest_pair<T>(points: &[Point<T>]) -> Option<(usize, usize)>
+where
+    T: Coord + Copy,
+{
+    if points.len() < 2 {
+        return None;
+    }
+    let mut indices: Vec<usize> = (0..points.len()).collect();
+    indices.sort_by(|&i, &j| {
+        points[i]
+            .partial_cmp(&points[j])
+            .expect("coordinates are comparable")
+    });
+    let mut scratch = Vec::with_capacity(points.len());
+    let (_, i, j) = closest_in(points, &mut indices, &mut scratch);
+    Some((i.min(j), i.max(j)))
+}
+
+/// Returns the indices of two vertices at maximum distance, using rotating calipers. The polygon
+/// must be convex without repeated vertices, as produced by `convex_hull`; either winding works.
+pub fn diameter<T>(polygon: &Polygon<T>) -> Option<(usize, usize)>
+where
+    T: Coord + Copy,
+{
+    let vertices = &polygon.vertices;
+    match vertices.len() {
+        0 => return None,
+        1 => return Some((0, 0)),
+        _ => {}
+    }
+
+    let n = vertices.len();
+    let mut best = (T::zero(), 0, 0);
+    antipodal_pairs(vertices, |edge, j| {
+        for i in [edge, (edge + 1) % n] {
+            let distance = squared_distance(vertices[i], vertices[j]);
+            if distance > best.0 {
+                best = (distance, i.min(j), i.max(j));
+            }
+        }
+    });
+    Some((best.1, best.2))
+}
+
+/// Returns two points at maximum distance together with that distance, or `None` for fewer than
+/// two points. Every pair is compared exactly as squared values in `T`, which takes quadratic
+/// time; for large inputs, `diameter` of the convex hull finds the same distance faster.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn farthest_pair<T>(points: &[Point<T>]) -> Option<(Point<T>, Point<T>, f64)>
+where
+    T: Coord + Copy + ToPrimitive,
+{
+    let mut best: Option<(T, Point<T>, Point<T>)> = None;
+    for (k, &p) in points.iter().enumerate() {
+        for &q in &points[k + 1..] {
+            let distance = squared_distance(p, q);
+            if best.is_none_or(|(farthest, _, _)| distance > farthest) {
+                best = Some((distance, p, q));
+            }
+        }
+    }
+    best.map(|(_, p, q)| (p, q, (to_f64_point(&q) - to_f64_point(&p)).magnitude()))
+}
+
+/// Returns the largest distance between two of the points, the diameter of the point set, or
+/// `None` for fewer than two; see `farthest_pair`. The name `diameter` is taken by the rotating
+/// calipers search over a convex polygon, which returns vertex indices instead.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn farthest_distance<T>(points: &[Point<T>]) -> Option<f64>
+where
+    T: Coord + Copy + ToPrimitive,
+{
+    farthest_pair(points).map(|(_, _, distance)| distance)
+}
+
+/// Returns the index of the point closest to `query` together with its distance, or `None` for
+/// no points. Distances are compared exactly as squared values in `T`, and ties go to the lowest
+/// index. This checks every point; `KdTree` answers repeated queries faster.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn nearest<T>(query: Point<T>, points: &[Point<T>]) -> Option<(usize, f64)>
+where
+    T: Coord + Copy + ToPrimitive,
+{
+    let mut best: Option<(T, usize)> = None;
+    for (i, &p) in points.iter().enumerate() {
+        let distance = squared_distance(query, p);
+        if best.is_none_or(|(nearest, _)| distance < nearest) {
+            best = Some((distance, i));
+        }
+    }
+    best.map(|(_, i)| {
+        let distance = (to_f64_point(&points[i]) - to_f64_point(&query)).magnitude();
+        (i, distance)
+    })
+}
+
+/// Returns the indices, in increasing order, of the points within `radius` of `query`, including
+/// those exactly at that distance. Distances are compared exactly as squared values in `T`.
+pub fn within_radius<T>(query: Point<T>, points: &[Point<T>], radius: T) -> Vec<usize>
+where
+    T: Coord + Copy,
+{
+    let limit = radius * radius;
+    points
+        .iter()
+        .enumerate()
+        .filter(|&(_, &p)| squared_distance(query, p) <= limit)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Returns the minimum distance between two parallel lines enclosing the polygon, which is zero
+/// for fewer than three vertices. The polygon must satisfy the same conditions as for `diameter`.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn width<T>(polygon: &Polygon<T>) -> T
+where
+    T: FloatCoord,
+{
+    let vertices = &polygon.vertices;
+    let n = vertices.len();
+    if n < 3 {
+        return T::zero();
+    }
+
+    let mut width = T::infinity();
+    antipodal_pairs(vertices, |i, j| {
+        let (start, end) = (vertices[i], vertices[(i + 1) % n]);
+        let area = parallelogram_area(start, end, vertices[j]);
+        width = width.min(area / (end - start).magnitude());
+    });
+    width
+}
+
+/// Calls `visit(i, j)` for every edge `i`, from vertex `i` to the next one, with the vertex `j`
+/// farthest from its line. Pairing `j` with both ends of the edge covers every antipodal pair.
+fn antipodal_pairs<T>(vertices: &[Point<T>], mut visit: impl FnMut(usize, usize))
+where
+    T: Coord + Copy,
+{
+    let n = vertices.len();
+    let mut j = 1;
+    for i in 0..n {
+        let (start, end) = (vertices[i], vertices[(i + 1) % n]);
+        while parallelogram_area(start, end, vertices[(j + 1) % n])
+            > parallelogram_area(start, end, vertices[j])
+        {
+            j = (j + 1) % n;
+        }
+        visit(i, j);
+    }
+}
+
+fn parallelogram_area<T>(start: Point<T>, end: Point<T>, p: Point<T>) -> T
+where
+    T: Coord + Copy,
+{
+    (end - start).cross(p - start).abs()
+}
+
+fn squared_distance<T>(p: Point<T>, q: Point<T>) -> T
+where
+    T: Coord + Copy,
+{
+    (q - p).dot(q - p)
+}
+
+/// Finds the closest pair among `indices`, which must be sorted by x, and leaves them sorted by
+/// y so that the caller can merge halves in linear time.
+fn closest_in<T>(
+    points: &[Point<T>],
+    indices: &mut [usize],
+    scratch: &mut Vec<usize>,
+) -> (T, usize, usize)
+where
+    T: Coord + Copy,
+{
+    let by_y = |&i: &usize, &j: &usize| {
+        points[i]
+            .y
+            .partial_cmp(&points[j].y)
+            .expect("coordinates are comparable")
+    };
+
+    if indices.len() <= 3 {
+        let (i, j) = (indices[0], indices[1]);
+        let mut best = (squared_distance(points[i], points[j]), i, j);
+        for (k, &i) in indices.iter().enumerate() {
+            for &j in &indices[k + 1..] {
+                let distance = squared_distance(points[i], points[j]);
+                if distance < best.0 {
+                    best = (distance, i, j);
+                }
+            }
+        }
+        indices.sort_by(by_y);
+        return best;
+    }
+
+    let middle = indices.len() / 2;
+    let split = points[indices[middle]].x;
+    let (left, right) = indices.split_at_mut(middle);
+    let from_left = closest_in(points, left, scratch);
+    let from_right = closest_in(points, right, scratch);
+    let mut best = if from_right.0 < from_left.0 {
+        from_right
+    } else {
+        from_left
+    };
+
+    scratch.clear();
+    let (mut l, mut r) = (0, middle);
+    while l < middle && r < indices.len() {
+        if by_y(&indices[r], &indices[l]).is_lt() {
+            scratch.push(indices[r]);
+            r += 1;
+        } else {
+            scratch.push(indices[l]);
+            l += 1;
+        }
+    }
+    scratch.extend_from_slice(&indices[l..middle]);
+    scratch.extend_from_slice(&indices[r..]);
+    indices.copy_from_slice(scratch);
+
+    scratch.clear();
+    for &i in indices.iter() {
+        let dx = points[i].x - split;
+        if dx * dx >= best.0 {
+            continue;
+        }
+        for &j in scratch.iter().rev() {
+            let dy = points[i].y - points[j].y;
+            if dy * dy >= best.0 {
+                break;
+            }
+            let distance = squared_distance(points[i], points[j]);
+            if distance < best.0 {
+                best = (distance, i, j);
+            }
+        }
+        scratch.push(i);
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hull::convex_hull;
+    use crate::test_utils::random_points;
+
+    fn brute_force_extreme(points: &[Point<i64>], farthest: bool) -> i64 {
+        let mut distances = Vec::new();
+        for (k, &p) in points.iter().enumerate() {
+            for &q in &points[k + 1..] {
+                distances.push(squared_distance(p, q));
+            }
+        }
+        if farthest {
+            distances.into_iter().max().unwrap_or(0)
+        } else {
+            distances.into_iter().min().unwrap_or(0)
+        }
+    }
+
+    #[test]
+    fn finds_closest_pair() {
+        let points = [
+            Point::with_coords(0, 0),
+            Point::with_coords(10, 10),
+            Point::with_coords(4, 7),
+            Point::with_coords(11, 8),
+            Point::with_coords(-5, 3),
+        ];
+        assert_eq!(closest_pair(&points), Some((1, 3)));
+        assert_eq!(closest_pair(&points[..1]), None);
+        assert_eq!(closest_pair::<i64>(&[]), None);
+    }
+
+    #[test]
+    fn finds_repeated_point_as_closest_pair() {
+        let points = [
+            Point::with_coords(3, 3),
+            Point::with_coords(0, 0),
+            Point::with_coords(1, 1),
+            Point::with_coords(3, 3),
+        ];
+        assert_eq!(closest_pair(&points), Some((0, 3)));
+    }
+
+    #[test]
+    fn matches_brute_force_closest_pair() {
+        for seed in 1..=50 {
+            let points = random_points(seed * 7919, 2 + seed as usize * 7, 1000);
+            let (i, j) = closest_pair(&points).unwrap();
+            assert!(i < j);
+            assert_eq!(
+                squared_distance(points[i], points[j]),
+                brute_force_extreme(&points, false),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn finds_closest_pair_on_vertical_line() {
+        let points: Vec<_> = [0, 9, 3, 20, 7, 14, 1]
+            .iter()
+            .map(|&y| Point::with_coords(5, y))
+            .collect();
+        assert_eq!(closest_pair(&points), Some((0, 6)));
+    }
+
+    #[test]
+    fn finds_points_within_radius() {
+        let points = [
+            Point::with_coords(0, 0),
+            Point::with_coords(3, 4),
+            Point::with_coords(-4, 3),
+            Point::with_coords(5, 1),
+            Point::with_coords(1, -1),
+        ];
+        let query = Point::with_coords(0, 0);
+        assert_eq!(within_radius(query, &points, 5), vec![0, 1, 2, 4]);
+        assert_eq!(within_radius(query, &points, 4), vec![0, 4]);
+        assert_eq!(within_radius(query, &points, 0), vec![0]);
+        assert!(within_radius(Point::with_coords(20, 20), &points, 5).is_empty());
+        assert!(within_radius::<i64>(query, &[], 5).is_empty());
+    }
+
+    #[test]
+    fn finds_diameter_of_convex_polygon() {
+        let square = Polygon::with_vertices(vec![
+            Point::with_coords(0, 0),
+            Point::with_coords(4, 0),
+            Point::with_coords(4, 4),
+            Point::with_coords(0, 4),
+        ]);
+        let (i, j) = diameter(&square).unwrap();
+        assert_eq!(squared_distance(square.vertices[i], square.vertices[j]), 32);
+
+        let segment =
+            Polygon::with_vertices(vec![Point::with_coords(1, 1), Point::with_coords(4, 5)]);
+        assert_eq!(diameter(&segment), Some((0, 1)));
+        let point = Polygon::with_vertices(vec![Point::with_coords(1, 1)]);
+        assert_eq!(diameter(&point), Some((0, 0)));
+        assert_eq!(diameter(&Polygon::<i64>::with_vertices(Vec::new())), None);
+    }
+
+    #[test]
+    fn matches_brute_force_diameter_of_hulls() {
+        for seed in 1..=50 {
+            let points = random_points(seed * 104_729, 3 + seed as usize * 5, 30);
+            let mut hull = convex_hull(&points);
+            let expected = brute_force_extreme(&hull.vertices, true);
+            let (i, j) = diameter(&hull).unwrap();
+            assert_eq!(
+                squared_distance(hull.vertices[i], hull.vertices[j]),
+                expected,
+                "seed {}",
+                seed
+            );
+
+            hull.vertices.reverse();
+            let (i, j) = diameter(&hull).unwrap();
+            assert_eq!(
+                squared_distance(hull.vertices[i], hull.vertices[j]),
+                expected
+            );
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    mod float {
+        use super::*;
+
+        fn brute_force_width(polygon: &Polygon<f64>) -> f64 {
+            polygon
+                .edges()
+                .map(|edge| {
+                    let length = (edge.end - edge.start).magnitude();
+                    polygon
+                        .vertices
+                        .iter()
+                        .map(|&p| (edge.end - edge.start).cross(p - edge.start).abs() / length)
+                        .fold(0.0, f64::max)
+                })
+                .fold(f64::INFINITY, f64::min)
+        }
+
+        #[test]
+        fn finds_diagonal_as_farthest_pair_of_square() {
+            let square = [
+                Point::with_coords(0, 0),
+                Point::with_coords(3, 0),
+                Point::with_coords(3, 3),
+                Point::with_coords(1, 2),
+                Point::with_coords(0, 3),
+            ];
+            let (p, q, distance) = farthest_pair(&square).unwrap();
+            assert_eq!((p, q), (Point::with_coords(0, 0), Point::with_coords(3, 3)));
+            assert!((distance - 18f64.sqrt()).abs() < 1e-12);
+            assert_eq!(farthest_distance(&square), Some(distance));
+            assert_eq!(farthest_pair(&square[..1]), None);
+            assert_eq!(farthest_distance::<i64>(&[]), None);
+        }
+
+        #[test]
+        fn finds_nearest_point() {
+            let points = [
+                Point::with_coords(10, 10),
+                Point::with_coords(3, 4),
+                Point::with_coords(-2, 1),
+                Point::with_coords(2, -1),
+            ];
+            let (i, distance) = nearest(Point::with_coords(0, 0), &points).unwrap();
+            assert_eq!(i, 2);
+            assert!((distance - 5f64.sqrt()).abs() < 1e-12);
+            assert_eq!(nearest(Point::with_coords(3, 4), &points), Some((1, 0.0)));
+            assert_eq!(nearest::<i64>(Point::with_coords(0, 0), &[]), None);
+        }
+
+        #[test]
+        fn matches_rotating_calipers_diameter() {
+            for seed in 1..=30 {
+                let points = random_points(seed * 613, 2 + seed as usize * 3, 100);
+                let hull = convex_hull(&points);
+                let (i, j) = diameter(&hull).unwrap();
+                let (p, q, _) = farthest_pair(&points).unwrap();
+                assert_eq!(
+                    squared_distance(p, q),
+                    squared_distance(hull.vertices[i], hull.vertices[j]),
+                    "seed {}",
+                    seed
+                );
+            }
+        }
+
+        #[test]
+        fn computes_width_of_convex_polygon() {
+            let rectangle: Polygon<f64> = Polygon::with_vertices(vec![
+                Point::with_coords(0.0, 0.0),
+                Point::with_coords(5.0, 0.0),
+                Point::with_coords(5.0, 2.0),
+                Point::with_coords(0.0, 2.0),
+            ]);
+            assert_eq!(width(&rectangle), 2.0);
+
+            let triangle: Polygon<f64> = Polygon::with_vertices(vec![
+                Point::with_coords(0.0, 0.0),
+                Point::with_coords(4.0, 0.0),
+                Point::with_coords(0.0, 3.0),
+            ]);
+            assert!((width(&triangle) - 2.4).abs() < 1e-12);
+
+            let segment = Polygon::with_vertices(vec![
+                Point::with_coords(0.0, 0.0),
+                Point::with_coords(1.0, 1.0),
+            ]);
+            assert_eq!(width(&segment), 0.0);
+        }
+
+        #[test]
+        fn matches_brute_force_width_of_hulls() {
+            for seed in 1..=50 {
+                let points: Vec<_> = random_points(seed * 31, 3 + seed as usize * 5, 30)
+                    .into_iter()
+                    .map(|p| p.cast::<f64>().unwrap())
+                    .collect();
+                let hull = convex_hull(&points);
+                if hull.vertices.len() < 3 {
+                    continue;
+                }
+                let expected = brute_force_width(&hull);
+                assert!(
+                    (width(&hull) - expected).abs() < 1e-9,
+                    "seed {}: {} != {}",
+                    seed,
+                    width(&hull),
+                    expected
+                );
+            }
+        }
+    }
+}
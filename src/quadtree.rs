@@ -0,0 +1,197 @@
+use crate::bounding::Aabb;
+use crate::meta::Coord;
+use crate::primitives::Point;
+use alloc::vec;
+use alloc::vec::Vec;
+
+// Nodes this deep are never split, so that many coincident points cannot subdivide forever.
+const MAX_DEPTH: usize = 24;
+
+/// A point quadtree over a fixed bound, tagging every point with an id of type `I`. A node splits
+/// into quadrants once it holds more than `capacity` points.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Quadtree<T, I>
+where
+    T: Coord,
+{
+    capacity: usize,
+    // Node 0 is the root; the children of a split node are stored consecutively.
+    nodes: Vec<QuadNode<T, I>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct QuadNode<T, I>
+where
+    T: Coord,
+{
+    bounds: Aabb<T>,
+    depth: usize,
+    items: Vec<(Point<T>, I)>,
+    children: Option<usize>,
+}
+
+impl<T, I> Quadtree<T, I>
+where
+    T: Coord + Copy,
+    I: Clone,
+{
+    /// Creates an empty tree over `bounds`, splitting nodes beyond `capacity` points, at least one.
+    pub fn with_bounds_and_capacity(bounds: Aabb<T>, capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            nodes: vec![QuadNode {
+                bounds,
+                depth: 0,
+                items: Vec::new(),
+                children: None,
+            }],
+        }
+    }
+
+    pub fn bounds(&self) -> Aabb<T> {
+        self.nodes[0].bounds
+    }
+
+    /// Adds `p` under `id`, or returns `false` and leaves the tree unchanged if `p` lies outside
+    /// the bounds.
+    pub fn insert(&mut self, id: I, p: Point<T>) -> bool {
+        if !self.bounds().contains_point(p) {
+            return false;
+        }
+        let mut node = 0;
+        while let Some(first) = self.nodes[node].children {
+            node = first + self.quadrant(node, p);
+        }
+        self.nodes[node].items.push((p, id));
+        if self.nodes[node].items.len() > self.capacity && self.nodes[node].depth < MAX_DEPTH {
+            self.split(node);
+        }
+        true
+    }
+
+    /// Returns the ids of the points inside `region`, boundary included, in no particular order.
+    pub fn query_region(&self, region: &Aabb<T>) -> Vec<I> {
+        let mut found = Vec::new();
+        let mut pending = vec![0];
+        while let Some(node) = pending.pop() {
+            let node = &self.nodes[node];
+            if !node.bounds.intersects(region) {
+                continue;
+            }
+            match node.children {
+                Some(first) => pending.extend(first..first + 4),
+                None => found.extend(
+                    node.items
+                        .iter()
+                        .filter(|(p, _)| region.contains_point(*p))
+                        .map(|(_, id)| id.clone()),
+                ),
+            }
+        }
+        found
+    }
+
+    // Quadrants are numbered by bit: 1 for the upper half in x, 2 for the upper half in y.
+    fn quadrant(&self, node: usize, p: Point<T>) -> usize {
+        let center = center(&self.nodes[node].bounds);
+        usize::from(p.x >= center.x) | usize::from(p.y >= center.y) << 1
+    }
+
+    fn split(&mut self, node: usize) {
+        let QuadNode { bounds, depth, .. } = self.nodes[node];
+        let center = center(&bounds);
+        let first = self.nodes.len();
+        for quadrant in 0..4 {
+            let (min_x, max_x) = if quadrant & 1 == 0 {
+                (bounds.min.x, center.x)
+            } else {
+                (center.x, bounds.max.x)
+            };
+            let (min_y, max_y) = if quadrant & 2 == 0 {
+                (bounds.min.y, center.y)
+            } else {
+                (center.y, bounds.max.y)
+            };
+            self.nodes.push(QuadNode {
+                bounds: Aabb::with_corners(
+                    Point::with_coords(min_x, min_y),
+                    Point::with_coords(max_x, max_y),
+                ),
+                depth: depth + 1,
+                items: Vec::new(),
+                children: None,
+            });
+        }
+        self.nodes[node].children = Some(first);
+        for (p, id) in core::mem::take(&mut self.nodes[node].items) {
+            let child = first + self.quadrant(node, p);
+            self.nodes[child].items.push((p, id));
+        }
+        for child in first..first + 4 {
+            if self.nodes[child].items.len() > self.capacity && depth + 1 < MAX_DEPTH {
+                self.split(child);
+            }
+        }
+    }
+}
+
+fn center<T>(bounds: &Aabb<T>) -> Point<T>
+where
+    T: Coord + Copy,
+{
+    let two = T::one() + T::one();
+    Point::with_coords(
+        bounds.min.x + (bounds.max.x - bounds.min.x) / two,
+        bounds.min.y + (bounds.max.y - bounds.min.y) / two,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_points;
+
+    #[test]
+    fn queries_quadtree_region() {
+        let bounds = Aabb::with_corners(Point::with_coords(0, 0), Point::with_coords(10, 10));
+        let mut tree = Quadtree::with_bounds_and_capacity(bounds, 2);
+        for (id, &(x, y)) in [(1, 1), (2, 2), (8, 8), (9, 1), (5, 5)].iter().enumerate() {
+            assert!(tree.insert(id, Point::with_coords(x, y)));
+        }
+        assert!(!tree.insert(5, Point::with_coords(11, 0)));
+
+        let region = Aabb::with_corners(Point::with_coords(0, 0), Point::with_coords(5, 5));
+        let mut found = tree.query_region(&region);
+        found.sort_unstable();
+        assert_eq!(found, vec![0, 1, 4]);
+    }
+
+    #[test]
+    fn keeps_coincident_points_in_quadtree() {
+        let bounds = Aabb::with_corners(Point::with_coords(0, 0), Point::with_coords(4, 4));
+        let mut tree = Quadtree::with_bounds_and_capacity(bounds, 1);
+        for id in 0..10 {
+            tree.insert(id, Point::with_coords(3, 3));
+        }
+        assert_eq!(tree.query_region(&bounds).len(), 10);
+    }
+
+    #[test]
+    fn matches_brute_force_quadtree_queries() {
+        let points = random_points(211, 500, 50);
+        let bounds = Aabb::with_corners(Point::with_coords(-50, -50), Point::with_coords(50, 50));
+        let mut tree = Quadtree::with_bounds_and_capacity(bounds, 4);
+        for (i, &p) in points.iter().enumerate() {
+            assert!(tree.insert(i, p));
+        }
+        for pair in random_points(223, 100, 60).chunks(2) {
+            let region = Aabb::with_corners(pair[0], pair[1]);
+            let mut found = tree.query_region(&region);
+            found.sort_unstable();
+            let expected: Vec<_> = (0..points.len())
+                .filter(|&i| region.contains_point(points[i]))
+                .collect();
+            assert_eq!(found, expected);
+        }
+    }
+}
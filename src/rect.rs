@@ -0,0 +1,114 @@
+use crate::bounding::Aabb;
+use crate::meta::Coord;
+use crate::primitives::Point;
+use crate::vectors::Vector;
+
+/// An axis-aligned rectangle given by its corner with the smallest coordinates and its extent,
+/// which is never negative, so that `origin + size` is the opposite corner.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect<T>
+where
+    T: Coord,
+{
+    pub origin: Point<T>,
+    pub size: Vector<T>,
+}
+
+impl<T> Rect<T>
+where
+    T: Coord,
+{
+    /// Negative sizes extend the rectangle the other way from `origin`, which is moved to the
+    /// smallest corner so that the size becomes positive.
+    pub fn with_origin_and_size(origin: Point<T>, size: Vector<T>) -> Self {
+        let opposite = origin.clone() + size;
+        Aabb::with_corners(origin, opposite).into()
+    }
+
+    /// Returns the middle of the rectangle, rounded towards the origin for integer coordinates.
+    pub fn center(&self) -> Point<T> {
+        let two = T::one() + T::one();
+        Point::with_coords(
+            self.origin.x.clone() + self.size.x.clone() / two.clone(),
+            self.origin.y.clone() + self.size.y.clone() / two,
+        )
+    }
+
+    /// Returns the corners counterclockwise, starting from the origin.
+    pub fn corners(&self) -> [Point<T>; 4] {
+        let (x, y) = (self.origin.x.clone(), self.origin.y.clone());
+        let (right, top) = (
+            x.clone() + self.size.x.clone(),
+            y.clone() + self.size.y.clone(),
+        );
+        [
+            self.origin.clone(),
+            Point::with_coords(right.clone(), y),
+            Point::with_coords(right, top.clone()),
+            Point::with_coords(x, top),
+        ]
+    }
+
+    /// Points on the boundary are contained.
+    pub fn contains(&self, p: Point<T>) -> bool {
+        Aabb::from(self.clone()).contains_point(p)
+    }
+}
+
+impl<T> From<Aabb<T>> for Rect<T>
+where
+    T: Coord,
+{
+    fn from(aabb: Aabb<T>) -> Self {
+        let size = aabb.max - aabb.min.clone();
+        Self {
+            origin: aabb.min,
+            size,
+        }
+    }
+}
+
+impl<T> From<Rect<T>> for Aabb<T>
+where
+    T: Coord,
+{
+    fn from(rect: Rect<T>) -> Self {
+        let max = rect.origin.clone() + rect.size;
+        Self {
+            min: rect.origin,
+            max,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_rect_by_origin_and_size() {
+        let rect = Rect::with_origin_and_size(Point::with_coords(1, 2), Vector::with_coords(4, 3));
+        assert_eq!(
+            rect.corners(),
+            [
+                Point::with_coords(1, 2),
+                Point::with_coords(5, 2),
+                Point::with_coords(5, 5),
+                Point::with_coords(1, 5)
+            ]
+        );
+        assert_eq!(rect.center(), Point::with_coords(3, 3));
+        assert!(rect.contains(Point::with_coords(5, 4)));
+        assert!(!rect.contains(Point::with_coords(0, 4)));
+
+        let flipped =
+            Rect::with_origin_and_size(Point::with_coords(5, 5), Vector::with_coords(-4, -3));
+        assert_eq!(flipped, rect);
+        assert_eq!(
+            Aabb::from(rect),
+            Aabb::with_corners(Point::with_coords(1, 2), Point::with_coords(5, 5))
+        );
+        assert_eq!(Rect::from(Aabb::from(rect)), rect);
+    }
+}
@@ -0,0 +1,576 @@
+//! Orientation and in-circle predicates for `f64` points whose signs are exact.
+//!
+//! Both predicates first evaluate the determinant in plain floating point and return it when it
+//! is provably larger than the accumulated rounding error. Otherwise they fall back to summing
+//! the exact products as floating-point expansions, following Shewchuk's "Adaptive Precision
+//! Floating-Point Arithmetic and Fast Robust Geometric Predicates".
+//!
+//! `orient_robust` is the integer counterpart: it widens the coordinates to `i128` and compares
+//! the two products of the cross product in 256-bit arithmetic.
+
+use crate::meta::Coord;
+use crate::primitives::Point;
+use crate::vectors::{Orientation, Vector};
+use alloc::vec::Vec;
+use core::any::Any;
+use core::cmp::Ordering;
+use num_traits::float::FloatCore;
+use num_traits::PrimInt;
+
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const SPLITTER: f64 = 134_217_729.0;
+const ORIENT_ERROR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const INCIRCLE_ERROR_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+/// Returns a value that is positive if `a`, `b` and `c` are counterclockwise, negative if they
+/// are clockwise and zero if they are collinear. Only the sign is exact.
+pub fn orient2d(a: Point<f64>, b: Point<f64>, c: Point<f64>) -> f64 {
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+
+    let sum = if left > 0.0 && right > 0.0 {
+        left + right
+    } else if left < 0.0 && right < 0.0 {
+        -left - right
+    } else {
+        return det;
+    };
+    if FloatCore::abs(det) >= ORIENT_ERROR_BOUND * sum {
+        return det;
+    }
+
+    let terms = [
+        two_product(a.x, b.y),
+        two_product(-a.x, c.y),
+        two_product(-c.x, b.y),
+        two_product(-a.y, b.x),
+        two_product(a.y, c.x),
+        two_product(c.y, b.x),
+    ];
+    let det = terms.iter().fold(Vec::new(), |sum, &(high, low)| {
+        grow_expansion(&grow_expansion(&sum, low), high)
+    });
+    estimate(&det)
+}
+
+/// Returns a value that is positive if `d` lies inside the circle through `a`, `b` and `c`,
+/// negative if it lies outside and zero if the four points are cocircular. The points `a`, `b`
+/// and `c` must be counterclockwise, otherwise the sign is reversed. Only the sign is exact.
+pub fn incircle(a: Point<f64>, b: Point<f64>, c: Point<f64>, d: Point<f64>) -> f64 {
+    let (adx, ady) = (a.x - d.x, a.y - d.y);
+    let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+    let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (FloatCore::abs(bdxcdy) + FloatCore::abs(cdxbdy)) * alift
+        + (FloatCore::abs(cdxady) + FloatCore::abs(adxcdy)) * blift
+        + (FloatCore::abs(adxbdy) + FloatCore::abs(bdxady)) * clift;
+    if FloatCore::abs(det) > INCIRCLE_ERROR_BOUND * permanent {
+        return det;
+    }
+
+    let (adx, ady) = (two_diff(a.x, d.x), two_diff(a.y, d.y));
+    let (bdx, bdy) = (two_diff(b.x, d.x), two_diff(b.y, d.y));
+    let (cdx, cdy) = (two_diff(c.x, d.x), two_diff(c.y, d.y));
+    let lift = |dx: &[f64], dy: &[f64]| {
+        expansion_sum(&expansion_product(dx, dx), &expansion_product(dy, dy))
+    };
+    let cross = |ux: &[f64], uy: &[f64], vx: &[f64], vy: &[f64]| {
+        expansion_sum(
+            &expansion_product(ux, vy),
+            &negate(&expansion_product(vx, uy)),
+        )
+    };
+
+    let det = expansion_sum(
+        &expansion_sum(
+            &expansion_product(&lift(&adx, &ady), &cross(&bdx, &bdy, &cdx, &cdy)),
+            &expansion_product(&lift(&bdx, &bdy), &cross(&cdx, &cdy, &adx, &ady)),
+        ),
+        &expansion_product(&lift(&cdx, &cdy), &cross(&adx, &ady, &bdx, &bdy)),
+    );
+    estimate(&det)
+}
+
+/// Returns the orientation of `a`, `b` and `c` without overflowing, however large the integer
+/// coordinates are. The sign is exact for coordinates of up to 64 bits; `i128` coordinates panic
+/// if two of them are more than `i128::MAX` apart.
+pub fn orient_robust<T>(a: Point<T>, b: Point<T>, c: Point<T>) -> Orientation
+where
+    T: Coord + PrimInt,
+{
+    let widen = |p: Point<T>| {
+        let widen = |c: T| c.to_i128().expect("signed integers fit in i128");
+        Point::with_coords(widen(p.x), widen(p.y))
+    };
+    orient_i128(&widen(a), &widen(b), &widen(c))
+}
+
+fn orient_i128(a: &Point<i128>, b: &Point<i128>, c: &Point<i128>) -> Orientation {
+    let offset = |p: &Point<i128>| {
+        let difference = |c: i128, origin: i128| {
+            c.checked_sub(origin)
+                .expect("coordinate differences fit in i128")
+        };
+        Vector::with_coords(difference(p.x, a.x), difference(p.y, a.y))
+    };
+    offset(b).cross_sign(offset(c))
+}
+
+// Evaluates the orientation with `orient2d` when `T` is `f64` or `f32`; `f32` coordinates
+// convert to `f64` exactly.
+pub(crate) fn float_orientation<T>(p: &Point<T>, q: &Point<T>, r: &Point<T>) -> Option<Orientation>
+where
+    T: Coord,
+{
+    let (a, b, c) = (as_f64_point(p)?, as_f64_point(q)?, as_f64_point(r)?);
+    Some(match orient2d(a, b, c).partial_cmp(&0.0) {
+        Some(Ordering::Greater) => Orientation::Counterclockwise,
+        Some(Ordering::Less) => Orientation::Clockwise,
+        _ => Orientation::Collinear,
+    })
+}
+
+// Evaluates the orientation with `orient_robust` when `T` is a primitive signed integer.
+pub(crate) fn integer_orientation<T>(
+    p: &Point<T>,
+    q: &Point<T>,
+    r: &Point<T>,
+) -> Option<Orientation>
+where
+    T: Coord,
+{
+    let (a, b, c) = (as_i128_point(p)?, as_i128_point(q)?, as_i128_point(r)?);
+    Some(orient_i128(&a, &b, &c))
+}
+
+// Evaluates the in-circle determinant with `incircle` when `T` is `f64` or `f32`.
+pub(crate) fn float_incircle<T>(
+    a: &Point<T>,
+    b: &Point<T>,
+    c: &Point<T>,
+    d: &Point<T>,
+) -> Option<f64>
+where
+    T: Coord,
+{
+    let (a, b) = (as_f64_point(a)?, as_f64_point(b)?);
+    let (c, d) = (as_f64_point(c)?, as_f64_point(d)?);
+    Some(incircle(a, b, c, d))
+}
+
+fn as_f64_point<T>(p: &Point<T>) -> Option<Point<f64>>
+where
+    T: Coord,
+{
+    let p: &dyn Any = p;
+    if let Some(&p) = p.downcast_ref::<Point<f64>>() {
+        Some(p)
+    } else {
+        let p = p.downcast_ref::<Point<f32>>()?;
+        Some(Point::with_coords(p.x as f64, p.y as f64))
+    }
+}
+
+fn as_i128_point<T>(p: &Point<T>) -> Option<Point<i128>>
+where
+    T: Coord,
+{
+    let p: &dyn Any = p;
+    macro_rules! widen {
+        ($($int:ty),*) => {$(
+            if let Some(p) = p.downcast_ref::<Point<$int>>() {
+                return Some(Point::with_coords(p.x as i128, p.y as i128));
+            }
+        )*};
+    }
+    widen!(i8, i16, i32, i64, isize, i128);
+    None
+}
+
+// A point stored exactly as `(x / w, y / w)` with `w > 0`, so that intersections of lines
+// through `f64` points can be compared without rounding.
+#[derive(Clone, Debug)]
+pub(crate) struct RationalPoint {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    w: Vec<f64>,
+}
+
+impl RationalPoint {
+    pub(crate) fn from_point(p: Point<f64>) -> Self {
+        Self {
+            x: grow_expansion(&[], p.x),
+            y: grow_expansion(&[], p.y),
+            w: grow_expansion(&[], 1.0),
+        }
+    }
+
+    // The intersection of the line through `a` and `b` with the line through `c` and `d`, which
+    // must not be parallel.
+    pub(crate) fn line_intersection(
+        a: Point<f64>,
+        b: Point<f64>,
+        c: Point<f64>,
+        d: Point<f64>,
+    ) -> Self {
+        let cross = |ux: &[f64], uy: &[f64], vx: &[f64], vy: &[f64]| {
+            expansion_sum(
+                &expansion_product(ux, vy),
+                &negate(&expansion_product(vx, uy)),
+            )
+        };
+        let (rx, ry) = (two_diff(b.x, a.x), two_diff(b.y, a.y));
+        let (sx, sy) = (two_diff(d.x, c.x), two_diff(d.y, c.y));
+        let (ex, ey) = (two_diff(c.x, a.x), two_diff(c.y, a.y));
+        let w = cross(&rx, &ry, &sx, &sy);
+        let t = cross(&ex, &ey, &sx, &sy);
+        let along = |origin: f64, direction: &[f64]| {
+            expansion_sum(
+                &expansion_product(&[origin], &w),
+                &expansion_product(direction, &t),
+            )
+        };
+        let (x, y) = (along(a.x, &rx), along(a.y, &ry));
+        if estimate(&w) < 0.0 {
+            Self {
+                x: negate(&x),
+                y: negate(&y),
+                w: negate(&w),
+            }
+        } else {
+            Self { x, y, w }
+        }
+    }
+}
+
+impl PartialEq for RationalPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for RationalPoint {}
+
+impl PartialOrd for RationalPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Orders lexicographically, by `x` and then by `y`.
+impl Ord for RationalPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let compare = |a: &[f64], b: &[f64]| {
+            let difference = expansion_sum(
+                &expansion_product(a, &other.w),
+                &negate(&expansion_product(b, &self.w)),
+            );
+            estimate(&difference)
+                .partial_cmp(&0.0)
+                .expect("expansions are finite")
+        };
+        compare(&self.x, &other.x).then_with(|| compare(&self.y, &other.y))
+    }
+}
+
+// Expansions are sequences of non-overlapping components ordered by increasing magnitude; their
+// exact value is the sum of the components.
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+fn two_diff(a: f64, b: f64) -> Vec<f64> {
+    let (high, low) = two_sum(a, -b);
+    grow_expansion(&grow_expansion(&[], low), high)
+}
+
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLITTER * a;
+    let high = c - (c - a);
+    (high, a - high)
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    let ((a_high, a_low), (b_high, b_low)) = (split(a), split(b));
+    let error = product - a_high * b_high - a_low * b_high - a_high * b_low;
+    (product, a_low * b_low - error)
+}
+
+fn grow_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut result = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &component in e {
+        let (sum, error) = two_sum(q, component);
+        if error != 0.0 {
+            result.push(error);
+        }
+        q = sum;
+    }
+    if q != 0.0 {
+        result.push(q);
+    }
+    result
+}
+
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |sum, &component| {
+        grow_expansion(&sum, component)
+    })
+}
+
+fn expansion_product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut product = Vec::new();
+    for &a in e {
+        for &b in f {
+            let (high, low) = two_product(a, b);
+            product = grow_expansion(&grow_expansion(&product, low), high);
+        }
+    }
+    product
+}
+
+fn negate(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|&component| -component).collect()
+}
+
+// The largest component carries the sign of a non-overlapping expansion.
+fn estimate(e: &[f64]) -> f64 {
+    e.last().copied().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f64, y: f64) -> Point<f64> {
+        Point::with_coords(x, y)
+    }
+
+    fn sign(value: f64) -> i32 {
+        if value > 0.0 {
+            1
+        } else if value < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
+
+    fn naive_orient2d(a: Point<f64>, b: Point<f64>, c: Point<f64>) -> f64 {
+        (a.x - c.x) * (b.y - c.y) - (a.y - c.y) * (b.x - c.x)
+    }
+
+    fn exact_orient2d(a: (i128, i128), b: (i128, i128), c: (i128, i128)) -> i32 {
+        ((a.0 - c.0) * (b.1 - c.1) - (a.1 - c.1) * (b.0 - c.0)).signum() as i32
+    }
+
+    fn exact_incircle(points: [(i128, i128); 4]) -> i32 {
+        let [a, b, c, d] = points;
+        let (adx, ady, bdx, bdy, cdx, cdy) = (
+            a.0 - d.0,
+            a.1 - d.1,
+            b.0 - d.0,
+            b.1 - d.1,
+            c.0 - d.0,
+            c.1 - d.1,
+        );
+        let det = (adx * adx + ady * ady) * (bdx * cdy - cdx * bdy)
+            + (bdx * bdx + bdy * bdy) * (cdx * ady - adx * cdy)
+            + (cdx * cdx + cdy * cdy) * (adx * bdy - bdx * ady);
+        det.signum() as i32
+    }
+
+    #[test]
+    fn computes_orientation_of_well_separated_points() {
+        let (a, b) = (point(0.0, 0.0), point(4.0, 0.0));
+        assert!(orient2d(a, b, point(1.0, 1.0)) > 0.0);
+        assert!(orient2d(a, b, point(1.0, -1.0)) < 0.0);
+        assert_eq!(orient2d(a, b, point(8.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn computes_exact_orientation_of_nearly_collinear_points() {
+        // Points near (0.5, 0.5) on a grid of ulp-sized steps, tested against the line through
+        // (12, 12) and (24, 24); scaled by 2^53 the coordinates become exact integers.
+        let ulp = f64::EPSILON / 2.0;
+        let scale = 2f64.powi(53);
+        let (a, b) = (point(12.0, 12.0), point(24.0, 24.0));
+        let scaled = |p: Point<f64>| ((p.x * scale) as i128, (p.y * scale) as i128);
+
+        let mut naive_mistakes = 0;
+        for i in 0..32 {
+            for j in 0..32 {
+                let c = point(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+                let expected = exact_orient2d(scaled(a), scaled(b), scaled(c));
+                assert_eq!(sign(orient2d(a, b, c)), expected, "i = {}, j = {}", i, j);
+                if sign(naive_orient2d(a, b, c)) != expected {
+                    naive_mistakes += 1;
+                }
+            }
+        }
+        assert!(naive_mistakes > 0);
+    }
+
+    #[test]
+    fn locates_point_relative_to_circle() {
+        let (a, b, c) = (point(1.0, 0.0), point(0.0, 1.0), point(-1.0, 0.0));
+        assert!(incircle(a, b, c, point(0.0, 0.0)) > 0.0);
+        assert!(incircle(a, b, c, point(2.0, 2.0)) < 0.0);
+        assert_eq!(incircle(a, b, c, point(0.0, -1.0)), 0.0);
+        assert!(incircle(c, b, a, point(0.0, 0.0)) < 0.0);
+    }
+
+    #[test]
+    fn computes_exact_incircle_of_nearly_cocircular_points() {
+        // Points on a circle of radius 5k, with the last one nudged by a few units.
+        let k = 1i128 << 25;
+        let (a, b, c) = ((5 * k, 0), (3 * k, 4 * k), (-4 * k, 3 * k));
+        let to_point = |p: (i128, i128)| point(p.0 as f64, p.1 as f64);
+
+        for i in -3..=3 {
+            for j in -3..=3 {
+                let d = (4 * k + i, -3 * k + j);
+                let expected = exact_incircle([a, b, c, d]);
+                let actual = incircle(to_point(a), to_point(b), to_point(c), to_point(d));
+                assert_eq!(sign(actual), expected, "i = {}, j = {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn computes_exact_orientation_near_i32_limits() {
+        let (a, b) = (
+            Point::with_coords(i32::MIN, i32::MIN),
+            Point::with_coords(i32::MAX, i32::MAX),
+        );
+        let c = Point::with_coords(0, 1);
+        assert_eq!(orient_robust(a, b, c), Orientation::Counterclockwise);
+        assert_eq!(
+            orient_robust(a, b, Point::with_coords(1, 0)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            orient_robust(a, b, Point::with_coords(-7, -7)),
+            Orientation::Collinear
+        );
+
+        // The differences wrap in i32 and the naive cross product comes out negative.
+        let (dx, dy) = (b.x.wrapping_sub(a.x), b.y.wrapping_sub(a.y));
+        let (ex, ey) = (c.x.wrapping_sub(a.x), c.y.wrapping_sub(a.y));
+        assert!(dx.wrapping_mul(ey).wrapping_sub(dy.wrapping_mul(ex)) < 0);
+    }
+
+    #[test]
+    fn computes_exact_orientation_near_i64_limits() {
+        let (a, b) = (
+            Point::with_coords(i64::MIN, i64::MIN),
+            Point::with_coords(i64::MAX, i64::MAX),
+        );
+        assert_eq!(
+            orient_robust(a, b, Point::with_coords(i64::MAX - 1, i64::MAX)),
+            Orientation::Counterclockwise
+        );
+        assert_eq!(
+            orient_robust(a, b, Point::with_coords(i64::MIN + 1, i64::MIN)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            orient_robust(a, b, Point::with_coords(0, 0)),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            orient_robust(
+                Point::with_coords(i64::MAX, i64::MIN),
+                Point::with_coords(i64::MIN, i64::MAX),
+                Point::with_coords(i64::MAX, i64::MAX)
+            ),
+            Orientation::Clockwise
+        );
+    }
+
+    #[test]
+    fn computes_orientation_of_small_i128_coordinates() {
+        let (a, b) = (Point::with_coords(0i128, 0), Point::with_coords(4, 0));
+        assert_eq!(
+            orient_robust(a, b, Point::with_coords(1, 1)),
+            Orientation::Counterclockwise
+        );
+        assert_eq!(
+            orient_robust(a, b, Point::with_coords(-3, 0)),
+            Orientation::Collinear
+        );
+    }
+
+    #[test]
+    fn compares_line_intersections_exactly() {
+        let (a, b) = (point(0.0, 0.0), point(1.0, 1.0));
+        let (c, d) = (point(0.0, 1.0), point(1.0, -1.0));
+        let crossing = RationalPoint::line_intersection(a, b, c, d);
+        assert_eq!(crossing, RationalPoint::line_intersection(a, b, d, c));
+
+        // The nearest `f64` to one third is slightly smaller than it.
+        let third = RationalPoint::from_point(point(1.0 / 3.0, 1.0 / 3.0));
+        assert!(crossing > third);
+        assert!(crossing < RationalPoint::from_point(point(0.34, -1.0)));
+        assert_eq!(
+            RationalPoint::line_intersection(a, point(4.0, 4.0), point(0.0, 4.0), point(4.0, 0.0)),
+            RationalPoint::from_point(point(2.0, 2.0))
+        );
+    }
+
+    #[test]
+    fn routes_only_float_points_through_robust_predicates() {
+        let (p, q, r) = (
+            Point::with_coords(0.0f32, 0.0),
+            Point::with_coords(2.0, 0.0),
+            Point::with_coords(1.0, 1.0),
+        );
+        assert_eq!(
+            float_orientation(&p, &q, &r),
+            Some(Orientation::Counterclockwise)
+        );
+        assert_eq!(
+            float_orientation(
+                &Point::with_coords(0, 0),
+                &Point::with_coords(2, 0),
+                &Point::with_coords(1, 1)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn routes_only_primitive_integer_points_through_widened_orientation() {
+        let (p, q, r) = (
+            Point::with_coords(0i16, 0),
+            Point::with_coords(2, 0),
+            Point::with_coords(1, -1),
+        );
+        assert_eq!(
+            integer_orientation(&p, &q, &r),
+            Some(Orientation::Clockwise)
+        );
+        assert_eq!(
+            integer_orientation(
+                &Point::with_coords(0.0, 0.0),
+                &Point::with_coords(2.0, 0.0),
+                &Point::with_coords(1.0, 1.0)
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Serializes a `Vector` or a `Point` as a `[x, y]` array instead of the default `{"x": .., "y": ..}` object.
+//!
+//! The derived `Serialize`/`Deserialize` impls keep the object form; opt into the array form per
+//! field with `#[serde(with = "comgeo::serde_array")]`.
+
+use crate::meta::Coord;
+use crate::primitives::Point;
+use crate::vectors::Vector;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The types that serialize as a `[x, y]` array.
+pub trait Coords<T> {
+    fn coords(&self) -> [&T; 2];
+
+    fn from_coords(x: T, y: T) -> Self;
+}
+
+impl<T> Coords<T> for Vector<T>
+where
+    T: Coord,
+{
+    fn coords(&self) -> [&T; 2] {
+        [&self.x, &self.y]
+    }
+
+    fn from_coords(x: T, y: T) -> Self {
+        Vector::with_coords(x, y)
+    }
+}
+
+impl<T> Coords<T> for Point<T>
+where
+    T: Coord,
+{
+    fn coords(&self) -> [&T; 2] {
+        [&self.x, &self.y]
+    }
+
+    fn from_coords(x: T, y: T) -> Self {
+        Point::with_coords(x, y)
+    }
+}
+
+pub fn serialize<T, V, S>(v: &V, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Coord + Serialize,
+    V: Coords<T>,
+    S: Serializer,
+{
+    v.coords().serialize(serializer)
+}
+
+pub fn deserialize<'de, T, V, D>(deserializer: D) -> Result<V, D::Error>
+where
+    T: Coord + Deserialize<'de>,
+    V: Coords<T>,
+    D: Deserializer<'de>,
+{
+    let [x, y] = <[T; 2]>::deserialize(deserializer)?;
+    Ok(V::from_coords(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Feature {
+        #[serde(with = "crate::serde_array")]
+        position: Vector<i32>,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Marker {
+        #[serde(with = "crate::serde_array")]
+        location: Point<i32>,
+    }
+
+    #[test]
+    fn serializes_vector_as_object_by_default() {
+        let v = Vector::with_coords(3, 4);
+        assert_eq!(serde_json::to_string(&v).unwrap(), r#"{"x":3,"y":4}"#);
+    }
+
+    #[test]
+    fn serializes_vector_as_array() {
+        let feature = Feature {
+            position: Vector::with_coords(3, 4),
+        };
+        assert_eq!(
+            serde_json::to_string(&feature).unwrap(),
+            r#"{"position":[3,4]}"#
+        );
+    }
+
+    #[test]
+    fn round_trips_vector_through_array() {
+        let feature: Feature = serde_json::from_str(r#"{"position":[3,4]}"#).unwrap();
+        assert_eq!(feature.position, Vector::with_coords(3, 4));
+        let json = serde_json::to_string(&feature).unwrap();
+        assert_eq!(serde_json::from_str::<Feature>(&json).unwrap(), feature);
+    }
+
+    #[test]
+    fn round_trips_point_through_array() {
+        let marker = Marker {
+            location: Point::with_coords(3, 4),
+        };
+        let json = serde_json::to_string(&marker).unwrap();
+        assert_eq!(json, r#"{"location":[3,4]}"#);
+        assert_eq!(serde_json::from_str::<Marker>(&json).unwrap(), marker);
+    }
+
+    #[test]
+    fn rejects_array_of_wrong_length() {
+        assert!(serde_json::from_str::<Feature>(r#"{"position":[3]}"#).is_err());
+    }
+}
@@ -0,0 +1,272 @@
+use crate::meta::Coord;
+use crate::primitives::Point;
+use alloc::vec::Vec;
+
+/// A static k-d tree over a set of points. Queries return indices into the slice the tree was
+/// built from; points at equal distance are ordered by index.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KdTree<T>
+where
+    T: Coord,
+{
+    // The tree is implicit: every range holds its splitting point in the middle, with the left
+    // and right subtrees on either side, splitting on x at even depths and on y at odd ones.
+    nodes: Vec<(Point<T>, usize)>,
+}
+
+impl<T> KdTree<T>
+where
+    T: Coord + Copy,
+{
+    pub fn with_points(points: &[Point<T>]) -> Self {
+        let mut nodes: Vec<_> = points.iter().copied().zip(0..).collect();
+        build(&mut nodes, 0);
+        Self { nodes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn nearest(&self, p: Point<T>) -> Option<usize> {
+        self.k_nearest(p, 1).pop()
+    }
+
+    /// Returns up to `k` indices ordered from nearest to farthest.
+    pub fn k_nearest(&self, p: Point<T>, k: usize) -> Vec<usize> {
+        let mut best = Vec::with_capacity(k.min(self.len()) + 1);
+        if k > 0 {
+            self.search_nearest(&self.nodes, 0, p, k, &mut best);
+        }
+        best.into_iter().map(|(_, i)| i).collect()
+    }
+
+    /// Returns the indices of the points inside the box spanned by `min` and `max`, boundary
+    /// included, in no particular order.
+    pub fn range(&self, min: Point<T>, max: Point<T>) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.search_range(&self.nodes, 0, min, max, &mut found);
+        found
+    }
+
+    fn search_nearest(
+        &self,
+        nodes: &[(Point<T>, usize)],
+        depth: usize,
+        p: Point<T>,
+        k: usize,
+        best: &mut Vec<(T, usize)>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+        let middle = nodes.len() / 2;
+        let (split, index) = nodes[middle];
+
+        let distance = (split - p).dot(split - p);
+        let position = best.partition_point(|&(d, i)| d < distance || (d == distance && i < index));
+        if position < k {
+            best.insert(position, (distance, index));
+            best.truncate(k);
+        }
+
+        let offset = axis(p, depth) - axis(split, depth);
+        let (near, far) = if offset < T::zero() {
+            (&nodes[..middle], &nodes[middle + 1..])
+        } else {
+            (&nodes[middle + 1..], &nodes[..middle])
+        };
+        self.search_nearest(near, depth + 1, p, k, best);
+        if best.len() < k || offset * offset <= best[best.len() - 1].0 {
+            self.search_nearest(far, depth + 1, p, k, best);
+        }
+    }
+
+    fn search_range(
+        &self,
+        nodes: &[(Point<T>, usize)],
+        depth: usize,
+        min: Point<T>,
+        max: Point<T>,
+        found: &mut Vec<usize>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+        let middle = nodes.len() / 2;
+        let (split, index) = nodes[middle];
+        if min.x <= split.x && split.x <= max.x && min.y <= split.y && split.y <= max.y {
+            found.push(index);
+        }
+
+        let coordinate = axis(split, depth);
+        if axis(min, depth) <= coordinate {
+            self.search_range(&nodes[..middle], depth + 1, min, max, found);
+        }
+        if coordinate <= axis(max, depth) {
+            self.search_range(&nodes[middle + 1..], depth + 1, min, max, found);
+        }
+    }
+}
+
+fn build<T>(nodes: &mut [(Point<T>, usize)], depth: usize)
+where
+    T: Coord + Copy,
+{
+    if nodes.len() <= 1 {
+        return;
+    }
+    let middle = nodes.len() / 2;
+    nodes.select_nth_unstable_by(middle, |(p, _), (q, _)| {
+        axis(*p, depth)
+            .partial_cmp(&axis(*q, depth))
+            .expect("coordinates are comparable")
+    });
+    let (left, right) = nodes.split_at_mut(middle);
+    build(left, depth + 1);
+    build(&mut right[1..], depth + 1);
+}
+
+fn axis<T>(p: Point<T>, depth: usize) -> T
+where
+    T: Coord + Copy,
+{
+    if depth & 1 == 0 {
+        p.x
+    } else {
+        p.y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_points;
+
+    fn brute_force_k_nearest(points: &[Point<i64>], p: Point<i64>, k: usize) -> Vec<usize> {
+        let mut indices: Vec<_> = (0..points.len()).collect();
+        indices.sort_by_key(|&i| ((points[i] - p).dot(points[i] - p), i));
+        indices.truncate(k);
+        indices
+    }
+
+    #[test]
+    fn answers_queries_on_empty_tree() {
+        let tree = KdTree::<i64>::with_points(&[]);
+        assert!(tree.is_empty());
+        assert_eq!(tree.nearest(Point::with_coords(0, 0)), None);
+        assert!(tree.k_nearest(Point::with_coords(0, 0), 3).is_empty());
+        assert!(tree
+            .range(Point::with_coords(-1, -1), Point::with_coords(1, 1))
+            .is_empty());
+    }
+
+    #[test]
+    fn finds_nearest_point() {
+        let points = [
+            Point::with_coords(0, 0),
+            Point::with_coords(5, 5),
+            Point::with_coords(-3, 4),
+            Point::with_coords(9, -1),
+        ];
+        let tree = KdTree::with_points(&points);
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.nearest(Point::with_coords(4, 3)), Some(1));
+        assert_eq!(tree.nearest(Point::with_coords(-2, 2)), Some(2));
+        assert_eq!(tree.nearest(Point::with_coords(9, -1)), Some(3));
+    }
+
+    #[test]
+    fn orders_equidistant_points_by_index() {
+        let points = [
+            Point::with_coords(2, 0),
+            Point::with_coords(0, 2),
+            Point::with_coords(-2, 0),
+            Point::with_coords(2, 0),
+        ];
+        let tree = KdTree::with_points(&points);
+        assert_eq!(
+            tree.k_nearest(Point::with_coords(0, 0), 4),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(tree.k_nearest(Point::with_coords(1, 0), 2), vec![0, 3]);
+    }
+
+    #[test]
+    fn returns_all_points_when_fewer_than_k() {
+        let points = random_points(3, 5, 10);
+        let tree = KdTree::with_points(&points);
+        assert_eq!(
+            tree.k_nearest(Point::with_coords(0, 0), 10),
+            brute_force_k_nearest(&points, Point::with_coords(0, 0), 10)
+        );
+        assert!(tree.k_nearest(Point::with_coords(0, 0), 0).is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force_nearest_neighbors() {
+        let points = random_points(7919, 500, 100);
+        let tree = KdTree::with_points(&points);
+        for (i, &p) in random_points(104_729, 200, 120).iter().enumerate() {
+            let k = 1 + i % 12;
+            assert_eq!(
+                tree.k_nearest(p, k),
+                brute_force_k_nearest(&points, p, k),
+                "query {:?}",
+                p
+            );
+            assert_eq!(tree.nearest(p), brute_force_k_nearest(&points, p, 1).pop());
+        }
+    }
+
+    #[test]
+    fn finds_points_in_range_including_boundary() {
+        let points = [
+            Point::with_coords(0, 0),
+            Point::with_coords(2, 2),
+            Point::with_coords(2, 5),
+            Point::with_coords(4, 1),
+            Point::with_coords(5, 1),
+        ];
+        let tree = KdTree::with_points(&points);
+        let mut found = tree.range(Point::with_coords(0, 0), Point::with_coords(4, 2));
+        found.sort_unstable();
+        assert_eq!(found, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn matches_brute_force_range_queries() {
+        let points = random_points(31, 500, 50);
+        let tree = KdTree::with_points(&points);
+        let corners = random_points(37, 100, 60);
+        for pair in corners.chunks(2) {
+            let min = Point::with_coords(pair[0].x.min(pair[1].x), pair[0].y.min(pair[1].y));
+            let max = Point::with_coords(pair[0].x.max(pair[1].x), pair[0].y.max(pair[1].y));
+            let mut found = tree.range(min, max);
+            found.sort_unstable();
+            let expected: Vec<_> = (0..points.len())
+                .filter(|&i| {
+                    let p = points[i];
+                    min.x <= p.x && p.x <= max.x && min.y <= p.y && p.y <= max.y
+                })
+                .collect();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn finds_nearest_float_point() {
+        let points = [
+            Point::with_coords(0.5, 0.5),
+            Point::with_coords(-1.25, 3.0),
+            Point::with_coords(2.0, -0.75),
+        ];
+        let tree = KdTree::with_points(&points);
+        assert_eq!(tree.nearest(Point::with_coords(1.5, -0.5)), Some(2));
+        assert_eq!(tree.k_nearest(Point::with_coords(0.0, 0.0), 2), vec![0, 2]);
+    }
+}
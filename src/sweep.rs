@@ -0,0 +1,1026 @@
+//! Sweep-line algorithms over segments and boxes, and the building blocks they use.
+//!
+//! `EventQueue` and `StatusStructure` are the event queue and active set of the Bentley–Ottmann
+//! sweep behind `intersections`, exposed for writing other sweeps with the same ordering.
+
+use crate::bounding::Aabb;
+use crate::meta::Coord;
+use crate::primitives::{orientation, Point, Segment, SegmentIntersection};
+use crate::robust::RationalPoint;
+use crate::vectors::Orientation;
+use alloc::collections::{BTreeSet, BinaryHeap, VecDeque};
+use alloc::vec::Vec;
+use core::cmp::{Ordering, Reverse};
+use core::ops::Range;
+use num_traits::ToPrimitive;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Intersection<T>
+where
+    T: Coord,
+{
+    pub first: usize,
+    pub second: usize,
+    pub kind: SegmentIntersection<T>,
+}
+
+/// Reports every intersecting pair of segments once, as `Intersection`s whose indices refer to
+/// `segments` with `first < second`, in roughly left-to-right order.
+///
+/// This is the Bentley–Ottmann sweep and runs in O((n + k) log n) time for n segments and k
+/// intersecting pairs. Events at segment endpoints, including shared endpoints and endpoints
+/// lying on other segments, are handled with exact predicates. Crossings strictly inside two
+/// segments are scheduled at their exact rational intersection point, so the events are processed
+/// in the right order for any coordinates that convert to `f64` exactly.
+pub fn intersections<T>(segments: &[Segment<T>]) -> impl Iterator<Item = Intersection<T>> + '_
+where
+    T: Coord + Copy + ToPrimitive,
+{
+    Sweep::new(segments)
+}
+
+/// Reports every pair of intersecting boxes once, as indices `(i, j)` into `boxes` with `i < j`,
+/// in sorted order. Boxes that only touch intersect, as with `Aabb::intersects`.
+///
+/// Boxes enter an active set in order of their left sides and leave it once the sweep passes
+/// their right sides, so only boxes overlapping in x are compared in y. This takes O(n log n + m)
+/// time for n boxes and m pairs overlapping in x.
+pub fn overlapping_box_pairs<T>(boxes: &[Aabb<T>]) -> Vec<(usize, usize)>
+where
+    T: Coord,
+{
+    let mut order: Vec<usize> = (0..boxes.len()).collect();
+    order.sort_by(|&i, &j| {
+        boxes[i]
+            .min
+            .x
+            .partial_cmp(&boxes[j].min.x)
+            .expect("coordinates are comparable")
+    });
+
+    let mut active: Vec<usize> = Vec::new();
+    let mut pairs = Vec::new();
+    for i in order {
+        let current = &boxes[i];
+        active.retain(|&j| boxes[j].max.x >= current.min.x);
+        for &j in &active {
+            let other = &boxes[j];
+            if current.min.y <= other.max.y && other.min.y <= current.max.y {
+                pairs.push((i.min(j), i.max(j)));
+            }
+        }
+        active.push(i);
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+struct Sweep<'a, T>
+where
+    T: Coord + Copy,
+{
+    segments: &'a [Segment<T>],
+    // Endpoints and crossings, keyed by exact points so that crossings fall in the right place.
+    events: EventQueue<RationalPoint, SweepEvent<T>>,
+    status: StatusStructure<T>,
+    reported: BTreeSet<(usize, usize)>,
+    pending: VecDeque<Intersection<T>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum SweepEvent<T>
+where
+    T: Coord,
+{
+    // An endpoint, paired with its segment if it is the segment's start.
+    Endpoint(Point<T>, Option<usize>),
+    // Two neighbours in the status, in their order before they cross.
+    Crossing(usize, usize),
+}
+
+impl<'a, T> Sweep<'a, T>
+where
+    T: Coord + Copy + ToPrimitive,
+{
+    fn new(segments: &'a [Segment<T>]) -> Self {
+        let status = StatusStructure::with_segments(segments);
+        let mut endpoints = Vec::with_capacity(2 * segments.len());
+        for i in 0..segments.len() {
+            let s = status.segment(i);
+            endpoints.push((s.start, Some(i)));
+            endpoints.push((s.end, None));
+        }
+        // Sorting first keeps endpoints that round to the same `f64` point in their exact order,
+        // since the queue pops equal keys in the order they were pushed. For the same reason,
+        // endpoints come before crossings at the same point.
+        endpoints.sort_by(|(p, _), (q, _)| p.partial_cmp(q).expect("coordinates are comparable"));
+        let mut events = EventQueue::new();
+        for (p, start) in endpoints {
+            events.push(
+                RationalPoint::from_point(to_f64_point(p)),
+                SweepEvent::Endpoint(p, start),
+            );
+        }
+
+        Self {
+            segments,
+            events,
+            status,
+            reported: BTreeSet::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn process_endpoint(&mut self, p: Point<T>, start: Option<usize>) {
+        let mut starting: Vec<usize> = start.into_iter().collect();
+        while let Some((_, &SweepEvent::Endpoint(q, start))) = self.events.peek() {
+            if q != p {
+                break;
+            }
+            starting.extend(start);
+            self.events.pop();
+        }
+
+        let through = self.status.through(p);
+        let (low, through) = (
+            through.start,
+            self.status.remove_range(through.start, through.end),
+        );
+
+        let touching: Vec<_> = through.iter().chain(&starting).copied().collect();
+        for (i, &a) in touching.iter().enumerate() {
+            for &b in &touching[i + 1..] {
+                self.report(a, b);
+            }
+        }
+
+        let continuing: Vec<_> = touching
+            .into_iter()
+            .filter(|&s| self.status.segment(s).end != p)
+            .collect();
+        let inserted = self.status.insert_leaving(p, continuing);
+
+        if inserted.is_empty() {
+            if low > 0 && low < self.status.len() {
+                self.schedule(self.status.at(low - 1), self.status.at(low));
+            }
+        } else {
+            if low > 0 {
+                self.schedule(self.status.at(low - 1), self.status.at(low));
+            }
+            if inserted.end < self.status.len() {
+                self.schedule(
+                    self.status.at(inserted.end - 1),
+                    self.status.at(inserted.end),
+                );
+            }
+        }
+    }
+
+    fn process_crossing(&mut self, lower: usize, upper: usize) {
+        if !self.status.contains(lower) || !self.status.contains(upper) {
+            return;
+        }
+        let rank = self.status.rank(lower);
+        if rank + 1 >= self.status.len() || self.status.at(rank + 1) != upper {
+            return;
+        }
+
+        self.status.swap(rank);
+        self.report(lower, upper);
+        if rank > 0 {
+            self.schedule(self.status.at(rank - 1), upper);
+        }
+        if rank + 2 < self.status.len() {
+            self.schedule(lower, self.status.at(rank + 2));
+        }
+    }
+
+    // Schedules the crossing of two neighbours if they cross strictly inside both segments to
+    // the right of the sweep, which is the case when the lower one is steeper.
+    fn schedule(&mut self, lower: usize, upper: usize) {
+        let (a, b) = (self.status.segment(lower), self.status.segment(upper));
+        let crosses = |s: &Segment<T>, t: &Segment<T>| {
+            let (o1, o2) = (
+                orientation(s.start, s.end, t.start),
+                orientation(s.start, s.end, t.end),
+            );
+            o1 != o2 && o1 != Orientation::Collinear && o2 != Orientation::Collinear
+        };
+        if compare_directions(a, b) != Ordering::Greater || !crosses(a, b) || !crosses(b, a) {
+            return;
+        }
+
+        let point = RationalPoint::line_intersection(
+            to_f64_point(a.start),
+            to_f64_point(a.end),
+            to_f64_point(b.start),
+            to_f64_point(b.end),
+        );
+        self.events.push(point, SweepEvent::Crossing(lower, upper));
+    }
+
+    fn report(&mut self, a: usize, b: usize) {
+        let (first, second) = if a < b { (a, b) } else { (b, a) };
+        if !self.reported.insert((first, second)) {
+            return;
+        }
+        let kind = self.segments[first].intersection_point(&self.segments[second]);
+        if kind != SegmentIntersection::None {
+            self.pending.push_back(Intersection {
+                first,
+                second,
+                kind,
+            });
+        }
+    }
+}
+
+impl<'a, T> Iterator for Sweep<'a, T>
+where
+    T: Coord + Copy + ToPrimitive,
+{
+    type Item = Intersection<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            match self.events.pop()? {
+                (_, SweepEvent::Endpoint(p, start)) => self.process_endpoint(p, start),
+                (_, SweepEvent::Crossing(lower, upper)) => self.process_crossing(lower, upper),
+            }
+        }
+        self.pending.pop_front()
+    }
+}
+
+// Tells whether the segment, which the sweep at `p` crosses, passes below, through or above `p`.
+fn locate<T>(s: &Segment<T>, p: Point<T>) -> Ordering
+where
+    T: Coord + Copy,
+{
+    if s.start.x == s.end.x {
+        if s.end.y < p.y {
+            Ordering::Less
+        } else if s.start.y > p.y {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    } else {
+        match orientation(s.start, s.end, p) {
+            Orientation::Counterclockwise => Ordering::Less,
+            Orientation::Clockwise => Ordering::Greater,
+            Orientation::Collinear => Ordering::Equal,
+        }
+    }
+}
+
+// Orders segments leaving a common point from bottom to top, with vertical ones last.
+fn compare_directions<T>(a: &Segment<T>, b: &Segment<T>) -> Ordering
+where
+    T: Coord + Copy,
+{
+    let origin = Point::origin();
+    let direction = |s: &Segment<T>| Point::with_coords(s.end.x - s.start.x, s.end.y - s.start.y);
+    match orientation(origin, direction(a), direction(b)) {
+        Orientation::Counterclockwise => Ordering::Less,
+        Orientation::Clockwise => Ordering::Greater,
+        Orientation::Collinear => Ordering::Equal,
+    }
+}
+
+fn to_f64_point<T>(p: Point<T>) -> Point<f64>
+where
+    T: Coord + Copy + ToPrimitive,
+{
+    p.cast().expect("coordinates representable as f64")
+}
+
+/// A min-queue of sweep events ordered by their points, lexicographically by x and then y. Events
+/// at equal points come out in the order they were pushed.
+///
+/// Points are usually `Point<T>` for any coordinate type; `intersections` uses the same queue
+/// with exact rational points, so that crossings between endpoints are never misplaced.
+#[derive(Clone, Debug)]
+pub struct EventQueue<P, E> {
+    heap: BinaryHeap<Reverse<Event<P, E>>>,
+    pushed: u64,
+}
+
+impl<P, E> EventQueue<P, E>
+where
+    P: PartialOrd,
+{
+    pub fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            pushed: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn push(&mut self, p: P, event: E) {
+        self.heap.push(Reverse(Event {
+            point: p,
+            sequence: self.pushed,
+            event,
+        }));
+        self.pushed += 1;
+    }
+
+    pub fn peek(&self) -> Option<(&P, &E)> {
+        self.heap
+            .peek()
+            .map(|Reverse(entry)| (&entry.point, &entry.event))
+    }
+
+    pub fn pop(&mut self) -> Option<(P, E)> {
+        self.heap
+            .pop()
+            .map(|Reverse(entry)| (entry.point, entry.event))
+    }
+}
+
+impl<P, E> Default for EventQueue<P, E>
+where
+    P: PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Event<P, E> {
+    point: P,
+    sequence: u64,
+    event: E,
+}
+
+impl<P, E> PartialEq for Event<P, E>
+where
+    P: PartialOrd,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<P, E> Eq for Event<P, E> where P: PartialOrd {}
+
+impl<P, E> PartialOrd for Event<P, E>
+where
+    P: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P, E> Ord for Event<P, E>
+where
+    P: PartialOrd,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.point
+            .partial_cmp(&other.point)
+            .expect("coordinates are comparable")
+            .then(self.sequence.cmp(&other.sequence))
+    }
+}
+
+const NIL: usize = usize::MAX;
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    segment: usize,
+    priority: u64,
+    size: usize,
+    left: usize,
+    right: usize,
+    parent: usize,
+}
+
+/// The active set of a sweep over segments, referred to by their indices, in their order along
+/// the vertical sweep line from bottom to top. When the sweep stops at a point, `through` finds
+/// the segments passing through it and `insert_leaving` adds the segments leaving it.
+///
+/// The entries live in a treap ordered by position rather than by key, so that no comparison
+/// between segments is ever needed to find one, and swapping two neighbours at their crossing is
+/// cheap. Every operation but `with_segments`, `remove_range` and `insert_leaving` takes
+/// O(log n) expected time.
+#[derive(Clone, Debug)]
+pub struct StatusStructure<T>
+where
+    T: Coord,
+{
+    segments: Vec<Segment<T>>,
+    nodes: Vec<Node>,
+    // Nodes are allocated per segment but swapping two neighbours exchanges their segments.
+    node_of: Vec<usize>,
+    root: usize,
+    seed: u64,
+}
+
+impl<T> StatusStructure<T>
+where
+    T: Coord + Copy,
+{
+    /// Creates an empty status for `segments`, storing each running from its lexicographically
+    /// smaller endpoint.
+    pub fn with_segments(segments: &[Segment<T>]) -> Self {
+        let mut status = Self {
+            segments: segments
+                .iter()
+                .map(|s| {
+                    if s.end < s.start {
+                        Segment::with_endpoints(s.end, s.start)
+                    } else {
+                        *s
+                    }
+                })
+                .collect(),
+            nodes: Vec::with_capacity(segments.len()),
+            node_of: Vec::with_capacity(segments.len()),
+            root: NIL,
+            seed: 0x9e37_79b9_7f4a_7c15,
+        };
+        for segment in 0..segments.len() {
+            let priority = status.next_priority();
+            status.nodes.push(Node {
+                segment,
+                priority,
+                size: 0,
+                left: NIL,
+                right: NIL,
+                parent: NIL,
+            });
+            status.node_of.push(segment);
+        }
+        status
+    }
+
+    fn next_priority(&mut self) -> u64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        self.seed
+    }
+
+    /// Returns `segment` as stored, running from its lexicographically smaller endpoint.
+    pub fn segment(&self, segment: usize) -> &Segment<T> {
+        &self.segments[segment]
+    }
+
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root == NIL
+    }
+
+    pub fn contains(&self, segment: usize) -> bool {
+        self.nodes[self.node_of[segment]].size > 0
+    }
+
+    /// Returns the segment at position `rank`, which must be below `len`.
+    pub fn at(&self, mut rank: usize) -> usize {
+        let mut node = self.root;
+        loop {
+            let left = self.nodes[node].left;
+            let left_size = self.size(left);
+            match rank.cmp(&left_size) {
+                Ordering::Less => node = left,
+                Ordering::Equal => return self.nodes[node].segment,
+                Ordering::Greater => {
+                    rank -= left_size + 1;
+                    node = self.nodes[node].right;
+                }
+            }
+        }
+    }
+
+    /// Returns the position of `segment`, which must be present.
+    pub fn rank(&self, segment: usize) -> usize {
+        let mut node = self.node_of[segment];
+        let mut rank = self.size(self.nodes[node].left);
+        while self.nodes[node].parent != NIL {
+            let parent = self.nodes[node].parent;
+            if self.nodes[parent].right == node {
+                rank += self.size(self.nodes[parent].left) + 1;
+            }
+            node = parent;
+        }
+        rank
+    }
+
+    /// Counts the leading segments satisfying `predicate`, which must hold for a prefix.
+    pub fn partition_point(&self, predicate: impl Fn(usize) -> bool) -> usize {
+        let (mut node, mut count) = (self.root, 0);
+        while node != NIL {
+            if predicate(self.nodes[node].segment) {
+                count += self.size(self.nodes[node].left) + 1;
+                node = self.nodes[node].right;
+            } else {
+                node = self.nodes[node].left;
+            }
+        }
+        count
+    }
+
+    /// Returns the positions of the segments passing through `p`; those before pass below it and
+    /// those after pass above it. Every segment present must cross the vertical line through `p`
+    /// without crossing another one before it, as when the sweep stops at `p`.
+    pub fn through(&self, p: Point<T>) -> Range<usize> {
+        let low = self.partition_point(|s| locate(&self.segments[s], p) == Ordering::Less);
+        let high = self.partition_point(|s| locate(&self.segments[s], p) != Ordering::Greater);
+        low..high
+    }
+
+    /// Inserts `segments`, which must be absent and start at or pass through `p`, ordered from
+    /// bottom to top by direction and then by index, and returns their positions. No segment
+    /// present may pass through `p`, as after removing the range `through` returns.
+    pub fn insert_leaving(&mut self, p: Point<T>, mut segments: Vec<usize>) -> Range<usize> {
+        let low = self.through(p).start;
+        segments.sort_by(|&a, &b| {
+            compare_directions(&self.segments[a], &self.segments[b]).then(a.cmp(&b))
+        });
+        for (i, &segment) in segments.iter().enumerate() {
+            self.insert(low + i, segment);
+        }
+        low..low + segments.len()
+    }
+
+    /// Inserts `segment`, which must be absent, at position `rank`, shifting later ones up.
+    pub fn insert(&mut self, rank: usize, segment: usize) {
+        let node = self.node_of[segment];
+        let priority = self.nodes[node].priority;
+        self.nodes[node] = Node {
+            segment,
+            priority,
+            size: 1,
+            left: NIL,
+            right: NIL,
+            parent: NIL,
+        };
+        let (left, right) = self.split(self.root, rank);
+        let left = self.merge(left, node);
+        self.root = self.merge(left, right);
+        self.nodes[self.root].parent = NIL;
+    }
+
+    /// Removes the segments at positions `low..high` and returns them in order.
+    pub fn remove_range(&mut self, low: usize, high: usize) -> Vec<usize> {
+        let (left, rest) = self.split(self.root, low);
+        let (middle, right) = self.split(rest, high - low);
+        if right != NIL {
+            self.nodes[right].parent = NIL;
+        }
+        self.root = self.merge(left, right);
+        if self.root != NIL {
+            self.nodes[self.root].parent = NIL;
+        }
+
+        let mut removed = Vec::with_capacity(high - low);
+        let mut stack = Vec::new();
+        let mut node = middle;
+        while node != NIL || !stack.is_empty() {
+            while node != NIL {
+                stack.push(node);
+                node = self.nodes[node].left;
+            }
+            node = stack.pop().expect("stack is not empty");
+            removed.push(self.nodes[node].segment);
+            let right = self.nodes[node].right;
+            self.nodes[node].size = 0;
+            node = right;
+        }
+        removed
+    }
+
+    /// Removes `segment` if present, telling whether it was.
+    pub fn remove(&mut self, segment: usize) -> bool {
+        if !self.contains(segment) {
+            return false;
+        }
+        let rank = self.rank(segment);
+        self.remove_range(rank, rank + 1);
+        true
+    }
+
+    /// Exchanges the segments at `rank` and `rank + 1`.
+    pub fn swap(&mut self, rank: usize) {
+        let (a, b) = (self.at(rank), self.at(rank + 1));
+        let (node_a, node_b) = (self.node_of[a], self.node_of[b]);
+        self.nodes[node_a].segment = b;
+        self.nodes[node_b].segment = a;
+        self.node_of[a] = node_b;
+        self.node_of[b] = node_a;
+    }
+
+    fn size(&self, node: usize) -> usize {
+        if node == NIL {
+            0
+        } else {
+            self.nodes[node].size
+        }
+    }
+
+    fn update(&mut self, node: usize) {
+        let (left, right) = (self.nodes[node].left, self.nodes[node].right);
+        self.nodes[node].size = self.size(left) + self.size(right) + 1;
+        if left != NIL {
+            self.nodes[left].parent = node;
+        }
+        if right != NIL {
+            self.nodes[right].parent = node;
+        }
+    }
+
+    // Splits off the first `rank` nodes; the parents of the returned roots are left stale.
+    fn split(&mut self, node: usize, rank: usize) -> (usize, usize) {
+        if node == NIL {
+            return (NIL, NIL);
+        }
+        let left_size = self.size(self.nodes[node].left);
+        if rank <= left_size {
+            let (left, right) = self.split(self.nodes[node].left, rank);
+            self.nodes[node].left = right;
+            self.update(node);
+            (left, node)
+        } else {
+            let (left, right) = self.split(self.nodes[node].right, rank - left_size - 1);
+            self.nodes[node].right = left;
+            self.update(node);
+            (node, right)
+        }
+    }
+
+    fn merge(&mut self, left: usize, right: usize) -> usize {
+        if left == NIL {
+            return right;
+        }
+        if right == NIL {
+            return left;
+        }
+        if self.nodes[left].priority > self.nodes[right].priority {
+            let merged = self.merge(self.nodes[left].right, right);
+            self.nodes[left].right = merged;
+            self.update(left);
+            left
+        } else {
+            let merged = self.merge(left, self.nodes[right].left);
+            self.nodes[right].left = merged;
+            self.update(right);
+            right
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::Rng;
+    use crate::vectors::Vector;
+
+    fn segment(x1: i64, y1: i64, x2: i64, y2: i64) -> Segment<i64> {
+        Segment::with_endpoints(Point::with_coords(x1, y1), Point::with_coords(x2, y2))
+    }
+
+    fn random_segments(seed: u64, n: usize, range: i64) -> Vec<Segment<i64>> {
+        let mut rng = Rng(seed);
+        let mut next = move || rng.range(0, range + 1);
+        (0..n)
+            .map(|_| segment(next(), next(), next(), next()))
+            .collect()
+    }
+
+    fn sorted(intersections: impl Iterator<Item = Intersection<i64>>) -> Vec<Intersection<i64>> {
+        let mut intersections: Vec<_> = intersections.collect();
+        intersections.sort_by_key(|i| (i.first, i.second));
+        intersections
+    }
+
+    fn brute_force(segments: &[Segment<i64>]) -> Vec<Intersection<i64>> {
+        let mut intersections = Vec::new();
+        for first in 0..segments.len() {
+            for second in first + 1..segments.len() {
+                let kind = segments[first].intersection_point(&segments[second]);
+                if kind != SegmentIntersection::None {
+                    intersections.push(Intersection {
+                        first,
+                        second,
+                        kind,
+                    });
+                }
+            }
+        }
+        intersections
+    }
+
+    fn pairs(intersections: &[Intersection<i64>]) -> Vec<(usize, usize)> {
+        intersections.iter().map(|i| (i.first, i.second)).collect()
+    }
+
+    #[test]
+    fn finds_no_intersections_among_no_segments() {
+        assert_eq!(intersections::<i64>(&[]).count(), 0);
+    }
+
+    #[test]
+    fn finds_crossing_of_two_segments() {
+        let segments = [segment(0, 0, 4, 4), segment(0, 3, 3, 0)];
+        assert_eq!(
+            intersections(&segments).collect::<Vec<_>>(),
+            vec![Intersection {
+                first: 0,
+                second: 1,
+                kind: SegmentIntersection::Point(Point::with_coords(1.5, 1.5)),
+            }]
+        );
+    }
+
+    #[test]
+    fn finds_no_intersections_among_disjoint_segments() {
+        let segments = [
+            segment(0, 0, 4, 0),
+            segment(0, 1, 4, 1),
+            segment(5, 0, 8, 3),
+            segment(2, 2, 2, 5),
+        ];
+        assert_eq!(intersections(&segments).count(), 0);
+    }
+
+    #[test]
+    fn finds_intersections_at_shared_endpoints() {
+        let segments = [
+            segment(0, 0, 2, 2),
+            segment(2, 2, 4, 0),
+            segment(2, 2, 2, 5),
+            segment(4, 0, 0, 0),
+        ];
+        assert_eq!(
+            pairs(&sorted(intersections(&segments))),
+            vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3)]
+        );
+    }
+
+    #[test]
+    fn finds_intersections_with_vertical_segments() {
+        let segments = [
+            segment(2, -5, 2, 5),
+            segment(0, 0, 4, 1),
+            segment(2, 3, 6, 3),
+            segment(-1, 4, 2, 4),
+            segment(0, 6, 4, 6),
+            segment(2, 5, 2, 8),
+        ];
+        assert_eq!(
+            pairs(&sorted(intersections(&segments))),
+            vec![(0, 1), (0, 2), (0, 3), (0, 5), (4, 5)]
+        );
+    }
+
+    #[test]
+    fn finds_every_pair_through_common_point() {
+        let segments = [
+            segment(-2, -2, 2, 2),
+            segment(-2, 2, 2, -2),
+            segment(-2, 0, 2, 0),
+            segment(0, -2, 0, 2),
+            segment(-2, -1, 2, 1),
+        ];
+        let found = sorted(intersections(&segments));
+        assert_eq!(found.len(), 10);
+        assert!(found
+            .iter()
+            .all(|i| i.kind == SegmentIntersection::Point(Point::with_coords(0.0, 0.0))));
+    }
+
+    #[test]
+    fn reports_overlapping_collinear_segments_once() {
+        let segments = [
+            segment(0, 0, 6, 3),
+            segment(8, 4, 2, 1),
+            segment(4, 2, 4, 2),
+        ];
+        assert_eq!(
+            sorted(intersections(&segments)),
+            vec![
+                Intersection {
+                    first: 0,
+                    second: 1,
+                    kind: SegmentIntersection::Overlap(segment(2, 1, 6, 3)),
+                },
+                Intersection {
+                    first: 0,
+                    second: 2,
+                    kind: SegmentIntersection::Point(Point::with_coords(4.0, 2.0)),
+                },
+                Intersection {
+                    first: 1,
+                    second: 2,
+                    kind: SegmentIntersection::Point(Point::with_coords(4.0, 2.0)),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_intersections_among_float_segments() {
+        let segments = [
+            Segment::with_endpoints(Point::with_coords(0.0, 0.0), Point::with_coords(1.0, 1.0)),
+            Segment::with_endpoints(Point::with_coords(0.0, 1.0), Point::with_coords(1.0, 0.0)),
+            Segment::with_endpoints(Point::with_coords(0.5, 0.0), Point::with_coords(0.5, 0.25)),
+        ];
+        let found: Vec<_> = intersections(&segments).collect();
+        assert_eq!(
+            found,
+            vec![Intersection {
+                first: 0,
+                second: 1,
+                kind: SegmentIntersection::Point(Point::with_coords(0.5, 0.5)),
+            }]
+        );
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_segments() {
+        for seed in 1..=100 {
+            let segments = random_segments(seed * 7919, 30, 8 + seed as i64 % 20);
+            assert_eq!(
+                sorted(intersections(&segments)),
+                brute_force(&segments),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn matches_brute_force_on_many_random_segments() {
+        let segments: Vec<_> = random_segments(104_729, 400, 1000)
+            .into_iter()
+            .map(|s| {
+                let end = Point::with_coords(
+                    s.start.x + (s.end.x - 500) / 10,
+                    s.start.y + (s.end.y - 500) / 10,
+                );
+                Segment::with_endpoints(s.start, end)
+            })
+            .collect();
+        let expected = brute_force(&segments);
+        assert!(!expected.is_empty());
+        assert_eq!(sorted(intersections(&segments)), expected);
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_segments_far_from_origin() {
+        // A unit in the last place is a quarter here, so crossings rounded to `f64` would land
+        // on the wrong side of nearby endpoints.
+        let offset = 1 << 50;
+        for seed in 1..=100 {
+            let segments: Vec<_> = random_segments(seed * 6007, 30, 12 + seed as i64 % 20)
+                .into_iter()
+                .map(|s| {
+                    segment(
+                        s.start.x + offset,
+                        s.start.y - offset,
+                        s.end.x + offset,
+                        s.end.y - offset,
+                    )
+                })
+                .collect();
+            assert_eq!(
+                sorted(intersections(&segments)),
+                brute_force(&segments),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    fn random_boxes(seed: u64, n: usize, range: i64, size: i64) -> Vec<Aabb<i64>> {
+        let mut rng = Rng(seed);
+        (0..n)
+            .map(|_| {
+                let corner = Point::with_coords(rng.range(0, range), rng.range(0, range));
+                let extent = Vector::with_coords(rng.range(0, size), rng.range(0, size));
+                Aabb::with_corners(corner, corner + extent)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_overlapping_box_pairs() {
+        let boxes = [
+            Aabb::with_corners(Point::with_coords(0, 0), Point::with_coords(4, 4)),
+            Aabb::with_corners(Point::with_coords(6, 0), Point::with_coords(8, 8)),
+            Aabb::with_corners(Point::with_coords(2, 2), Point::with_coords(7, 3)),
+            Aabb::with_corners(Point::with_coords(4, 5), Point::with_coords(5, 9)),
+            Aabb::with_corners(Point::with_coords(0, 9), Point::with_coords(4, 9)),
+        ];
+        assert_eq!(overlapping_box_pairs(&boxes), [(0, 2), (1, 2), (3, 4)]);
+        assert_eq!(overlapping_box_pairs::<i64>(&[]), []);
+    }
+
+    #[test]
+    fn matches_brute_force_on_random_boxes() {
+        for seed in 1..=50 {
+            let boxes = random_boxes(seed * 7919, 200, 1000, 60);
+            let mut expected = Vec::new();
+            for i in 0..boxes.len() {
+                for j in i + 1..boxes.len() {
+                    if boxes[i].intersects(&boxes[j]) {
+                        expected.push((i, j));
+                    }
+                }
+            }
+            assert!(!expected.is_empty());
+            assert_eq!(overlapping_box_pairs(&boxes), expected, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn pops_events_in_lexicographic_order() {
+        let mut queue = EventQueue::new();
+        for (i, &(x, y)) in [(3, 1), (1, 5), (3, 0), (1, 2), (1, 5)].iter().enumerate() {
+            queue.push(Point::with_coords(x, y), i);
+        }
+        assert_eq!(queue.len(), 5);
+        assert_eq!(queue.peek(), Some((&Point::with_coords(1, 2), &3)));
+        let mut popped = Vec::new();
+        while let Some((p, i)) = queue.pop() {
+            popped.push(((p.x, p.y), i));
+        }
+        assert_eq!(
+            popped,
+            [
+                ((1, 2), 3),
+                ((1, 5), 1),
+                ((1, 5), 4),
+                ((3, 0), 2),
+                ((3, 1), 0)
+            ]
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn keeps_status_structure_in_sweep_order() {
+        let segments: Vec<_> = (0..5).map(|y| segment(0, y, 4, y)).collect();
+        let mut status = StatusStructure::with_segments(&segments);
+        assert!(status.is_empty());
+        status.insert(0, 2);
+        status.insert(0, 4);
+        status.insert(1, 0);
+        status.insert(3, 1);
+        let order = |status: &StatusStructure<i64>| {
+            (0..status.len()).map(|r| status.at(r)).collect::<Vec<_>>()
+        };
+        assert_eq!(order(&status), [4, 0, 2, 1]);
+        assert_eq!(status.rank(2), 2);
+        assert_eq!(status.partition_point(|id| id == 4 || id == 0), 2);
+
+        assert!(status.remove(0));
+        assert!(!status.remove(0));
+        assert!(!status.contains(0));
+        status.swap(1);
+        assert_eq!(order(&status), [4, 1, 2]);
+        assert_eq!(status.remove_range(0, 2), [4, 1]);
+        assert_eq!(order(&status), [2]);
+    }
+
+    #[test]
+    fn orders_status_structure_along_sweep_line() {
+        let segments = [
+            segment(0, 0, 4, 0),
+            segment(4, 2, 0, 2),
+            segment(0, 4, 4, 4),
+            segment(2, 2, 4, 4),
+            segment(2, 2, 4, 1),
+        ];
+        let mut status = StatusStructure::with_segments(&segments);
+        assert_eq!(status.segment(1), &segment(0, 2, 4, 2));
+        for (i, s) in segments[..3].iter().enumerate() {
+            assert_eq!(status.insert_leaving(s.start, vec![i]), i..i + 1);
+        }
+
+        let p = Point::with_coords(2, 2);
+        assert_eq!(status.through(p), 1..2);
+        assert_eq!(status.remove_range(1, 2), [1]);
+        assert_eq!(status.insert_leaving(p, vec![3, 1, 4]), 1..4);
+        let order: Vec<_> = (0..status.len()).map(|r| status.at(r)).collect();
+        assert_eq!(order, [0, 4, 1, 3, 2]);
+        assert_eq!(status.through(Point::with_coords(3, 0)), 0..1);
+        assert_eq!(status.through(Point::with_coords(3, 5)), 5..5);
+    }
+}
@@ -0,0 +1,38 @@
+//! Fixtures shared by the unit tests.
+
+use crate::primitives::Point;
+use alloc::vec::Vec;
+
+/// A xorshift generator, so that randomized tests are reproducible from their seed.
+pub(crate) struct Rng(pub(crate) u64);
+
+impl Rng {
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns an integer in `low..high`.
+    pub(crate) fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next() % (high - low) as u64) as i64
+    }
+
+    /// Returns a float in `0.0..1.0`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub(crate) fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Returns `n` points with coordinates in `-range..=range`.
+pub(crate) fn random_points(seed: u64, n: usize, range: i64) -> Vec<Point<i64>> {
+    let mut rng = Rng(seed);
+    (0..n)
+        .map(|_| {
+            let x = rng.range(-range, range + 1);
+            Point::with_coords(x, rng.range(-range, range + 1))
+        })
+        .collect()
+}
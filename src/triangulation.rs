@@ -0,0 +1,597 @@
+use crate::meta::Coord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::meta::FloatCoord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::polygon::Polygon;
+use crate::primitives::{in_circle, orientation, to_f64_point, CirclePosition, Point};
+use crate::vectors::Orientation;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::ToPrimitive;
+
+/// A counterclockwise triangle of a `Triangulation`. The neighbour at index `i` shares the edge
+/// opposite `vertices[i]` and is `None` along the convex hull.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Triangle {
+    pub vertices: [usize; 3],
+    pub neighbors: [Option<usize>; 3],
+}
+
+/// Triangle vertices index into `points`, which holds the triangulated points in input order.
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct Triangulation<T>
+where
+    T: Coord,
+{
+    pub points: Vec<Point<T>>,
+    pub triangles: Vec<Triangle>,
+}
+
+/// Computes the Delaunay triangulation by Bowyer–Watson insertion in lexicographic order.
+///
+/// Repeated points only take part once, through their first occurrence, and if all points are
+/// collinear there are no triangles. Among cocircular points the choice of diagonals is
+/// arbitrary. Integer coordinates must be small enough for the fourth-degree in-circle
+/// determinant not to overflow.
+pub fn delaunay<T>(points: &[Point<T>]) -> Triangulation<T>
+where
+    T: Coord + Copy,
+{
+    let mut order = first_occurrences(points);
+    let triangles = match order.iter().skip(2).position(|&k| {
+        orientation(points[order[0]], points[order[1]], points[k]) != Orientation::Collinear
+    }) {
+        Some(k) => {
+            order.swap(2, k + 2);
+            let mut mesh = Mesh::with_first_triangle(points, [order[0], order[1], order[2]]);
+            for &i in &order[3..] {
+                mesh.insert(i);
+            }
+            mesh.into_triangles()
+        }
+        None => Vec::new(),
+    };
+
+    Triangulation {
+        points: points.to_vec(),
+        triangles,
+    }
+}
+
+/// Sums the unsigned areas of the triangles, computed in `f64`.
+pub fn triangle_fan_area<T>(triangles: &[[Point<T>; 3]]) -> f64
+where
+    T: Coord + ToPrimitive,
+{
+    triangles.iter().map(|t| twice_area(t).abs()).sum::<f64>() / 2.0
+}
+
+/// Averages the triangle centroids weighted by unsigned area, or returns `None` if the total area
+/// is zero. Overlapping triangles count twice, so this is the centroid of the surface only when
+/// they tile it, as those of a triangulation do.
+pub fn triangle_fan_centroid<T>(triangles: &[[Point<T>; 3]]) -> Option<Point<f64>>
+where
+    T: Coord + ToPrimitive,
+{
+    let (mut weight, mut x, mut y) = (0.0, 0.0, 0.0);
+    for triangle in triangles {
+        let area = twice_area(triangle).abs();
+        let [a, b, c] = [0, 1, 2].map(|i| to_f64_point(&triangle[i]));
+        weight += area;
+        x += area * (a.x + b.x + c.x) / 3.0;
+        y += area * (a.y + b.y + c.y) / 3.0;
+    }
+    if weight == 0.0 {
+        None
+    } else {
+        Some(Point::with_coords(x / weight, y / weight))
+    }
+}
+
+fn twice_area<T>(triangle: &[Point<T>; 3]) -> f64
+where
+    T: Coord + ToPrimitive,
+{
+    let [a, b, c] = [0, 1, 2].map(|i| to_f64_point(&triangle[i]));
+    (b - a).cross(c - a)
+}
+
+// Returns the indices of the first occurrence of every distinct point, in lexicographic order.
+fn first_occurrences<T>(points: &[Point<T>]) -> Vec<usize>
+where
+    T: Coord + Copy,
+{
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&i, &j| {
+        points[i]
+            .partial_cmp(&points[j])
+            .expect("coordinates are comparable")
+            .then(i.cmp(&j))
+    });
+    order.dedup_by(|&mut j, &mut i| points[i] == points[j]);
+    order
+}
+
+const INFINITE: usize = usize::MAX;
+
+// Triangles with the symbolic infinite vertex cover the outside of the convex hull, so that
+// points beyond the hull are inserted like any other.
+struct MeshTriangle {
+    vertices: [usize; 3],
+    neighbors: [usize; 3],
+    alive: bool,
+}
+
+struct Mesh<'a, T>
+where
+    T: Coord + Copy,
+{
+    points: &'a [Point<T>],
+    triangles: Vec<MeshTriangle>,
+    last: usize,
+    visited: Vec<usize>,
+    stamp: usize,
+}
+
+impl<'a, T> Mesh<'a, T>
+where
+    T: Coord + Copy,
+{
+    fn with_first_triangle(points: &'a [Point<T>], [a, b, c]: [usize; 3]) -> Self {
+        let [a, b, c] = if orientation(points[a], points[b], points[c]) == Orientation::Clockwise {
+            [a, c, b]
+        } else {
+            [a, b, c]
+        };
+        let triangle = |vertices, neighbors| MeshTriangle {
+            vertices,
+            neighbors,
+            alive: true,
+        };
+        Self {
+            points,
+            triangles: vec![
+                triangle([a, b, c], [2, 3, 1]),
+                triangle([b, a, INFINITE], [3, 2, 0]),
+                triangle([c, b, INFINITE], [1, 3, 0]),
+                triangle([a, c, INFINITE], [2, 1, 0]),
+            ],
+            last: 0,
+            visited: Vec::new(),
+            stamp: 0,
+        }
+    }
+
+    fn insert(&mut self, p: usize) {
+        let start = self.locate(p);
+        self.stamp += 1;
+        self.visited.resize(self.triangles.len(), 0);
+
+        let mut cavity = Vec::new();
+        let mut boundary = Vec::new();
+        let mut stack = vec![start];
+        self.visited[start] = self.stamp;
+        while let Some(t) = stack.pop() {
+            cavity.push(t);
+            for i in 0..3 {
+                let neighbor = self.triangles[t].neighbors[i];
+                if self.visited[neighbor] == self.stamp {
+                    continue;
+                }
+                if self.conflicts(neighbor, p) {
+                    self.visited[neighbor] = self.stamp;
+                    stack.push(neighbor);
+                } else {
+                    let vertices = self.triangles[t].vertices;
+                    boundary.push((vertices[(i + 1) % 3], vertices[(i + 2) % 3], neighbor, t));
+                }
+            }
+        }
+        for &t in &cavity {
+            self.triangles[t].alive = false;
+        }
+
+        let mut by_start = BTreeMap::new();
+        for &(a, b, outside, removed) in &boundary {
+            let created = self.triangles.len();
+            self.triangles.push(MeshTriangle {
+                vertices: [a, b, p],
+                neighbors: [INFINITE, INFINITE, outside],
+                alive: true,
+            });
+            let slot = self.triangles[outside]
+                .neighbors
+                .iter()
+                .position(|&n| n == removed)
+                .expect("outside triangle borders the cavity");
+            self.triangles[outside].neighbors[slot] = created;
+            by_start.insert(a, created);
+        }
+        for &created in by_start.values() {
+            let next = by_start[&self.triangles[created].vertices[1]];
+            self.triangles[created].neighbors[0] = next;
+            self.triangles[next].neighbors[1] = created;
+        }
+        self.last = self.triangles.len() - 1;
+    }
+
+    // Walks from the last created triangle towards `p` until reaching one in conflict with it.
+    fn locate(&self, p: usize) -> usize {
+        let mut t = self.last;
+        let mut turn = 0;
+        loop {
+            let triangle = &self.triangles[t];
+            if let Some(g) = triangle.vertices.iter().position(|&v| v == INFINITE) {
+                if self.conflicts(t, p) {
+                    return t;
+                }
+                t = triangle.neighbors[g];
+                continue;
+            }
+
+            turn = (turn + 1) % 3;
+            let next = (0..3).map(|k| (turn + k) % 3).find(|&i| {
+                let (a, b) = (
+                    triangle.vertices[(i + 1) % 3],
+                    triangle.vertices[(i + 2) % 3],
+                );
+                orientation(self.points[a], self.points[b], self.points[p])
+                    == Orientation::Clockwise
+            });
+            match next {
+                Some(i) => t = triangle.neighbors[i],
+                None => return t,
+            }
+        }
+    }
+
+    fn conflicts(&self, t: usize, p: usize) -> bool {
+        let vertices = self.triangles[t].vertices;
+        let point = self.points[p];
+        match vertices.iter().position(|&v| v == INFINITE) {
+            Some(g) => {
+                let (a, b) = (
+                    self.points[vertices[(g + 1) % 3]],
+                    self.points[vertices[(g + 2) % 3]],
+                );
+                match orientation(a, b, point) {
+                    Orientation::Counterclockwise => true,
+                    Orientation::Clockwise => false,
+                    Orientation::Collinear => {
+                        let (low, high) = if a < b { (a, b) } else { (b, a) };
+                        low < point && point < high
+                    }
+                }
+            }
+            None => {
+                let [a, b, c] = vertices.map(|v| self.points[v]);
+                in_circle(a, b, c, point) == CirclePosition::Inside
+            }
+        }
+    }
+
+    fn into_triangles(self) -> Vec<Triangle> {
+        let mut index = Vec::with_capacity(self.triangles.len());
+        let mut count = 0;
+        for triangle in &self.triangles {
+            if triangle.alive && !triangle.vertices.contains(&INFINITE) {
+                index.push(count);
+                count += 1;
+            } else {
+                index.push(INFINITE);
+            }
+        }
+
+        self.triangles
+            .iter()
+            .filter(|triangle| triangle.alive && !triangle.vertices.contains(&INFINITE))
+            .map(|triangle| Triangle {
+                vertices: triangle.vertices,
+                neighbors: triangle
+                    .neighbors
+                    .map(|n| Some(index[n]).filter(|&i| i != INFINITE)),
+            })
+            .collect()
+    }
+}
+
+impl<T> Triangulation<T>
+where
+    T: Coord + Copy,
+{
+    /// Lists, for every point, the points it shares a Delaunay edge with. Without triangles,
+    /// collinear points are connected to their neighbours along the line.
+    pub fn neighbors(&self) -> Vec<BTreeSet<usize>> {
+        let mut neighbors = vec![BTreeSet::new(); self.points.len()];
+        for triangle in &self.triangles {
+            for i in 0..3 {
+                let (a, b) = (triangle.vertices[i], triangle.vertices[(i + 1) % 3]);
+                neighbors[a].insert(b);
+                neighbors[b].insert(a);
+            }
+        }
+
+        if self.triangles.is_empty() {
+            for pair in first_occurrences(&self.points).windows(2) {
+                neighbors[pair[0]].insert(pair[1]);
+                neighbors[pair[1]].insert(pair[0]);
+            }
+        }
+        neighbors
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Triangulation<T>
+where
+    T: FloatCoord,
+{
+    /// Computes the Voronoi cell of every point, clipped to the box spanned by `min` and `max`,
+    /// as a counterclockwise polygon. Points repeating an earlier one get an empty cell.
+    pub fn voronoi_cells(&self, min: Point<T>, max: Point<T>) -> Vec<Polygon<T>> {
+        let bounds = Polygon::with_vertices(vec![
+            min,
+            Point::with_coords(max.x, min.y),
+            max,
+            Point::with_coords(min.x, max.y),
+        ]);
+        let mut cells = vec![Polygon::with_vertices(Vec::new()); self.points.len()];
+        let neighbors = self.neighbors();
+        for i in first_occurrences(&self.points) {
+            cells[i] = neighbors[i].iter().fold(bounds.clone(), |cell, &j| {
+                clip_to_closer_half(&cell, self.points[i], self.points[j])
+            });
+        }
+        cells
+    }
+}
+
+// Keeps the part of a convex polygon that is at least as close to `p` as to `q`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn clip_to_closer_half<T>(polygon: &Polygon<T>, p: Point<T>, q: Point<T>) -> Polygon<T>
+where
+    T: FloatCoord,
+{
+    let two = T::one() + T::one();
+    let midpoint = Point::with_coords((p.x + q.x) / two, (p.y + q.y) / two);
+    let side = |v: Point<T>| (q.x - p.x) * (v.x - midpoint.x) + (q.y - p.y) * (v.y - midpoint.y);
+
+    let mut vertices = Vec::with_capacity(polygon.vertices.len() + 1);
+    for edge in polygon.edges() {
+        let (a, b) = (edge.start, edge.end);
+        let (side_a, side_b) = (side(a), side(b));
+        if side_a <= T::zero() {
+            vertices.push(a);
+        }
+        if (side_a < T::zero() && side_b > T::zero()) || (side_a > T::zero() && side_b < T::zero())
+        {
+            let t = side_a / (side_a - side_b);
+            vertices.push(Point::with_coords(
+                a.x + (b.x - a.x) * t,
+                a.y + (b.y - a.y) * t,
+            ));
+        }
+    }
+    Polygon::with_vertices(vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hull::convex_hull;
+    use crate::test_utils::random_points;
+
+    fn points(coords: &[(i64, i64)]) -> Vec<Point<i64>> {
+        coords
+            .iter()
+            .map(|&(x, y)| Point::with_coords(x, y))
+            .collect()
+    }
+
+    fn assert_valid_delaunay(triangulation: &Triangulation<i64>) {
+        let Triangulation { points, triangles } = triangulation;
+        let mut twice_area = 0;
+        for (t, triangle) in triangles.iter().enumerate() {
+            let [a, b, c] = triangle.vertices.map(|v| points[v]);
+            assert_eq!(orientation(a, b, c), Orientation::Counterclockwise);
+            twice_area += (b - a).cross(c - a);
+
+            for p in points {
+                assert_ne!(in_circle(a, b, c, *p), CirclePosition::Inside);
+            }
+
+            for i in 0..3 {
+                if let Some(n) = triangle.neighbors[i] {
+                    let edge = [
+                        triangle.vertices[(i + 1) % 3],
+                        triangle.vertices[(i + 2) % 3],
+                    ];
+                    let other = &triangles[n];
+                    let j = other.neighbors.iter().position(|&m| m == Some(t)).unwrap();
+                    assert_eq!(
+                        [other.vertices[(j + 2) % 3], other.vertices[(j + 1) % 3]],
+                        edge
+                    );
+                }
+            }
+        }
+        assert_eq!(twice_area, convex_hull(points).twice_signed_area());
+    }
+
+    #[test]
+    fn triangulates_square_with_center() {
+        let triangulation = delaunay(&points(&[(0, 0), (2, 0), (2, 2), (0, 2), (1, 1)]));
+        assert_eq!(triangulation.triangles.len(), 4);
+        assert!(triangulation
+            .triangles
+            .iter()
+            .all(|t| t.vertices.contains(&4)
+                && t.neighbors.iter().filter(|n| n.is_none()).count() == 1));
+        assert_valid_delaunay(&triangulation);
+    }
+
+    #[test]
+    fn triangulates_single_triangle() {
+        let triangulation = delaunay(&points(&[(0, 0), (0, 3), (4, 0)]));
+        assert_eq!(triangulation.triangles.len(), 1);
+        assert_eq!(triangulation.triangles[0].neighbors, [None, None, None]);
+        assert_valid_delaunay(&triangulation);
+    }
+
+    #[test]
+    fn produces_no_triangles_for_degenerate_input() {
+        assert!(delaunay::<i64>(&[]).triangles.is_empty());
+        let single = delaunay(&points(&[(2, 3)]));
+        assert_eq!(single.points, points(&[(2, 3)]));
+        assert!(single.triangles.is_empty());
+        assert!(delaunay(&points(&[(1, 1), (1, 1), (1, 1)]))
+            .triangles
+            .is_empty());
+        assert!(delaunay(&points(&[(0, 0), (3, 3), (1, 1), (2, 2)]))
+            .triangles
+            .is_empty());
+    }
+
+    #[test]
+    fn triangulates_collinear_points_with_one_outlier() {
+        let triangulation = delaunay(&points(&[(0, 0), (1, 0), (2, 0), (3, 0), (1, 5)]));
+        assert_eq!(triangulation.triangles.len(), 3);
+        assert_valid_delaunay(&triangulation);
+    }
+
+    #[test]
+    fn ignores_repeated_points() {
+        let triangulation = delaunay(&points(&[(0, 0), (4, 0), (0, 0), (0, 4), (4, 0)]));
+        assert_eq!(triangulation.points.len(), 5);
+        assert_eq!(triangulation.triangles.len(), 1);
+        let mut vertices = triangulation.triangles[0].vertices;
+        vertices.sort_unstable();
+        assert_eq!(vertices, [0, 1, 3]);
+    }
+
+    #[test]
+    fn triangulates_cocircular_grid_points() {
+        let grid: Vec<_> = (0..6)
+            .flat_map(|x| (0..6).map(move |y| Point::with_coords(x, y)))
+            .collect();
+        let triangulation = delaunay(&grid);
+        assert_eq!(triangulation.triangles.len(), 50);
+        assert_valid_delaunay(&triangulation);
+    }
+
+    #[test]
+    fn produces_valid_delaunay_triangulation_of_random_points() {
+        for seed in 1..=30 {
+            let input = random_points(seed * 7919, 10 + seed as usize * 4, 20);
+            assert_valid_delaunay(&delaunay(&input));
+        }
+    }
+
+    #[test]
+    fn triangulates_float_points() {
+        let triangulation = delaunay(&[
+            Point::with_coords(0.0, 0.0),
+            Point::with_coords(1.0, 0.0),
+            Point::with_coords(0.0, 1.0),
+            Point::with_coords(1.0, 1.0),
+            Point::with_coords(0.5, 0.5 + f64::EPSILON),
+        ]);
+        assert_eq!(triangulation.triangles.len(), 4);
+    }
+
+    #[test]
+    fn lists_neighbors_along_delaunay_edges() {
+        let triangulation = delaunay(&points(&[(0, 0), (2, 0), (2, 2), (0, 2), (1, 1)]));
+        let neighbors = triangulation.neighbors();
+        assert_eq!(neighbors[4], (0..4).collect());
+        assert_eq!(neighbors[0], [1, 3, 4].iter().copied().collect());
+    }
+
+    #[test]
+    fn lists_neighbors_of_collinear_points_along_line() {
+        let triangulation = delaunay(&points(&[(2, 2), (0, 0), (1, 1)]));
+        let neighbors = triangulation.neighbors();
+        assert_eq!(neighbors[0], [2].iter().copied().collect());
+        assert_eq!(neighbors[2], [0, 1].iter().copied().collect());
+    }
+
+    #[test]
+    fn sums_area_and_centroid_of_triangulated_square() {
+        let square = delaunay(&points(&[(0, 0), (1, 0), (1, 1), (0, 1)]));
+        let triangles: Vec<[Point<i64>; 3]> = square
+            .triangles
+            .iter()
+            .map(|t| t.vertices.map(|v| square.points[v]))
+            .collect();
+        assert_eq!(triangles.len(), 2);
+        assert_eq!(triangle_fan_area(&triangles), 1.0);
+        assert_eq!(
+            triangle_fan_centroid(&triangles),
+            Some(Point::with_coords(0.5, 0.5))
+        );
+
+        let mut clockwise = triangles.clone();
+        clockwise[0].reverse();
+        assert_eq!(triangle_fan_area(&clockwise), 1.0);
+        assert_eq!(triangle_fan_centroid::<i64>(&[]), None);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    mod float {
+        use super::*;
+        use crate::test_utils::Rng;
+
+        fn box_cells(coords: &[(f64, f64)]) -> Vec<Polygon<f64>> {
+            let sites: Vec<_> = coords
+                .iter()
+                .map(|&(x, y)| Point::with_coords(x, y))
+                .collect();
+            delaunay(&sites)
+                .voronoi_cells(Point::with_coords(0.0, 0.0), Point::with_coords(4.0, 4.0))
+        }
+
+        #[test]
+        fn splits_box_between_two_sites() {
+            let cells = box_cells(&[(1.0, 2.0), (3.0, 2.0)]);
+            assert_eq!(cells[0].area(), 8.0);
+            assert_eq!(cells[1].area(), 8.0);
+            assert!(cells[0].contains_point(Point::with_coords(0.0, 0.0)));
+            assert!(!cells[0].contains_point(Point::with_coords(2.5, 1.0)));
+        }
+
+        #[test]
+        fn computes_voronoi_cells_of_grid() {
+            let coords: Vec<_> = (0..4)
+                .flat_map(|x| (0..4).map(move |y| (x as f64 + 0.5, y as f64 + 0.5)))
+                .collect();
+            let cells = box_cells(&coords);
+            for (cell, &(x, y)) in cells.iter().zip(&coords) {
+                assert!((cell.area() - 1.0).abs() < 1e-12);
+                assert_eq!(cell.winding(), Orientation::Counterclockwise);
+                assert!(cell.contains_point(Point::with_coords(x, y)));
+            }
+        }
+
+        #[test]
+        fn covers_box_with_voronoi_cells_of_random_sites() {
+            let mut rng = Rng(88_172_645_463_325_252);
+            let mut next = move || rng.range(0, 4000) as f64 / 1000.0;
+            let coords: Vec<_> = (0..60).map(|_| (next(), next())).collect();
+            let cells = box_cells(&coords);
+            let total: f64 = cells.iter().map(Polygon::area).sum();
+            assert!((total - 16.0).abs() < 1e-9);
+            for (cell, &(x, y)) in cells.iter().zip(&coords) {
+                assert!(cell.is_convex());
+                assert!(cell.contains_point(Point::with_coords(x, y)));
+            }
+        }
+
+        #[test]
+        fn gives_repeated_sites_empty_cells() {
+            let cells = box_cells(&[(1.0, 1.0), (3.0, 3.0), (1.0, 1.0)]);
+            assert_eq!(cells[0].area(), 8.0);
+            assert!(cells[2].vertices.is_empty());
+        }
+    }
+}
@@ -1,8 +1,19 @@
 use crate::meta::Coord;
-use std::fmt;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::meta::FloatCoord;
+use alloc::vec::Vec;
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, NumCast, PrimInt, ToPrimitive};
 
-#[derive(Default, PartialEq, Eq, Debug)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Vector<T>
 where
     T: Coord,
@@ -19,6 +30,10 @@ where
         Self { x, y }
     }
 
+    pub fn zero() -> Self {
+        Self::with_coords(T::zero(), T::zero())
+    }
+
     pub fn i_hat() -> Self {
         Self::with_coords(T::one(), T::zero())
     }
@@ -27,6 +42,30 @@ where
         Self::with_coords(T::zero(), T::one())
     }
 
+    /// Named like a constant, but a function: `T::one()` cannot be evaluated in a `const`.
+    #[allow(non_snake_case)]
+    pub fn UP() -> Self {
+        Self::j_hat()
+    }
+
+    /// Named like a constant, but a function; see `UP`.
+    #[allow(non_snake_case)]
+    pub fn DOWN() -> Self {
+        -Self::j_hat()
+    }
+
+    /// Named like a constant, but a function; see `UP`.
+    #[allow(non_snake_case)]
+    pub fn LEFT() -> Self {
+        -Self::i_hat()
+    }
+
+    /// Named like a constant, but a function; see `UP`.
+    #[allow(non_snake_case)]
+    pub fn RIGHT() -> Self {
+        Self::i_hat()
+    }
+
     pub fn dot(self, other: Self) -> T {
         self.x * other.x + self.y * other.y
     }
@@ -34,6 +73,299 @@ where
     pub fn cross(self, other: Self) -> T {
         self.x * other.y - other.x * self.y
     }
+
+    /// Computes the squared length, which is exact for integer coordinates.
+    pub fn norm_squared(self) -> T {
+        self.x.clone() * self.x + self.y.clone() * self.y
+    }
+
+    pub fn as_array(&self) -> &[T; 2] {
+        // SAFETY: `Vector` is `repr(C)` with exactly two fields of type `T`, so it has the same
+        // layout as `[T; 2]`.
+        unsafe { &*(self as *const Self as *const [T; 2]) }
+    }
+
+    pub fn as_mut_array(&mut self) -> &mut [T; 2] {
+        // SAFETY: see `as_array`.
+        unsafe { &mut *(self as *mut Self as *mut [T; 2]) }
+    }
+
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Vector<U>
+    where
+        U: Coord,
+    {
+        Vector::with_coords(f(self.x), f(self.y))
+    }
+
+    pub fn zip_map<U>(self, other: Self, f: impl Fn(T, T) -> U) -> Vector<U>
+    where
+        U: Coord,
+    {
+        Vector::with_coords(f(self.x, other.x), f(self.y, other.y))
+    }
+
+    pub fn hadamard(self, other: Self) -> Self {
+        Self::with_coords(self.x * other.x, self.y * other.y)
+    }
+
+    /// Divides component-wise. For integer coordinates a zero component in `other` panics,
+    /// just like the underlying integer division.
+    pub fn component_div(self, other: Self) -> Self {
+        Self::with_coords(self.x / other.x, self.y / other.y)
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Coord + ToPrimitive,
+{
+    pub fn cast<U>(self) -> Option<Vector<U>>
+    where
+        U: Coord + NumCast,
+    {
+        Some(Vector::with_coords(U::from(self.x)?, U::from(self.y)?))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Orientation {
+    Clockwise,
+    Collinear,
+    Counterclockwise,
+}
+
+impl<T> Vector<T>
+where
+    T: Coord + PrimInt,
+{
+    /// Returns the sign of `self.cross(other)` as an orientation, comparing the two products
+    /// in widened arithmetic so that it is exact for any signed integer coordinates.
+    pub fn cross_sign(self, other: Self) -> Orientation {
+        let widen = |c: T| c.to_i128().expect("signed integers fit in i128");
+        let (x, y, other_x, other_y) =
+            (widen(self.x), widen(self.y), widen(other.x), widen(other.y));
+        match compare_products(x, other_y, other_x, y) {
+            Ordering::Less => Orientation::Clockwise,
+            Ordering::Equal => Orientation::Collinear,
+            Ordering::Greater => Orientation::Counterclockwise,
+        }
+    }
+}
+
+fn compare_products(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let (left_sign, right_sign) = (a.signum() * b.signum(), c.signum() * d.signum());
+    if left_sign != right_sign {
+        return left_sign.cmp(&right_sign);
+    }
+    let ordering = product_magnitude(a, b).cmp(&product_magnitude(c, d));
+    if left_sign < 0 {
+        ordering.reverse()
+    } else {
+        ordering
+    }
+}
+
+fn product_magnitude(a: i128, b: i128) -> (u128, u128) {
+    const LOW: u128 = u64::MAX as u128;
+    let (a, b) = (a.unsigned_abs(), b.unsigned_abs());
+    let (a_high, a_low, b_high, b_low) = (a >> 64, a & LOW, b >> 64, b & LOW);
+
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let middle = (low_low >> 64) + (high_low & LOW) + (low_high & LOW);
+
+    let high = a_high * b_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+    let low = (middle << 64) | (low_low & LOW);
+    (high, low)
+}
+
+impl<T> Vector<T>
+where
+    T: Coord + CheckedAdd + CheckedSub + CheckedMul,
+{
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Some(Self::with_coords(
+            self.x.checked_add(&other.x)?,
+            self.y.checked_add(&other.y)?,
+        ))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(Self::with_coords(
+            self.x.checked_sub(&other.x)?,
+            self.y.checked_sub(&other.y)?,
+        ))
+    }
+
+    pub fn checked_mul_scalar(self, scalar: T) -> Option<Self> {
+        Some(Self::with_coords(
+            self.x.checked_mul(&scalar)?,
+            self.y.checked_mul(&scalar)?,
+        ))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Vector<T>
+where
+    T: FloatCoord,
+{
+    pub fn magnitude(self) -> T {
+        self.x.hypot(self.y)
+    }
+
+    /// Scales the vector to unit length; the zero vector has no direction and yields NaNs.
+    pub fn normalized(self) -> Self {
+        self / self.magnitude()
+    }
+
+    /// Same as `magnitude`, named to pair with `norm_squared`.
+    pub fn norm(self) -> T {
+        self.magnitude()
+    }
+
+    /// Scales the vector to unit length in place, like `normalized`.
+    pub fn normalize(&mut self) {
+        let magnitude = self.magnitude();
+        self.x = self.x / magnitude;
+        self.y = self.y / magnitude;
+    }
+
+    /// Returns the unsigned angle to `other` in `[0, π]`, or zero if either vector is zero.
+    pub fn angle_between(self, other: Self) -> T {
+        self.cross(other).abs().atan2(self.dot(other))
+    }
+
+    /// Returns the component parallel to `other`; projecting onto the zero vector yields NaNs.
+    pub fn project_onto(self, other: Self) -> Self {
+        other * (self.dot(other) / other.norm_squared())
+    }
+
+    /// Returns the component perpendicular to `other`, so that it and `project_onto` sum to the
+    /// vector itself.
+    pub fn reject_from(self, other: Self) -> Self {
+        self - self.project_onto(other)
+    }
+
+    pub fn angle(self) -> T {
+        self.y.atan2(self.x)
+    }
+
+    pub fn heading(self) -> T {
+        let angle = self.y.atan2(self.x);
+        if angle >= T::zero() {
+            angle
+        } else {
+            let wrapped = angle + T::TAU();
+            if wrapped < T::TAU() {
+                wrapped
+            } else {
+                T::zero()
+            }
+        }
+    }
+
+    pub fn rotate(self, radians: T) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::with_coords(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    pub fn rotate_towards(self, target: Self, max_radians: T) -> Self {
+        let magnitude = self.magnitude();
+        let target_magnitude = target.magnitude();
+        if magnitude.is_zero() || target_magnitude.is_zero() {
+            return self;
+        }
+
+        let angle = self.cross(target).atan2(self.dot(target));
+        if angle.abs() <= max_radians {
+            return target * (magnitude / target_magnitude);
+        }
+
+        if angle < T::zero() {
+            self.rotate(-max_radians)
+        } else {
+            self.rotate(max_radians)
+        }
+    }
+
+    /// Refracts a unit direction through a surface with unit `normal` facing against it, where
+    /// `eta` is the ratio of refractive indices. Returns `None` on total internal reflection.
+    pub fn refract(self, normal: Self, eta: T) -> Option<Self> {
+        let cos_incident = -self.dot(normal);
+        let k = T::one() - eta * eta * (T::one() - cos_incident * cos_incident);
+        if k < T::zero() {
+            return None;
+        }
+        Some(self * eta + normal * (eta * cos_incident - k.sqrt()))
+    }
+
+    pub fn floor(self) -> Self {
+        Self::with_coords(self.x.floor(), self.y.floor())
+    }
+
+    pub fn ceil(self) -> Self {
+        Self::with_coords(self.x.ceil(), self.y.ceil())
+    }
+
+    pub fn round(self) -> Self {
+        Self::with_coords(self.x.round(), self.y.round())
+    }
+
+    /// Rounds each coordinate to the nearest multiple of `step`. A zero `step` leaves the vector
+    /// unchanged.
+    pub fn snap_to_grid(self, step: T) -> Self {
+        if step.is_zero() {
+            return self;
+        }
+        Self::with_coords(
+            (self.x / step).round() * step,
+            (self.y / step).round() * step,
+        )
+    }
+
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        Self::with_coords(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+        )
+    }
+
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let magnitude = self.magnitude();
+        let other_magnitude = other.magnitude();
+        let angle = self.cross(other).atan2(self.dot(other));
+        if magnitude.is_zero() || other_magnitude.is_zero() || angle.abs() < T::epsilon().sqrt() {
+            return self.lerp(other, t);
+        }
+
+        let heading = self.angle() + angle * t;
+        let (sin, cos) = heading.sin_cos();
+        Self::with_coords(cos, sin) * (magnitude + (other_magnitude - magnitude) * t)
+    }
+}
+
+pub fn dot_all<T>(a: &[Vector<T>], b: &[Vector<T>]) -> Vec<T>
+where
+    T: Coord,
+{
+    assert_eq!(a.len(), b.len(), "slices of vectors differ in length");
+    a.iter()
+        .zip(b)
+        .map(|(v, w)| v.clone().dot(w.clone()))
+        .collect()
+}
+
+pub fn cross_all<T>(a: &[Vector<T>], b: &[Vector<T>]) -> Vec<T>
+where
+    T: Coord,
+{
+    assert_eq!(a.len(), b.len(), "slices of vectors differ in length");
+    a.iter()
+        .zip(b)
+        .map(|(v, w)| v.clone().cross(w.clone()))
+        .collect()
 }
 
 impl<T> fmt::Display for Vector<T>
@@ -45,6 +377,78 @@ where
     }
 }
 
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Vector<T>
+where
+    T: Coord + AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> RelativeEq for Vector<T>
+where
+    T: Coord + RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T> Arbitrary<'a> for Vector<T>
+where
+    T: Coord + Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::with_coords(T::arbitrary(u)?, T::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(T::size_hint(depth), T::size_hint(depth))
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<Vector<T>> for mint::Vector2<T>
+where
+    T: Coord,
+{
+    fn from(v: Vector<T>) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<mint::Vector2<T>> for Vector<T>
+where
+    T: Coord,
+{
+    fn from(v: mint::Vector2<T>) -> Self {
+        Self::with_coords(v.x, v.y)
+    }
+}
+
 impl<T> Neg for Vector<T>
 where
     T: Coord,
@@ -85,7 +489,7 @@ where
     type Output = Self;
 
     fn mul(self, scalar: T) -> Self::Output {
-        Self::with_coords(self.x * scalar, self.y * scalar)
+        Self::with_coords(self.x * scalar.clone(), self.y * scalar)
     }
 }
 
@@ -96,13 +500,83 @@ where
     type Output = Self;
 
     fn div(self, scalar: T) -> Self::Output {
-        Self::with_coords(self.x / scalar, self.y / scalar)
+        Self::with_coords(self.x / scalar.clone(), self.y / scalar)
+    }
+}
+
+forward_ref_unop!(impl Neg, neg for Vector<T>);
+forward_ref_binop!(impl Add, add for Vector<T>, Vector<T>);
+forward_ref_binop!(impl Sub, sub for Vector<T>, Vector<T>);
+forward_ref_binop!(impl Mul, mul for Vector<T>, T);
+forward_ref_binop!(impl Div, div for Vector<T>, T);
+
+impl<T> AddAssign for Vector<T>
+where
+    T: Coord,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.x = self.x.clone() + other.x;
+        self.y = self.y.clone() + other.y;
+    }
+}
+
+impl<T> SubAssign for Vector<T>
+where
+    T: Coord,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.x = self.x.clone() - other.x;
+        self.y = self.y.clone() - other.y;
+    }
+}
+
+impl<T> MulAssign<T> for Vector<T>
+where
+    T: Coord,
+{
+    fn mul_assign(&mut self, scalar: T) {
+        self.x = self.x.clone() * scalar.clone();
+        self.y = self.y.clone() * scalar;
+    }
+}
+
+impl<T> DivAssign<T> for Vector<T>
+where
+    T: Coord,
+{
+    fn div_assign(&mut self, scalar: T) {
+        self.x = self.x.clone() / scalar.clone();
+        self.y = self.y.clone() / scalar;
+    }
+}
+
+forward_ref_op_assign!(impl AddAssign, add_assign for Vector<T>, Vector<T>);
+forward_ref_op_assign!(impl SubAssign, sub_assign for Vector<T>, Vector<T>);
+forward_ref_op_assign!(impl MulAssign, mul_assign for Vector<T>, T);
+forward_ref_op_assign!(impl DivAssign, div_assign for Vector<T>, T);
+
+impl<T> From<(T, T)> for Vector<T>
+where
+    T: Coord,
+{
+    fn from((x, y): (T, T)) -> Self {
+        Self::with_coords(x, y)
+    }
+}
+
+impl<T> From<Vector<T>> for (T, T)
+where
+    T: Coord,
+{
+    fn from(v: Vector<T>) -> Self {
+        (v.x, v.y)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::Rng;
 
     #[test]
     fn creates_default_vector() {
@@ -130,6 +604,20 @@ mod tests {
         assert_eq!(y_unit, Vector::with_coords(0, 1));
     }
 
+    #[test]
+    fn creates_zero_vector_equal_to_default() {
+        let zero: Vector<i32> = Vector::zero();
+        assert_eq!(zero, Vector::default());
+    }
+
+    #[test]
+    fn creates_unit_vectors_for_named_directions() {
+        assert_eq!(Vector::<i32>::UP(), Vector::j_hat());
+        assert_eq!(Vector::<i32>::DOWN(), Vector::with_coords(0, -1));
+        assert_eq!(Vector::<i32>::LEFT(), Vector::with_coords(-1, 0));
+        assert_eq!(Vector::<i32>::RIGHT(), Vector::i_hat());
+    }
+
     #[test]
     fn creates_string_representation_of_vector() {
         let v = Vector::with_coords(-42, 0);
@@ -164,6 +652,59 @@ mod tests {
         assert_eq!(horizontal + vertical, Vector::with_coords(42, 42));
     }
 
+    #[test]
+    fn keeps_operands_usable_after_arithmetic() {
+        let v = Vector::with_coords(3, -1);
+        let w = Vector::with_coords(1, 2);
+        assert_eq!(v + w, Vector::with_coords(4, 1));
+        assert_eq!(v - w, Vector::with_coords(2, -3));
+        assert_eq!(v.dot(w), 1);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn applies_operators_to_references() {
+        let v = Vector::with_coords(6, -4);
+        let w = Vector::with_coords(1, 2);
+        assert_eq!(&v + &w, Vector::with_coords(7, -2));
+        assert_eq!(&v - w, Vector::with_coords(5, -6));
+        assert_eq!(v + &w, Vector::with_coords(7, -2));
+        assert_eq!(-&v, Vector::with_coords(-6, 4));
+        assert_eq!(&v * 2, Vector::with_coords(12, -8));
+        assert_eq!(&v / &2, Vector::with_coords(3, -2));
+    }
+
+    #[test]
+    fn applies_compound_assignment_operators() {
+        let mut v = Vector::with_coords(6, -4);
+        v += Vector::with_coords(2, 2);
+        assert_eq!(v, Vector::with_coords(8, -2));
+        v -= &Vector::with_coords(1, 1);
+        assert_eq!(v, Vector::with_coords(7, -3));
+        v *= 2;
+        assert_eq!(v, Vector::with_coords(14, -6));
+        v /= &2;
+        assert_eq!(v, Vector::with_coords(7, -3));
+    }
+
+    #[test]
+    fn converts_vector_to_and_from_tuple() {
+        let v: Vector<i32> = (3, -4).into();
+        assert_eq!(v, Vector::with_coords(3, -4));
+        let (x, y): (i32, i32) = v.into();
+        assert_eq!((x, y), (3, -4));
+    }
+
+    #[test]
+    fn hashes_equal_vectors_equally() {
+        let set: std::collections::HashSet<_> = [(1, 2), (3, 4), (1, 2)]
+            .iter()
+            .map(|&t| Vector::<i64>::from(t))
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Vector::with_coords(3, 4)));
+    }
+
     #[test]
     fn subtracts_two_vectors() {
         let horizontal = Vector::with_coords(42, 0);
@@ -196,10 +737,462 @@ mod tests {
         assert_eq!(v.dot(w), 42);
     }
 
+    #[test]
+    fn computes_squared_norm_of_integer_vector() {
+        assert_eq!(Vector::with_coords(3, -4).norm_squared(), 25);
+    }
+
     #[test]
     fn computes_cross_product_of_two_vectors() {
         let v = Vector::with_coords(42, 2);
         let w = Vector::with_coords(3, 1);
         assert_eq!(v.cross(w), 36);
     }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    mod float {
+        use super::*;
+
+        #[test]
+        fn computes_magnitude_of_float_vector() {
+            let v = Vector::with_coords(3.0, -4.0);
+            assert_eq!(v.magnitude(), 5.0);
+        }
+
+        #[test]
+        fn normalizes_float_vector_in_its_own_precision() {
+            let v: Vector<f32> = Vector::with_coords(3.0f32, -4.0f32).normalized();
+            assert_eq!(v, Vector::with_coords(0.6f32, -0.8f32));
+        }
+
+        #[test]
+        fn computes_norm_of_float_vector() {
+            assert_eq!(Vector::with_coords(-5.0, 12.0).norm(), 13.0);
+            assert_eq!(Vector::with_coords(-5.0, 12.0).norm_squared(), 169.0);
+        }
+
+        #[test]
+        fn normalizes_float_vector_in_place() {
+            let mut v = Vector::with_coords(0.0, -2.5);
+            v.normalize();
+            assert_eq!(v, Vector::with_coords(0.0, -1.0));
+        }
+
+        #[test]
+        fn computes_unsigned_angle_between_vectors() {
+            let v = Vector::with_coords(1.0, 0.0);
+            let angle = v.angle_between(Vector::with_coords(-1.0, -1.0));
+            assert!((angle - 3.0 * std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+            let angle = Vector::with_coords(0.0, 2.0).angle_between(Vector::with_coords(3.0, 0.0));
+            assert_eq!(angle, std::f64::consts::FRAC_PI_2);
+            assert_eq!(Vector::<f64>::zero().angle_between(Vector::i_hat()), 0.0);
+        }
+
+        #[test]
+        fn projects_vector_onto_another() {
+            let v = Vector::with_coords(3.0, 4.0);
+            assert_eq!(
+                v.project_onto(Vector::with_coords(2.0, 0.0)),
+                Vector::with_coords(3.0, 0.0)
+            );
+            let v = Vector::with_coords(2.0, 0.0);
+            assert_eq!(
+                v.project_onto(Vector::with_coords(1.0, 1.0)),
+                Vector::with_coords(1.0, 1.0)
+            );
+        }
+
+        #[test]
+        fn rejects_vector_from_another() {
+            let v = Vector::with_coords(3.0, 4.0);
+            assert_eq!(
+                v.reject_from(Vector::with_coords(2.0, 0.0)),
+                Vector::with_coords(0.0, 4.0)
+            );
+            let v = Vector::with_coords(2.0, 0.0);
+            assert_eq!(
+                v.reject_from(Vector::with_coords(1.0, 1.0)),
+                Vector::with_coords(1.0, -1.0)
+            );
+        }
+
+        #[test]
+        fn computes_angle_of_float_vector() {
+            let v = Vector::with_coords(-1.0, -1.0);
+            assert_eq!(v.angle(), -3.0 * std::f64::consts::FRAC_PI_4);
+        }
+
+        #[test]
+        fn rotates_float_vector() {
+            let v = Vector::with_coords(2.0f32, 0.0).rotate(std::f32::consts::FRAC_PI_2);
+            assert!(v.x.abs() < 1e-6);
+            assert!((v.y - 2.0).abs() < 1e-6);
+        }
+
+        #[test]
+        fn passes_straight_through_interface_along_normal() {
+            let v =
+                Vector::with_coords(0.0, -1.0).refract(Vector::with_coords(0.0, 1.0), 1.0 / 1.5);
+            assert_eq!(v, Some(Vector::with_coords(0.0, -1.0)));
+        }
+
+        #[test]
+        fn refracts_ray_through_flat_interface() {
+            let incident = Vector::with_coords(1.0, -1.0).normalized();
+            let v = incident
+                .refract(Vector::with_coords(0.0, 1.0), 1.0 / 1.5)
+                .unwrap();
+            let sin_refracted = std::f64::consts::FRAC_1_SQRT_2 / 1.5;
+            assert!((v.x - sin_refracted).abs() < 1e-12);
+            assert!((v.y + (1.0 - sin_refracted * sin_refracted).sqrt()).abs() < 1e-12);
+        }
+
+        #[test]
+        fn detects_total_internal_reflection() {
+            let grazing = Vector::with_coords(0.95, -0.05).normalized();
+            assert_eq!(grazing.refract(Vector::with_coords(0.0, 1.0), 1.5), None);
+        }
+
+        #[test]
+        fn floors_vector_coordinates() {
+            let v = Vector::with_coords(1.7, -2.3);
+            assert_eq!(v.floor(), Vector::with_coords(1.0, -3.0));
+        }
+
+        #[test]
+        fn ceils_vector_coordinates() {
+            let v = Vector::with_coords(1.7, -2.3);
+            assert_eq!(v.ceil(), Vector::with_coords(2.0, -2.0));
+        }
+
+        #[test]
+        fn rounds_vector_coordinates() {
+            let v = Vector::with_coords(1.7, -2.3);
+            assert_eq!(v.round(), Vector::with_coords(2.0, -2.0));
+        }
+
+        #[test]
+        fn snaps_vector_to_grid() {
+            let v = Vector::with_coords(1.2, 3.7);
+            assert_eq!(v.snap_to_grid(0.5), Vector::with_coords(1.0, 3.5));
+        }
+
+        #[test]
+        fn leaves_vector_unchanged_when_snapping_to_zero_step_grid() {
+            let v = Vector::with_coords(1.2, 3.7);
+            assert_eq!(v.snap_to_grid(0.0), Vector::with_coords(1.2, 3.7));
+        }
+
+        #[test]
+        fn interpolates_linearly_between_two_vectors() {
+            let v = Vector::with_coords(1.0, 0.0);
+            let w = Vector::with_coords(3.0, -4.0);
+            assert_eq!(v.lerp(w, 0.25), Vector::with_coords(1.5, -1.0));
+        }
+
+        #[test]
+        fn interpolates_direction_along_arc_between_two_vectors() {
+            let v = Vector::with_coords(1.0, 0.0).slerp(Vector::with_coords(0.0, 1.0), 0.5);
+            let diagonal = std::f64::consts::FRAC_1_SQRT_2;
+            assert!((v.x - diagonal).abs() < 1e-12);
+            assert!((v.y - diagonal).abs() < 1e-12);
+        }
+
+        #[test]
+        fn interpolates_magnitude_linearly_when_slerping() {
+            let v: Vector<f64> =
+                Vector::with_coords(2.0, 0.0).slerp(Vector::with_coords(0.0, -4.0), 0.5);
+            assert!((v.x.hypot(v.y) - 3.0).abs() < 1e-12);
+            assert!((v.y.atan2(v.x) + std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        }
+
+        #[test]
+        fn falls_back_to_lerp_when_slerping_parallel_vectors() {
+            let v = Vector::with_coords(1.0, 1.0).slerp(Vector::with_coords(3.0, 3.0), 0.5);
+            assert_eq!(v, Vector::with_coords(2.0, 2.0));
+        }
+
+        #[test]
+        fn computes_heading_of_cardinal_directions() {
+            use std::f64::consts::{FRAC_PI_2, PI};
+            assert_eq!(Vector::with_coords(1.0, 0.0).heading(), 0.0);
+            assert_eq!(Vector::with_coords(0.0, 1.0).heading(), FRAC_PI_2);
+            assert_eq!(Vector::with_coords(-1.0, 0.0).heading(), PI);
+            assert_eq!(Vector::with_coords(0.0, -1.0).heading(), 3.0 * FRAC_PI_2);
+        }
+
+        #[test]
+        fn keeps_heading_below_full_turn() {
+            let heading = Vector::with_coords(1.0, -1e-20).heading();
+            assert!((0.0..std::f64::consts::TAU).contains(&heading));
+        }
+
+        #[test]
+        fn rotates_vector_towards_target_by_limited_step() {
+            let step = std::f64::consts::FRAC_PI_8;
+            let v =
+                Vector::with_coords(1.0, 0.0).rotate_towards(Vector::with_coords(0.0, 1.0), step);
+            assert!((v.x - step.cos()).abs() < 1e-12);
+            assert!((v.y - step.sin()).abs() < 1e-12);
+        }
+
+        #[test]
+        fn rotates_vector_clockwise_towards_target_behind_it() {
+            let step = std::f64::consts::FRAC_PI_8;
+            let v =
+                Vector::with_coords(1.0, 0.0).rotate_towards(Vector::with_coords(0.0, -1.0), step);
+            assert!((v.x - step.cos()).abs() < 1e-12);
+            assert!((v.y + step.sin()).abs() < 1e-12);
+        }
+
+        #[test]
+        fn snaps_to_target_when_within_step() {
+            let v =
+                Vector::with_coords(1.0, 0.0).rotate_towards(Vector::with_coords(0.0, 1.0), 2.0);
+            assert_eq!(v, Vector::with_coords(0.0, 1.0));
+        }
+
+        #[test]
+        fn preserves_magnitude_when_rotating_towards_target() {
+            let v =
+                Vector::with_coords(2.0, 0.0).rotate_towards(Vector::with_coords(0.0, 5.0), 2.0);
+            assert_eq!(v, Vector::with_coords(0.0, 2.0));
+        }
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn compares_float_vectors_approximately() {
+        let v =
+            Vector::with_coords(0.1 + 0.2, 1.0).rotate_towards(Vector::with_coords(0.0, 1.0), 0.0);
+        approx::assert_abs_diff_eq!(v, Vector::with_coords(0.3, 1.0), epsilon = 1e-12);
+        approx::assert_relative_eq!(v, Vector::with_coords(0.3, 1.0));
+        approx::assert_relative_ne!(v, Vector::with_coords(0.3, 1.1));
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn builds_arbitrary_vector_from_raw_bytes() {
+        let bytes = 0x0102_0304_i32
+            .to_le_bytes()
+            .iter()
+            .chain(&(-5_i32).to_le_bytes())
+            .copied()
+            .collect::<Vec<_>>();
+        let v = Vector::<i32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(v, Vector::with_coords(0x0102_0304, -5));
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn round_trips_vector_through_mint() {
+        let v: mint::Vector2<f32> = Vector::with_coords(4.5f32, -2.0).into();
+        assert_eq!((v.x, v.y), (4.5, -2.0));
+        assert_eq!(Vector::from(v), Vector::with_coords(4.5f32, -2.0));
+
+        let w: mint::Vector2<f64> = Vector::with_coords(0.25, 8.0).into();
+        assert_eq!(Vector::from(w), Vector::with_coords(0.25, 8.0));
+    }
+
+    #[test]
+    fn computes_dot_products_over_slices() {
+        let a = [Vector::with_coords(1, 2), Vector::with_coords(-3, 4)];
+        let b = [Vector::with_coords(5, 6), Vector::with_coords(7, -8)];
+        let expected: Vec<i32> = a
+            .iter()
+            .zip(&b)
+            .map(|(v, w)| Vector::with_coords(v.x, v.y).dot(Vector::with_coords(w.x, w.y)))
+            .collect();
+        assert_eq!(dot_all(&a, &b), expected);
+        assert_eq!(dot_all(&a, &b), vec![17, -53]);
+    }
+
+    #[test]
+    fn computes_cross_products_over_slices() {
+        let a = [Vector::with_coords(42, 2), Vector::with_coords(1, 0)];
+        let b = [Vector::with_coords(3, 1), Vector::with_coords(0, 1)];
+        assert_eq!(cross_all(&a, &b), vec![36, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_slices_of_different_length() {
+        dot_all(&[Vector::with_coords(1, 2)], &[]);
+    }
+
+    #[test]
+    fn has_same_layout_as_array() {
+        assert_eq!(
+            std::mem::size_of::<Vector<i16>>(),
+            std::mem::size_of::<[i16; 2]>()
+        );
+        assert_eq!(
+            std::mem::align_of::<Vector<f64>>(),
+            std::mem::align_of::<[f64; 2]>()
+        );
+    }
+
+    #[test]
+    fn views_vector_as_array() {
+        let v = Vector::with_coords(42, -7);
+        assert_eq!(v.as_array(), &[42, -7]);
+    }
+
+    #[test]
+    fn mutates_vector_through_array_view() {
+        let mut v = Vector::with_coords(42.0, -7.0);
+        v.as_mut_array()[1] = 3.5;
+        v.as_mut_array()[0] *= 2.0;
+        assert_eq!(v, Vector::with_coords(84.0, 3.5));
+    }
+
+    #[test]
+    fn maps_vector_components() {
+        let v = Vector::with_coords(21, -4);
+        assert_eq!(v.map(|c| c * 2), Vector::with_coords(42, -8));
+    }
+
+    #[test]
+    fn maps_vector_components_into_another_coordinate_type() {
+        let v = Vector::with_coords(21, -4);
+        assert_eq!(v.map(|c| c as f64 / 2.0), Vector::with_coords(10.5, -2.0));
+    }
+
+    #[test]
+    fn zip_maps_components_of_two_vectors() {
+        let v = Vector::with_coords(1, 7);
+        let w = Vector::with_coords(3, -2);
+        assert_eq!(v.zip_map(w, std::cmp::max), Vector::with_coords(3, 7));
+    }
+
+    #[test]
+    fn computes_hadamard_product_of_two_vectors() {
+        let v = Vector::with_coords(2, 3);
+        let w = Vector::with_coords(4, 5);
+        assert_eq!(v.hadamard(w), Vector::with_coords(8, 15));
+    }
+
+    #[test]
+    fn divides_two_vectors_component_wise() {
+        let v = Vector::with_coords(8, 15);
+        let w = Vector::with_coords(4, 5);
+        assert_eq!(v.component_div(w), Vector::with_coords(2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_component_wise_division_of_integers_by_zero() {
+        let v = Vector::with_coords(8, 15);
+        v.component_div(Vector::with_coords(4, 0));
+    }
+
+    fn orientation_of(cross: i128) -> Orientation {
+        match cross.cmp(&0) {
+            Ordering::Less => Orientation::Clockwise,
+            Ordering::Equal => Orientation::Collinear,
+            Ordering::Greater => Orientation::Counterclockwise,
+        }
+    }
+
+    #[test]
+    fn computes_sign_of_cross_product() {
+        let v = Vector::with_coords(1, 0);
+        assert_eq!(
+            v.cross_sign(Vector::with_coords(0, 1)),
+            Orientation::Counterclockwise
+        );
+        let v = Vector::with_coords(1, 0);
+        assert_eq!(
+            v.cross_sign(Vector::with_coords(0, -1)),
+            Orientation::Clockwise
+        );
+        let v = Vector::with_coords(2, 3);
+        assert_eq!(
+            v.cross_sign(Vector::with_coords(-4, -6)),
+            Orientation::Collinear
+        );
+    }
+
+    #[test]
+    fn matches_widened_cross_product_sign_on_random_vectors() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        let mut next = || rng.next() as i32;
+        for _ in 0..1000 {
+            let (v, w) = (
+                Vector::with_coords(next(), next()),
+                Vector::with_coords(next(), next()),
+            );
+            let cross = v.x as i128 * w.y as i128 - w.x as i128 * v.y as i128;
+            assert_eq!(v.cross_sign(w), orientation_of(cross));
+        }
+    }
+
+    #[test]
+    fn computes_sign_of_cross_product_near_overflow() {
+        let v = Vector::with_coords(i64::MAX, i64::MAX - 1);
+        let w = Vector::with_coords(i64::MAX - 1, i64::MAX - 2);
+        assert_eq!(v.cross_sign(w), Orientation::Clockwise);
+        let v = Vector::with_coords(i64::MIN, i64::MIN + 1);
+        let w = Vector::with_coords(i64::MIN + 1, i64::MIN + 2);
+        assert_eq!(v.cross_sign(w), Orientation::Clockwise);
+    }
+
+    #[test]
+    fn computes_sign_of_cross_product_of_extreme_wide_integers() {
+        let v = Vector::with_coords(i128::MAX, i128::MAX - 1);
+        let w = Vector::with_coords(i128::MAX - 1, i128::MAX - 2);
+        assert_eq!(v.cross_sign(w), Orientation::Clockwise);
+        let v = Vector::with_coords(i128::MIN, i128::MIN);
+        let w = Vector::with_coords(i128::MIN, i128::MIN);
+        assert_eq!(v.cross_sign(w), Orientation::Collinear);
+    }
+
+    #[test]
+    fn adds_two_vectors_with_overflow_check() {
+        let v = Vector::with_coords(i32::MAX - 1, 0);
+        assert_eq!(
+            v.checked_add(Vector::with_coords(1, 1)),
+            Some(Vector::with_coords(i32::MAX, 1))
+        );
+    }
+
+    #[test]
+    fn detects_overflow_when_adding_two_vectors() {
+        let v = Vector::with_coords(0, i32::MAX);
+        assert_eq!(v.checked_add(Vector::with_coords(0, 1)), None);
+    }
+
+    #[test]
+    fn detects_overflow_when_subtracting_two_vectors() {
+        let v = Vector::with_coords(i32::MIN, 0);
+        assert_eq!(v.checked_sub(Vector::with_coords(1, 0)), None);
+        let v = Vector::with_coords(i32::MIN + 1, 0);
+        assert_eq!(
+            v.checked_sub(Vector::with_coords(1, 0)),
+            Some(Vector::with_coords(i32::MIN, 0))
+        );
+    }
+
+    #[test]
+    fn detects_overflow_when_multiplying_vector_by_scalar() {
+        let v = Vector::with_coords(2, i32::MAX / 2 + 1);
+        assert_eq!(v.checked_mul_scalar(2), None);
+        let v = Vector::with_coords(2, i32::MAX / 2);
+        assert_eq!(
+            v.checked_mul_scalar(2),
+            Some(Vector::with_coords(4, i32::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn casts_vector_to_another_coordinate_type() {
+        let v = Vector::with_coords(42, -2);
+        assert_eq!(v.cast::<f64>(), Some(Vector::with_coords(42.0, -2.0)));
+    }
+
+    #[test]
+    fn fails_to_cast_unrepresentable_coordinates() {
+        let v = Vector::with_coords(i64::MAX, 0);
+        assert_eq!(v.cast::<i32>(), None);
+    }
 }
@@ -0,0 +1,584 @@
+use crate::meta::Coord;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::meta::FloatCoord;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3<T>
+where
+    T: Coord,
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vector3<T>
+where
+    T: Coord,
+{
+    pub fn with_coords(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn zero() -> Self {
+        Self::with_coords(T::zero(), T::zero(), T::zero())
+    }
+
+    pub fn i_hat() -> Self {
+        Self::with_coords(T::one(), T::zero(), T::zero())
+    }
+
+    pub fn j_hat() -> Self {
+        Self::with_coords(T::zero(), T::one(), T::zero())
+    }
+
+    pub fn k_hat() -> Self {
+        Self::with_coords(T::zero(), T::zero(), T::one())
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self::with_coords(
+            self.y.clone() * other.z.clone() - self.z.clone() * other.y.clone(),
+            self.z * other.x.clone() - self.x.clone() * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Computes `self · (b × c)`, the signed volume of the parallelepiped spanned by the three
+    /// vectors; it is positive when they form a right-handed system.
+    pub fn triple_product(self, b: Self, c: Self) -> T {
+        self.dot(b.cross(c))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Vector3<T>
+where
+    T: FloatCoord,
+{
+    pub fn magnitude(self) -> T {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Scales the vector to unit length; the zero vector has no direction and yields NaNs.
+    pub fn normalized(self) -> Self {
+        self / self.magnitude()
+    }
+}
+
+impl<T> fmt::Display for Vector3<T>
+where
+    T: Coord + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
+    }
+}
+
+impl<T> Neg for Vector3<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::with_coords(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T> Add for Vector3<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::with_coords(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T> Sub for Vector3<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::with_coords(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T> Mul<T> for Vector3<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self::with_coords(
+            self.x * scalar.clone(),
+            self.y * scalar.clone(),
+            self.z * scalar,
+        )
+    }
+}
+
+impl<T> Div<T> for Vector3<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self::Output {
+        Self::with_coords(
+            self.x / scalar.clone(),
+            self.y / scalar.clone(),
+            self.z / scalar,
+        )
+    }
+}
+
+forward_ref_unop!(impl Neg, neg for Vector3<T>);
+forward_ref_binop!(impl Add, add for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl Sub, sub for Vector3<T>, Vector3<T>);
+forward_ref_binop!(impl Mul, mul for Vector3<T>, T);
+forward_ref_binop!(impl Div, div for Vector3<T>, T);
+
+impl<T> AddAssign for Vector3<T>
+where
+    T: Coord,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.x = self.x.clone() + other.x;
+        self.y = self.y.clone() + other.y;
+        self.z = self.z.clone() + other.z;
+    }
+}
+
+impl<T> SubAssign for Vector3<T>
+where
+    T: Coord,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.x = self.x.clone() - other.x;
+        self.y = self.y.clone() - other.y;
+        self.z = self.z.clone() - other.z;
+    }
+}
+
+impl<T> MulAssign<T> for Vector3<T>
+where
+    T: Coord,
+{
+    fn mul_assign(&mut self, scalar: T) {
+        self.x = self.x.clone() * scalar.clone();
+        self.y = self.y.clone() * scalar.clone();
+        self.z = self.z.clone() * scalar;
+    }
+}
+
+impl<T> DivAssign<T> for Vector3<T>
+where
+    T: Coord,
+{
+    fn div_assign(&mut self, scalar: T) {
+        self.x = self.x.clone() / scalar.clone();
+        self.y = self.y.clone() / scalar.clone();
+        self.z = self.z.clone() / scalar;
+    }
+}
+
+forward_ref_op_assign!(impl AddAssign, add_assign for Vector3<T>, Vector3<T>);
+forward_ref_op_assign!(impl SubAssign, sub_assign for Vector3<T>, Vector3<T>);
+forward_ref_op_assign!(impl MulAssign, mul_assign for Vector3<T>, T);
+forward_ref_op_assign!(impl DivAssign, div_assign for Vector3<T>, T);
+
+impl<T> From<(T, T, T)> for Vector3<T>
+where
+    T: Coord,
+{
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::with_coords(x, y, z)
+    }
+}
+
+impl<T> From<Vector3<T>> for (T, T, T)
+where
+    T: Coord,
+{
+    fn from(v: Vector3<T>) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3<T>
+where
+    T: Coord,
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T>
+where
+    T: Coord,
+{
+    pub fn with_coords(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> fmt::Display for Point3<T>
+where
+    T: Coord + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> Add<Vector3<T>> for Point3<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn add(self, offset: Vector3<T>) -> Self::Output {
+        Self::with_coords(self.x + offset.x, self.y + offset.y, self.z + offset.z)
+    }
+}
+
+impl<T> Sub<Vector3<T>> for Point3<T>
+where
+    T: Coord,
+{
+    type Output = Self;
+
+    fn sub(self, offset: Vector3<T>) -> Self::Output {
+        Self::with_coords(self.x - offset.x, self.y - offset.y, self.z - offset.z)
+    }
+}
+
+impl<T> Sub for Point3<T>
+where
+    T: Coord,
+{
+    type Output = Vector3<T>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Vector3::with_coords(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+forward_ref_binop!(impl Add, add for Point3<T>, Vector3<T>);
+forward_ref_binop!(impl Sub, sub for Point3<T>, Vector3<T>);
+forward_ref_binop!(impl Sub, sub for Point3<T>, Point3<T>);
+
+impl<T> AddAssign<Vector3<T>> for Point3<T>
+where
+    T: Coord,
+{
+    fn add_assign(&mut self, offset: Vector3<T>) {
+        self.x = self.x.clone() + offset.x;
+        self.y = self.y.clone() + offset.y;
+        self.z = self.z.clone() + offset.z;
+    }
+}
+
+impl<T> SubAssign<Vector3<T>> for Point3<T>
+where
+    T: Coord,
+{
+    fn sub_assign(&mut self, offset: Vector3<T>) {
+        self.x = self.x.clone() - offset.x;
+        self.y = self.y.clone() - offset.y;
+        self.z = self.z.clone() - offset.z;
+    }
+}
+
+forward_ref_op_assign!(impl AddAssign, add_assign for Point3<T>, Vector3<T>);
+forward_ref_op_assign!(impl SubAssign, sub_assign for Point3<T>, Vector3<T>);
+
+impl<T> From<(T, T, T)> for Point3<T>
+where
+    T: Coord,
+{
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::with_coords(x, y, z)
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T)
+where
+    T: Coord,
+{
+    fn from(p: Point3<T>) -> Self {
+        (p.x, p.y, p.z)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PlaneSide {
+    Above,
+    OnPlane,
+    Below,
+}
+
+/// The plane of points `p` satisfying `normal · p = offset`. The normal need not be unit length;
+/// it points towards the `Above` side.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane<T>
+where
+    T: Coord,
+{
+    pub normal: Vector3<T>,
+    pub offset: T,
+}
+
+impl<T> Plane<T>
+where
+    T: Coord,
+{
+    pub fn with_normal_and_offset(normal: Vector3<T>, offset: T) -> Self {
+        Self { normal, offset }
+    }
+
+    pub fn with_normal_through_point(normal: Vector3<T>, point: Point3<T>) -> Self {
+        let offset =
+            normal.x.clone() * point.x + normal.y.clone() * point.y + normal.z.clone() * point.z;
+        Self { normal, offset }
+    }
+
+    /// Orients the normal so that points seeing `a`, `b` and `c` counterclockwise lie above.
+    /// Returns `None` if the points are collinear.
+    pub fn through_points(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> Option<Self> {
+        let normal = (b - &a).cross(c - &a);
+        if normal == Vector3::zero() {
+            None
+        } else {
+            Some(Self::with_normal_through_point(normal, a))
+        }
+    }
+
+    /// Evaluates `normal · p - offset`, which is the signed distance scaled by the length of the
+    /// normal.
+    pub fn evaluate(&self, p: Point3<T>) -> T {
+        self.normal.x.clone() * p.x + self.normal.y.clone() * p.y + self.normal.z.clone() * p.z
+            - self.offset.clone()
+    }
+
+    pub fn side(&self, p: Point3<T>) -> PlaneSide {
+        let value = self.evaluate(p);
+        if value > T::zero() {
+            PlaneSide::Above
+        } else if value < T::zero() {
+            PlaneSide::Below
+        } else {
+            PlaneSide::OnPlane
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T> Plane<T>
+where
+    T: FloatCoord,
+{
+    pub fn signed_distance(&self, p: Point3<T>) -> T {
+        self.evaluate(p) / self.normal.magnitude()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_vector_with_specified_coordinates() {
+        let v = Vector3::with_coords(4, 2, -1);
+        assert_eq!((v.x, v.y, v.z), (4, 2, -1));
+    }
+
+    #[test]
+    fn creates_unit_vectors_alongside_axes() {
+        assert_eq!(Vector3::<i32>::i_hat(), Vector3::with_coords(1, 0, 0));
+        assert_eq!(Vector3::<i32>::j_hat(), Vector3::with_coords(0, 1, 0));
+        assert_eq!(Vector3::<i32>::k_hat(), Vector3::with_coords(0, 0, 1));
+        assert_eq!(Vector3::<i32>::zero(), Vector3::default());
+    }
+
+    #[test]
+    fn creates_string_representation_of_vector() {
+        assert_eq!(Vector3::with_coords(-4, 2, 0).to_string(), "[-4, 2, 0]");
+        assert_eq!(Point3::with_coords(-4, 2, 0).to_string(), "(-4, 2, 0)");
+    }
+
+    #[test]
+    fn computes_dot_product_of_two_vectors() {
+        let v = Vector3::with_coords(1, 2, 3);
+        let w = Vector3::with_coords(4, -5, 6);
+        assert_eq!(v.dot(w), 12);
+    }
+
+    #[test]
+    fn computes_cross_product_of_two_vectors() {
+        assert_eq!(
+            Vector3::<i32>::i_hat().cross(Vector3::j_hat()),
+            Vector3::k_hat()
+        );
+        assert_eq!(
+            Vector3::with_coords(1, 2, 3).cross(Vector3::with_coords(4, 5, 6)),
+            Vector3::with_coords(-3, 6, -3)
+        );
+    }
+
+    #[test]
+    fn computes_scalar_triple_product() {
+        let (a, b, c) = (
+            Vector3::with_coords(2, 0, 0),
+            Vector3::with_coords(0, 3, 0),
+            Vector3::with_coords(1, 1, 4),
+        );
+        assert_eq!(a.triple_product(b, c), 24);
+        assert_eq!(b.triple_product(a, c), -24);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn applies_arithmetic_operators() {
+        let v = Vector3::with_coords(1, 2, 3);
+        let w = Vector3::with_coords(4, -5, 6);
+        assert_eq!(v + w, Vector3::with_coords(5, -3, 9));
+        assert_eq!(-(v * 2), Vector3::with_coords(-2, -4, -6));
+        assert_eq!(&w - &v, Vector3::with_coords(3, -7, 3));
+        assert_eq!(&w / 2, Vector3::with_coords(2, -2, 3));
+    }
+
+    #[test]
+    fn applies_compound_assignment_operators() {
+        let mut v = Vector3::with_coords(1, 2, 3);
+        v += Vector3::with_coords(1, 1, 1);
+        v *= 3;
+        assert_eq!(v, Vector3::with_coords(6, 9, 12));
+        v -= &Vector3::with_coords(0, 3, 0);
+        v /= 3;
+        assert_eq!(v, Vector3::with_coords(2, 2, 4));
+
+        let mut p = Point3::with_coords(0, 0, 0);
+        p += v;
+        p -= &Vector3::k_hat();
+        assert_eq!(p, Point3::with_coords(2, 2, 3));
+    }
+
+    #[test]
+    fn converts_to_and_from_tuples() {
+        assert_eq!(Vector3::from((1, 2, 3)), Vector3::with_coords(1, 2, 3));
+        assert_eq!(
+            <(i32, i32, i32)>::from(Point3::with_coords(4, 5, 6)),
+            (4, 5, 6)
+        );
+        assert_eq!(Point3::from((4, 5, 6)), Point3::with_coords(4, 5, 6));
+    }
+
+    #[test]
+    fn offsets_and_subtracts_points() {
+        let p = Point3::with_coords(1, 2, 3);
+        assert_eq!(
+            p + Vector3::with_coords(1, 1, 1),
+            Point3::with_coords(2, 3, 4)
+        );
+        assert_eq!(
+            p - Point3::with_coords(0, 2, 5),
+            Vector3::with_coords(1, 0, -2)
+        );
+    }
+
+    #[test]
+    fn classifies_points_against_plane() {
+        let plane = Plane::with_normal_through_point(
+            Vector3::with_coords(0, 0, 2),
+            Point3::with_coords(5, 5, 1),
+        );
+        assert_eq!(plane.side(Point3::with_coords(0, 0, 3)), PlaneSide::Above);
+        assert_eq!(
+            plane.side(Point3::with_coords(-7, 3, 1)),
+            PlaneSide::OnPlane
+        );
+        assert_eq!(plane.side(Point3::with_coords(0, 0, 0)), PlaneSide::Below);
+        assert_eq!(plane.evaluate(Point3::with_coords(0, 0, 3)), 4);
+    }
+
+    #[test]
+    fn creates_plane_through_counterclockwise_points() {
+        let plane = Plane::through_points(
+            Point3::with_coords(0, 0, 1),
+            Point3::with_coords(1, 0, 1),
+            Point3::with_coords(0, 1, 1),
+        )
+        .unwrap();
+        assert_eq!(plane, Plane::with_normal_and_offset(Vector3::k_hat(), 1));
+        assert_eq!(plane.side(Point3::with_coords(3, 3, 2)), PlaneSide::Above);
+    }
+
+    #[test]
+    fn creates_no_plane_through_collinear_points() {
+        let plane = Plane::through_points(
+            Point3::with_coords(0, 0, 0),
+            Point3::with_coords(1, 1, 1),
+            Point3::with_coords(3, 3, 3),
+        );
+        assert_eq!(plane, None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_point_and_plane_through_serde() {
+        let p = Point3::with_coords(1, -2, 3);
+        let json = serde_json::to_string(&p).unwrap();
+        assert_eq!(json, r#"{"x":1,"y":-2,"z":3}"#);
+        assert_eq!(serde_json::from_str::<Point3<i32>>(&json).unwrap(), p);
+
+        let plane = Plane::with_normal_and_offset(Vector3::k_hat(), 4);
+        let json = serde_json::to_string(&plane).unwrap();
+        assert_eq!(json, r#"{"normal":{"x":0,"y":0,"z":1},"offset":4}"#);
+        assert_eq!(serde_json::from_str::<Plane<i32>>(&json).unwrap(), plane);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    mod float {
+        use super::*;
+
+        #[test]
+        fn computes_magnitude_and_normalizes_vector() {
+            assert_eq!(Vector3::with_coords(2.0, 3.0, 6.0).magnitude(), 7.0);
+            assert_eq!(
+                Vector3::with_coords(0.0, 0.0, -3.0).normalized(),
+                Vector3::with_coords(0.0, 0.0, -1.0)
+            );
+        }
+
+        #[test]
+        fn computes_signed_distance_to_plane() {
+            let plane = Plane::with_normal_and_offset(Vector3::with_coords(0.0, 3.0, 4.0), 10.0);
+            assert_eq!(
+                plane.signed_distance(Point3::with_coords(1.0, 0.0, 0.0)),
+                -2.0
+            );
+            assert_eq!(
+                plane.signed_distance(Point3::with_coords(0.0, 5.0, 5.0)),
+                5.0
+            );
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::bounding::Aabb;
+use crate::meta::Coord;
+use crate::polygon::Polygon;
+use crate::primitives::{to_f64_point, Point, Segment};
+use crate::vectors::Vector;
+use alloc::vec::Vec;
+use num_traits::{Float, ToPrimitive};
+
+// Rays are cast this many radians to either side of every endpoint, so that they slip past
+// obstacles ending there.
+const OFFSET: f64 = 1e-7;
+
+/// Returns the region of `bounds` visible from `viewer`, which must lie inside it, with
+/// `obstacles` blocking the view. The vertices run counterclockwise around the viewer.
+///
+/// Rays are cast towards every obstacle endpoint and bounds corner, and slightly to either side
+/// of them, and each stops at the nearest obstacle or side of `bounds`; the polygon joins the
+/// stopping points in angular order. Obstacles sharing endpoints or lying along a ray are
+/// handled this way too. This takes O(n²) time for n obstacles, and coordinates are processed as
+/// `f64`.
+pub fn visibility_polygon<T>(
+    viewer: Point<T>,
+    obstacles: &[Segment<T>],
+    bounds: &Aabb<T>,
+) -> Polygon<f64>
+where
+    T: Coord + ToPrimitive,
+{
+    let viewer = to_f64_point(&viewer);
+    let (min, max) = (to_f64_point(&bounds.min), to_f64_point(&bounds.max));
+    let corners = [
+        min,
+        Point::with_coords(max.x, min.y),
+        max,
+        Point::with_coords(min.x, max.y),
+    ];
+    let walls: Vec<(Point<f64>, Point<f64>)> = obstacles
+        .iter()
+        .map(|s| (to_f64_point(&s.start), to_f64_point(&s.end)))
+        .chain((0..4).map(|i| (corners[i], corners[(i + 1) % 4])))
+        .collect();
+
+    let mut hits: Vec<(f64, Point<f64>)> = Vec::with_capacity(3 * 2 * walls.len());
+    for &(a, b) in &walls {
+        for p in [a, b] {
+            if p == viewer {
+                continue;
+            }
+            let towards = p - viewer;
+            let angle = Float::atan2(towards.y, towards.x);
+            for (ray, direction) in [
+                (angle - OFFSET, rotate(towards, -OFFSET)),
+                (angle, towards),
+                (angle + OFFSET, rotate(towards, OFFSET)),
+            ] {
+                if let Some(hit) = cast(viewer, direction, &walls) {
+                    hits.push((ray, hit));
+                }
+            }
+        }
+    }
+
+    hits.sort_by(|(a, _), (b, _)| a.partial_cmp(b).expect("angles are comparable"));
+    let mut vertices: Vec<Point<f64>> = Vec::with_capacity(hits.len());
+    for (_, hit) in hits {
+        if vertices
+            .last()
+            .is_none_or(|&last| (hit - last).norm_squared() > 1e-18)
+        {
+            vertices.push(hit);
+        }
+    }
+    while vertices.len() > 1 && (vertices[0] - vertices[vertices.len() - 1]).norm_squared() <= 1e-18
+    {
+        vertices.pop();
+    }
+    Polygon::with_vertices(vertices)
+}
+
+fn rotate(v: Vector<f64>, angle: f64) -> Vector<f64> {
+    let (sin, cos) = Float::sin_cos(angle);
+    Vector::with_coords(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+}
+
+// Returns where the ray from `origin` along `direction` first meets one of the walls, snapping
+// hits on a wall's endpoints to the endpoints themselves.
+fn cast(
+    origin: Point<f64>,
+    direction: Vector<f64>,
+    walls: &[(Point<f64>, Point<f64>)],
+) -> Option<Point<f64>> {
+    let mut nearest: Option<(f64, Point<f64>)> = None;
+    for &(a, b) in walls {
+        let edge = b - a;
+        let denominator = direction.cross(edge);
+        if denominator == 0.0 {
+            continue;
+        }
+        let offset = a - origin;
+        let t = offset.cross(edge) / denominator;
+        let u = offset.cross(direction) / denominator;
+        if t < 0.0 || !(-1e-12..=1.0 + 1e-12).contains(&u) || nearest.is_some_and(|(n, _)| n <= t) {
+            continue;
+        }
+        let hit = if u <= 1e-12 {
+            a
+        } else if u >= 1.0 - 1e-12 {
+            b
+        } else {
+            a + edge * u
+        };
+        nearest = Some((t, hit));
+    }
+    nearest.map(|(_, hit)| hit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x1: i64, y1: i64, x2: i64, y2: i64) -> Segment<i64> {
+        Segment::with_endpoints(Point::with_coords(x1, y1), Point::with_coords(x2, y2))
+    }
+
+    fn room() -> Aabb<i64> {
+        Aabb::with_corners(Point::with_coords(0, 0), Point::with_coords(10, 10))
+    }
+
+    #[test]
+    fn sees_whole_empty_box() {
+        let visible = visibility_polygon(Point::with_coords(3, 4), &[], &room());
+        assert!((visible.area() - 100.0).abs() < 1e-6);
+        assert_eq!(
+            visible.winding(),
+            crate::vectors::Orientation::Counterclockwise
+        );
+        for corner in [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)] {
+            let corner = Point::with_coords(corner.0, corner.1);
+            assert!(visible.vertices.contains(&corner));
+        }
+    }
+
+    #[test]
+    fn hides_region_behind_wall() {
+        let wall = [segment(5, 2, 5, 8)];
+        let visible = visibility_polygon(Point::with_coords(1, 5), &wall, &room());
+        assert!((visible.area() - 166.0 / 3.0).abs() < 1e-4);
+        assert!(visible.contains_point(Point::with_coords(4.0, 5.0)));
+        assert!(visible.contains_point(Point::with_coords(6.0, 9.9)));
+        assert!(!visible.contains_point(Point::with_coords(6.0, 5.0)));
+        assert!(!visible.contains_point(Point::with_coords(9.0, 1.0)));
+    }
+
+    #[test]
+    fn sees_around_walls_sharing_a_corner() {
+        let corner = [segment(5, 2, 5, 8), segment(5, 8, 2, 8)];
+        let visible = visibility_polygon(Point::with_coords(2, 2), &corner, &room());
+        assert!(visible.contains_point(Point::with_coords(4.0, 7.0)));
+        assert!(visible.contains_point(Point::with_coords(9.0, 1.0)));
+        assert!(visible.contains_point(Point::with_coords(1.0, 9.0)));
+        assert!(!visible.contains_point(Point::with_coords(6.0, 9.0)));
+        assert!(!visible.contains_point(Point::with_coords(4.0, 9.0)));
+    }
+}
@@ -0,0 +1,46 @@
+//! Reads Well-Known Text into the crate's geometry types, under the names of a dedicated module.
+//!
+//! `WktGeometry` and `WktError` are the `Geometry` and `ParseError` of `formats`, so the parsed
+//! geometries are written back with `Geometry::to_wkt`.
+
+use crate::formats::{Geometry, ParseError};
+use crate::meta::Coord;
+use core::str::FromStr;
+
+pub type WktGeometry<T> = Geometry<T>;
+
+pub type WktError = ParseError;
+
+/// Same as `Geometry::from_wkt`.
+pub fn parse_wkt<T>(input: &str) -> Result<WktGeometry<T>, WktError>
+where
+    T: Coord + FromStr,
+{
+    Geometry::from_wkt(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon::Polygon;
+    use crate::primitives::Point;
+
+    #[test]
+    fn parses_and_writes_polygon() {
+        let geometry = parse_wkt::<i32>("POLYGON((0 0, 1 0, 1 1, 0 1, 0 0))").unwrap();
+        assert_eq!(
+            geometry,
+            WktGeometry::Polygon(Polygon::with_vertices(vec![
+                Point::with_coords(0, 0),
+                Point::with_coords(1, 0),
+                Point::with_coords(1, 1),
+                Point::with_coords(0, 1),
+            ]))
+        );
+        assert_eq!(parse_wkt(&geometry.to_wkt()), Ok(geometry));
+        assert_eq!(
+            parse_wkt::<i32>("POLYGON((0 0, 1 0"),
+            Err(WktError::UnexpectedEnd)
+        );
+    }
+}